@@ -0,0 +1,189 @@
+use crate::constants::{MAX_PRECISION_U32, MAX_SCALE_U32};
+use crate::ops::wide::{Target, add_one, div_by_u96, mul_by_pow10, mul_by_u96, round_exact};
+use crate::{Decimal, RoundingStrategy};
+use alloc::{vec, vec::Vec};
+use core::cmp::Reverse;
+
+/// The policy used to hand out the units that are left over once every part of an allocation
+/// has been rounded down to the requested number of decimal places.
+///
+/// Only parts that had a non-zero amount rounded away are eligible to receive a leftover unit, so
+/// no part ever differs from its exact share by a whole unit or more.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AllocationStrategy {
+    /// The largest remainder (Hamilton) method. Parts that lost the largest amount to rounding
+    /// receive a unit first, with ties given to the earliest part.
+    #[default]
+    LargestRemainder,
+    /// The largest remainder method, with ties given to the latest part.
+    LargestRemainderFromLast,
+    /// Leftover units are given to the earliest eligible parts, regardless of how much was
+    /// rounded away from them.
+    FromFirst,
+    /// Leftover units are given to the latest eligible parts, regardless of how much was rounded
+    /// away from them.
+    FromLast,
+}
+
+impl Decimal {
+    /// Allocates this amount into parts proportional to the given ratios, with each part having
+    /// `dp` decimal places. The parts always sum exactly to the original amount, with any
+    /// leftover units handed out using [`AllocationStrategy::LargestRemainder`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the allocation is not possible. See
+    /// [`Decimal::allocate_with_strategy`] for the conditions under which this occurs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let fee = dec!(10.00);
+    /// assert_eq!(fee.allocate(&[dec!(1), dec!(1), dec!(1)], 2), [dec!(3.34), dec!(3.33), dec!(3.33)]);
+    /// assert_eq!(fee.allocate(&[dec!(0.7), dec!(0.2), dec!(0.1)], 0), [dec!(7), dec!(2), dec!(1)]);
+    /// ```
+    #[must_use]
+    pub fn allocate(&self, ratios: &[Decimal], dp: u32) -> Vec<Decimal> {
+        match self.allocate_with_strategy(ratios, dp, AllocationStrategy::default()) {
+            Some(parts) => parts,
+            None => panic!("Unable to allocate {self} by the given ratios to {dp} decimal places"),
+        }
+    }
+
+    /// Splits this amount into `n` parts that are as even as possible, with each part having
+    /// `dp` decimal places. The parts always sum exactly to the original amount, with any
+    /// leftover units given to the earliest parts.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `n` is zero, or if this amount has more than `dp` decimal places.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(100).split_evenly(3, 2), [dec!(33.34), dec!(33.33), dec!(33.33)]);
+    /// assert_eq!(dec!(-0.05).split_evenly(3, 2), [dec!(-0.02), dec!(-0.02), dec!(-0.01)]);
+    /// ```
+    #[must_use]
+    pub fn split_evenly(&self, n: usize, dp: u32) -> Vec<Decimal> {
+        self.allocate(&vec![Decimal::ONE; n], dp)
+    }
+
+    /// Allocates this amount into parts proportional to the given ratios, with each part having
+    /// `dp` decimal places. Each part is first rounded toward zero, after which the remaining
+    /// units are handed out according to the given [`AllocationStrategy`]. The parts always sum
+    /// exactly to the original amount.
+    ///
+    /// Returns `None` if:
+    /// * `ratios` is empty, contains a negative ratio or sums to zero.
+    /// * This amount has more than `dp` decimal places, since it can't be divided into parts of
+    ///   that precision.
+    /// * A part can't be represented with `dp` decimal places, or the ratios require more
+    ///   precision than can be computed internally.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{AllocationStrategy, Decimal};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let amount = dec!(0.05);
+    /// let ratios = [dec!(1), dec!(1), dec!(1)];
+    /// assert_eq!(
+    ///     amount.allocate_with_strategy(&ratios, 2, AllocationStrategy::LargestRemainderFromLast),
+    ///     Some(vec![dec!(0.01), dec!(0.02), dec!(0.02)])
+    /// );
+    /// assert_eq!(amount.allocate_with_strategy(&ratios, 1, AllocationStrategy::FromFirst), None);
+    /// ```
+    #[must_use]
+    pub fn allocate_with_strategy(
+        &self,
+        ratios: &[Decimal],
+        dp: u32,
+        strategy: AllocationStrategy,
+    ) -> Option<Vec<Decimal>> {
+        let dp = dp.min(MAX_SCALE_U32);
+        let amount = self.normalize();
+        if amount.scale() > dp || ratios.iter().any(|r| r.is_sign_negative() && !r.is_zero()) {
+            return None;
+        }
+
+        // Work with integer weights so that the shares can be calculated exactly
+        let ratio_scale = ratios.iter().map(Decimal::scale).max()?;
+        let mut weights = Vec::with_capacity(ratios.len());
+        let mut total = 0u128;
+        for ratio in ratios {
+            let m = ratio.mantissa_array3();
+            let mut weight = [m[0], m[1], m[2], 0];
+            if !mul_by_pow10(&mut weight, ratio_scale - ratio.scale()) || weight[3] != 0 {
+                return None;
+            }
+            let weight = u128::from(weight[0]) | (u128::from(weight[1]) << 32) | (u128::from(weight[2]) << 64);
+            total = total.checked_add(weight).filter(|t| t >> 96 == 0)?;
+            weights.push(weight);
+        }
+        if total == 0 {
+            return None;
+        }
+
+        // The amount expressed as a whole number of units, i.e. the smallest value at `dp` places
+        let m = amount.mantissa_array3();
+        let mut units = [m[0], m[1], m[2], 0, 0, 0, 0, 0, 0, 0];
+        mul_by_pow10(&mut units, dp - amount.scale());
+
+        let mut parts = Vec::with_capacity(ratios.len());
+        let mut remainders = Vec::with_capacity(ratios.len());
+        let mut leftover = 0u128;
+        for weight in weights {
+            let mut part = units;
+            // The units occupy at most 190 bits so the product always fits
+            mul_by_u96(&mut part, weight);
+            let remainder = div_by_u96(&mut part, total);
+            // The remainders always sum to a multiple of the total weight
+            leftover = leftover.checked_add(remainder)?;
+            parts.push(part);
+            remainders.push(remainder);
+        }
+        let leftover = usize::try_from(leftover / total).ok()?;
+
+        let mut order: Vec<usize> = (0..parts.len()).filter(|i| remainders[*i] != 0).collect();
+        match strategy {
+            AllocationStrategy::LargestRemainder => order.sort_by_key(|i| (Reverse(remainders[*i]), *i)),
+            AllocationStrategy::LargestRemainderFromLast => {
+                order.sort_by_key(|i| (Reverse(remainders[*i]), Reverse(*i)))
+            }
+            AllocationStrategy::FromFirst => {}
+            AllocationStrategy::FromLast => order.reverse(),
+        }
+        for i in order.into_iter().take(leftover) {
+            add_one(&mut parts[i]);
+        }
+
+        // A part may have too many digits to be represented with `dp` decimal places (e.g. when
+        // allocating `Decimal::MAX`). Trailing zeros can be dropped, but anything else would break
+        // the guarantee that the parts sum to the original amount.
+        let negative = amount.is_sign_negative();
+        parts
+            .iter()
+            .map(|part| {
+                round_exact(
+                    part,
+                    dp,
+                    negative,
+                    false,
+                    Target::Precision(MAX_PRECISION_U32),
+                    RoundingStrategy::ToZero,
+                )
+                .filter(|rounded| !rounded.inexact)
+                .map(|rounded| rounded.value)
+            })
+            .collect()
+    }
+}
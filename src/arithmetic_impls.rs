@@ -1,9 +1,14 @@
 // #[rustfmt::skip] is being used because `rustfmt` poorly formats `#[doc = concat!(..)]`. See
 // https://github.com/rust-lang/rustfmt/issues/5062 for more information.
 
-use crate::{Decimal, decimal::CalculationResult, ops};
+use crate::constants::{MAX_PRECISION_U32, MAX_SCALE_U32};
+use crate::decimal::{CalculationResult, out_of_range};
+use crate::ops::wide::{DecWide, ExactSum, Target, div_truncated, round_exact};
+use crate::{Decimal, RoundingStrategy, ops};
 use core::ops::{Add, Div, Mul, Rem, Sub};
-use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, Inv};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedRem, CheckedSub, Euclid, Inv, MulAdd, MulAddAssign,
+};
 
 // Macros and `Decimal` implementations
 
@@ -19,7 +24,7 @@ macro_rules! impl_checked {
         )]
         #[inline(always)]
         #[must_use]
-        pub fn $fun(self, other: Decimal) -> Option<Decimal> {
+        pub const fn $fun(self, other: Decimal) -> Option<Decimal> {
             match ops::$impl(&self, &other) {
                 CalculationResult::Ok(result) => Some(result),
                 _ => None,
@@ -40,7 +45,7 @@ macro_rules! impl_saturating {
         )]
         #[inline(always)]
         #[must_use]
-        pub fn $fun(self, other: Decimal) -> Decimal {
+        pub const fn $fun(self, other: Decimal) -> Decimal {
             if let Some(elem) = self.$impl(other) {
                 elem
             } else {
@@ -99,6 +104,468 @@ impl Decimal {
 
     impl_checked!("division", "/", checked_div, div_impl);
     impl_checked!("remainder", "%", checked_rem, rem_impl);
+
+    /// Multiplies `self` by `other`, rounding the exact product to `dp` decimal places using the
+    /// given [`RoundingStrategy`]. Unlike calling [`Decimal::round_dp_with_strategy`] on the
+    /// result of a multiplication, the product is only ever rounded once.
+    ///
+    /// The result has a scale of `dp` (capped at `Decimal::MAX_SCALE`) unless the padded
+    /// coefficient would not fit, in which case the largest possible scale is used.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let quantity = dec!(3.125);
+    /// let price = dec!(1.21);
+    /// assert_eq!(quantity.mul_with_scale(price, 2, RoundingStrategy::MidpointAwayFromZero), dec!(3.78));
+    /// assert_eq!(dec!(1.5).mul_with_scale(dec!(2), 2, RoundingStrategy::ToZero).to_string(), "3.00");
+    /// ```
+    #[must_use]
+    pub fn mul_with_scale(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Decimal {
+        match self.checked_mul_with_scale(other, dp, strategy) {
+            Some(prod) => prod,
+            None => panic!("Multiplication overflowed"),
+        }
+    }
+
+    /// Checked multiplication with an explicit result scale. Computes `self * other` rounded
+    /// once to `dp` decimal places, returning `None` if overflow occurred or the rounded product
+    /// cannot be represented with `dp` decimal places.
+    ///
+    /// See [`Decimal::mul_with_scale`] for more information.
+    #[must_use]
+    pub fn checked_mul_with_scale(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+        let product = DecWide::from_product(&self, &other)?;
+        product.round(Target::Scale(dp), strategy).map(|rounded| rounded.value)
+    }
+
+    /// Computes `self * a + b`, rounding the exact result only once. Calculating `self * a` on
+    /// its own rounds the product whenever it needs more than 96 bits, and adding `b` may then
+    /// round a second time; here the full product is kept until after the addition.
+    ///
+    /// Like the arithmetic operators, the result keeps as many decimal places as fit (up to
+    /// `Decimal::MAX_SCALE`), rounding any excess using [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let a = dec!(100000000000000.000000000001);
+    /// let b = dec!(-10000000000000000000000000000);
+    /// assert_eq!(a.mul_add(a, b), dec!(200.000000000000000000000001));
+    /// assert_eq!(a * a + b, dec!(200));
+    /// ```
+    #[must_use]
+    pub fn mul_add(self, a: Decimal, b: Decimal) -> Decimal {
+        match self.checked_mul_add(a, b) {
+            Some(result) => result,
+            None => panic!("Multiply-add overflowed"),
+        }
+    }
+
+    /// Checked fused multiply-add. Computes `self * a + b` with a single rounding, returning
+    /// `None` if overflow occurred.
+    ///
+    /// See [`Decimal::mul_add`] for more information.
+    #[must_use]
+    pub fn checked_mul_add(self, a: Decimal, b: Decimal) -> Option<Decimal> {
+        let product = DecWide::from_product(&self, &a)?;
+        // A zero product is treated as an unscaled zero, as with multiplication
+        let product_scale = if product.is_zero() { 0 } else { product.scale() };
+
+        // The product is at most 192 bits with a scale of 56 and needs at most another 28 digits
+        // to align with `b`, while `b` needs at most 56 digits. Either way this fits in 320 bits.
+        let mut total = ExactSum::<10>::new(product_scale.max(b.scale()));
+        total.add(product.data(), product_scale, product.is_negative())?;
+        total.add(&b.mantissa_array3(), b.scale(), b.is_sign_negative())?;
+        total
+            .round(
+                Target::Precision(MAX_PRECISION_U32),
+                RoundingStrategy::MidpointNearestEven,
+            )
+            .map(|rounded| rounded.value)
+    }
+
+    /// Sums a slice of decimals exactly, rounding only once at the end. Folding with `+` (as the
+    /// `Sum` implementation does) can round the running total at every step when the values have
+    /// different scales, and panics on overflow.
+    ///
+    /// Like the arithmetic operators, the result keeps as many decimal places as fit (up to
+    /// `Decimal::MAX_SCALE`), rounding any excess using [`RoundingStrategy::MidpointNearestEven`].
+    /// Intermediate totals may exceed the range of `Decimal`, so long as the final sum does not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sum cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Error};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let values = [Decimal::MAX, dec!(0.0000000000000000000000000001), -Decimal::MAX];
+    /// assert_eq!(Decimal::sum_exact(&values), Ok(dec!(0.0000000000000000000000000001)));
+    /// assert_eq!(Decimal::sum_exact(&[Decimal::MAX, Decimal::ONE]), Err(Error::ExceedsMaximumPossibleValue));
+    /// ```
+    pub fn sum_exact(values: &[Decimal]) -> crate::Result<Decimal> {
+        let scale = values.iter().map(Decimal::scale).max().unwrap_or_default();
+
+        // Each aligned term is at most 96 bits scaled by 10^28, so 256 bits leaves plenty of room
+        let mut total = ExactSum::<8>::new(scale);
+        for value in values {
+            total
+                .add(&value.mantissa_array3(), value.scale(), value.is_sign_negative())
+                .ok_or_else(|| out_of_range(total.is_negative()))?;
+        }
+        total
+            .round(
+                Target::Precision(MAX_PRECISION_U32),
+                RoundingStrategy::MidpointNearestEven,
+            )
+            .map(|rounded| rounded.value)
+            .ok_or_else(|| out_of_range(total.is_negative()))
+    }
+
+    /// Computes the dot product of two slices of decimals, i.e. the sum of the pairwise products,
+    /// exactly and rounding only once at the end. Neither the individual products nor the running
+    /// total are rounded, so the result is the same as if it were calculated by hand.
+    ///
+    /// Rounding follows [`Decimal::sum_exact`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result cannot be represented.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the slices have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let quantities = [dec!(150), dec!(20.5), dec!(-3)];
+    /// let prices = [dec!(12.345), dec!(99.99), dec!(0.5)];
+    /// assert_eq!(Decimal::dot(&quantities, &prices), Ok(dec!(3900.045)));
+    /// ```
+    pub fn dot(a: &[Decimal], b: &[Decimal]) -> crate::Result<Decimal> {
+        assert_eq!(a.len(), b.len(), "Dot product of slices with different lengths");
+
+        // A zero product is treated as an unscaled zero, as with multiplication
+        let scale = a
+            .iter()
+            .zip(b)
+            .filter(|(a, b)| !a.is_zero() && !b.is_zero())
+            .map(|(a, b)| a.scale() + b.scale())
+            .max()
+            .unwrap_or_default();
+
+        // Each product is at most 192 bits and is scaled by at most 10^56 to align it, which leaves
+        // plenty of room within 448 bits
+        let mut total = ExactSum::<14>::new(scale);
+        for (a, b) in a.iter().zip(b) {
+            let negative = a.is_sign_negative() ^ b.is_sign_negative();
+            let product = DecWide::from_product(a, b).ok_or_else(|| out_of_range(negative))?;
+            if product.is_zero() {
+                continue;
+            }
+            total
+                .add(product.data(), product.scale(), negative)
+                .ok_or_else(|| out_of_range(total.is_negative()))?;
+        }
+        total
+            .round(
+                Target::Precision(MAX_PRECISION_U32),
+                RoundingStrategy::MidpointNearestEven,
+            )
+            .map(|rounded| rounded.value)
+            .ok_or_else(|| out_of_range(total.is_negative()))
+    }
+
+    /// Divides `self` by `other`, rounding the exact quotient to `dp` decimal places using the
+    /// given [`RoundingStrategy`]. Unlike calling [`Decimal::round_dp_with_strategy`] on the
+    /// result of a division, the quotient is only ever rounded once.
+    ///
+    /// The result has a scale of `dp` (capped at `Decimal::MAX_SCALE`) unless the padded
+    /// coefficient would not fit, in which case the largest possible scale is used.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the result overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(2).div_with_scale(dec!(3), 2, RoundingStrategy::MidpointNearestEven), dec!(0.67));
+    /// assert_eq!(dec!(2).div_with_scale(dec!(3), 2, RoundingStrategy::ToZero), dec!(0.66));
+    ///
+    /// // The exact quotient is 0.44499999999999999999999999997, however rounding the default
+    /// // quotient of 0.4450000000000000000000000000 would instead give 0.45
+    /// let value = dec!(4.4499999999999999999999999997);
+    /// assert_eq!(value.div_with_scale(dec!(10), 2, RoundingStrategy::MidpointAwayFromZero), dec!(0.44));
+    /// assert_eq!((value / dec!(10)).round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero), dec!(0.45));
+    /// ```
+    #[must_use]
+    pub fn div_with_scale(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Decimal {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        match self.checked_div_with_scale(other, dp, strategy) {
+            Some(quot) => quot,
+            None => panic!("Division overflowed"),
+        }
+    }
+
+    /// Checked division with an explicit result scale. Computes `self / other` rounded once to
+    /// `dp` decimal places, returning `None` if `other` is zero, overflow occurred or the rounded
+    /// quotient cannot be represented with `dp` decimal places.
+    ///
+    /// See [`Decimal::div_with_scale`] for more information.
+    #[must_use]
+    pub fn checked_div_with_scale(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+        if other.is_zero() {
+            return None;
+        }
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+        // Truncating one digit beyond the requested scale allows the remainder to be folded into
+        // the rounding decision.
+        let (quotient, scale, sticky) = div_truncated(&self, &other, dp.min(MAX_SCALE_U32) + 1);
+        round_exact(&quotient, scale, negative, sticky, Target::Scale(dp), strategy).map(|rounded| rounded.value)
+    }
+
+    /// Calculates the quotient and remainder of `self / other` at the same time. The quotient is
+    /// truncated toward zero and the remainder has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(7.5).div_rem(dec!(2)), (dec!(3), dec!(1.5)));
+    /// assert_eq!(dec!(-7.5).div_rem(dec!(2)), (dec!(-3), dec!(-1.5)));
+    /// ```
+    #[must_use]
+    pub fn div_rem(self, other: Decimal) -> (Decimal, Decimal) {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        match self.checked_div_rem(other) {
+            Some(result) => result,
+            None => panic!("Division overflowed"),
+        }
+    }
+
+    /// Checked quotient and remainder. Computes `(self / other, self % other)` with the quotient
+    /// truncated toward zero, returning `None` if `other` is zero or overflow occurred.
+    #[must_use]
+    pub fn checked_div_rem(self, other: Decimal) -> Option<(Decimal, Decimal)> {
+        let quot = self.div_to_integer(other, RoundingStrategy::ToZero)?;
+        let rem = self.checked_rem(other)?;
+        Some((quot, rem))
+    }
+
+    /// Calculates the quotient of Euclidean division of `self` by `other`. This is the integer
+    /// `q` such that `self = other * q + r` where `0 <= r < |other|`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(7.5).div_euclid(dec!(2)), dec!(3));
+    /// assert_eq!(dec!(-7.5).div_euclid(dec!(2)), dec!(-4));
+    /// assert_eq!(dec!(7.5).div_euclid(dec!(-2)), dec!(-3));
+    /// assert_eq!(dec!(-7.5).div_euclid(dec!(-2)), dec!(4));
+    /// ```
+    #[must_use]
+    pub fn div_euclid(self, other: Decimal) -> Decimal {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        match self.checked_div_euclid(other) {
+            Some(quot) => quot,
+            None => panic!("Division overflowed"),
+        }
+    }
+
+    /// Checked Euclidean division. Computes [`Decimal::div_euclid`], returning `None` if `other`
+    /// is zero or overflow occurred.
+    #[must_use]
+    pub fn checked_div_euclid(self, other: Decimal) -> Option<Decimal> {
+        if other.is_sign_negative() {
+            self.div_to_integer(other, RoundingStrategy::ToPositiveInfinity)
+        } else {
+            self.div_to_integer(other, RoundingStrategy::ToNegativeInfinity)
+        }
+    }
+
+    /// Calculates the least non-negative remainder of `self / other`. This is the `r` such that
+    /// `self = other * q + r` where `0 <= r < |other|`.
+    ///
+    /// As with `f64`, the remainder is rounded to fit within a `Decimal`. When `self` is negative
+    /// and tiny compared to `other`, the exact remainder `|other| - |self|` may therefore round
+    /// to `|other|` itself.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(7.5).rem_euclid(dec!(2)), dec!(1.5));
+    /// assert_eq!(dec!(-7.5).rem_euclid(dec!(2)), dec!(0.5));
+    /// assert_eq!(dec!(-7.5).rem_euclid(dec!(-2)), dec!(0.5));
+    /// ```
+    #[must_use]
+    pub fn rem_euclid(self, other: Decimal) -> Decimal {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        match self.checked_rem_euclid(other) {
+            Some(rem) => rem,
+            None => panic!("Division overflowed"),
+        }
+    }
+
+    /// Checked Euclidean remainder. Computes [`Decimal::rem_euclid`], returning `None` if `other`
+    /// is zero or overflow occurred.
+    #[must_use]
+    pub fn checked_rem_euclid(self, other: Decimal) -> Option<Decimal> {
+        let rem = self.checked_rem(other)?;
+        if rem.is_sign_negative() && !rem.is_zero() {
+            rem.checked_add(other.abs())
+        } else {
+            Some(rem)
+        }
+    }
+
+    /// Calculates the quotient of `self / other`, rounded toward negative infinity.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(7.5).div_floor(dec!(2)), dec!(3));
+    /// assert_eq!(dec!(-7.5).div_floor(dec!(2)), dec!(-4));
+    /// assert_eq!(dec!(-7.5).div_floor(dec!(-2)), dec!(3));
+    /// ```
+    #[must_use]
+    pub fn div_floor(self, other: Decimal) -> Decimal {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        match self.checked_div_floor(other) {
+            Some(quot) => quot,
+            None => panic!("Division overflowed"),
+        }
+    }
+
+    /// Checked floored division. Computes [`Decimal::div_floor`], returning `None` if `other` is
+    /// zero or overflow occurred.
+    #[must_use]
+    pub fn checked_div_floor(self, other: Decimal) -> Option<Decimal> {
+        self.div_to_integer(other, RoundingStrategy::ToNegativeInfinity)
+    }
+
+    /// Calculates the quotient of `self / other`, rounded toward positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(7.5).div_ceil(dec!(2)), dec!(4));
+    /// assert_eq!(dec!(-7.5).div_ceil(dec!(2)), dec!(-3));
+    /// assert_eq!(dec!(-7.5).div_ceil(dec!(-2)), dec!(4));
+    /// ```
+    #[must_use]
+    pub fn div_ceil(self, other: Decimal) -> Decimal {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        match self.checked_div_ceil(other) {
+            Some(quot) => quot,
+            None => panic!("Division overflowed"),
+        }
+    }
+
+    /// Checked ceiling division. Computes [`Decimal::div_ceil`], returning `None` if `other` is
+    /// zero or overflow occurred.
+    #[must_use]
+    pub fn checked_div_ceil(self, other: Decimal) -> Option<Decimal> {
+        self.div_to_integer(other, RoundingStrategy::ToPositiveInfinity)
+    }
+
+    // Rounds the exact quotient of `self / other` to an integer using the given strategy.
+    pub(crate) fn div_to_integer(self, other: Decimal, strategy: RoundingStrategy) -> Option<Decimal> {
+        if other.is_zero() {
+            return None;
+        }
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+        // A minimum scale of one guarantees a digit is dropped whenever the remainder is non-zero
+        let (quotient, scale, sticky) = div_truncated(&self, &other, 1);
+        round_exact(&quotient, scale, negative, sticky, Target::Scale(0), strategy).map(|rounded| rounded.value)
+    }
+
+    /// Saturating division. Computes `self / other`, saturating at the relevant upper or lower
+    /// boundary.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero.
+    #[inline(always)]
+    #[must_use]
+    pub const fn saturating_div(self, other: Decimal) -> Decimal {
+        match ops::div_impl(&self, &other) {
+            CalculationResult::Ok(quot) => quot,
+            CalculationResult::Overflow => if_xnor_then_max(&self, &other),
+            CalculationResult::DivByZero => panic!("Division by zero"),
+        }
+    }
 }
 
 // Macros and trait implementations
@@ -198,6 +665,46 @@ impl CheckedRem for Decimal {
     }
 }
 
+impl Euclid for Decimal {
+    #[inline]
+    fn div_euclid(&self, v: &Decimal) -> Decimal {
+        Decimal::div_euclid(*self, *v)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &Decimal) -> Decimal {
+        Decimal::rem_euclid(*self, *v)
+    }
+}
+
+impl CheckedEuclid for Decimal {
+    #[inline]
+    fn checked_div_euclid(&self, v: &Decimal) -> Option<Decimal> {
+        Decimal::checked_div_euclid(*self, *v)
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &Decimal) -> Option<Decimal> {
+        Decimal::checked_rem_euclid(*self, *v)
+    }
+}
+
+impl MulAdd for Decimal {
+    type Output = Decimal;
+
+    #[inline]
+    fn mul_add(self, a: Decimal, b: Decimal) -> Decimal {
+        Decimal::mul_add(self, a, b)
+    }
+}
+
+impl MulAddAssign for Decimal {
+    #[inline]
+    fn mul_add_assign(&mut self, a: Decimal, b: Decimal) {
+        *self = Decimal::mul_add(*self, a, b);
+    }
+}
+
 impl Inv for Decimal {
     type Output = Self;
 
@@ -263,7 +770,7 @@ impl Sub<&Decimal> for &Decimal {
 
 // This function signature is expected by `impl_saturating`, thus the reason of `_b`.
 #[inline(always)]
-const fn if_a_is_positive_then_max(a: &Decimal, _b: &Decimal) -> Decimal {
+pub(crate) const fn if_a_is_positive_then_max(a: &Decimal, _b: &Decimal) -> Decimal {
     if a.is_sign_positive() {
         Decimal::MAX
     } else {
@@ -276,7 +783,7 @@ const fn if_a_is_positive_then_max(a: &Decimal, _b: &Decimal) -> Decimal {
 // If the `a` and `b` combination represents a XNOR bit operation, returns MAX. Otherwise,
 // returns MIN.
 #[inline(always)]
-const fn if_xnor_then_max(a: &Decimal, b: &Decimal) -> Decimal {
+pub(crate) const fn if_xnor_then_max(a: &Decimal, b: &Decimal) -> Decimal {
     match (a.is_sign_positive(), b.is_sign_positive()) {
         (true, true) => Decimal::MAX,
         (true, false) => Decimal::MIN,
@@ -318,6 +825,8 @@ mod tests {
         assert_eq!(Decimal::MAX.saturating_mul(Decimal::MIN), Decimal::MIN);
         assert_eq!(Decimal::MAX.saturating_sub(Decimal::MAX), Decimal::ZERO);
         assert_eq!(Decimal::MAX.saturating_sub(Decimal::MIN), Decimal::MAX);
+        assert_eq!(Decimal::MAX.saturating_div(Decimal::new(1, 1)), Decimal::MAX);
+        assert_eq!(Decimal::MAX.saturating_div(Decimal::new(-1, 1)), Decimal::MIN);
 
         assert_eq!(Decimal::MIN.saturating_add(Decimal::MAX), Decimal::ZERO);
         assert_eq!(Decimal::MIN.saturating_add(Decimal::MIN), Decimal::MIN);
@@ -325,5 +834,7 @@ mod tests {
         assert_eq!(Decimal::MIN.saturating_mul(Decimal::MIN), Decimal::MAX);
         assert_eq!(Decimal::MIN.saturating_sub(Decimal::MAX), Decimal::MIN);
         assert_eq!(Decimal::MIN.saturating_sub(Decimal::MIN), Decimal::ZERO);
+        assert_eq!(Decimal::MIN.saturating_div(Decimal::new(1, 1)), Decimal::MIN);
+        assert_eq!(Decimal::MIN.saturating_div(Decimal::new(-1, 1)), Decimal::MAX);
     }
 }
@@ -0,0 +1,694 @@
+use crate::constants::{MAX_PRECISION_U32, POWERS_10};
+use crate::decimal::out_of_range;
+use crate::decimal192::impl_binop;
+use crate::ops::array::{div_by_u32, manage_add_by_internal, mul_by_u32, sub_by_internal};
+use crate::ops::wide::{Target, discard_digits, round_exact};
+use crate::{Decimal, Error, RoundingStrategy};
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use num_traits::{One, Zero};
+
+/// The largest power of ten that fits within a 32 bit word, used to process digits in chunks.
+const CHUNK_DIGITS: usize = 9;
+
+/// `BigDecimal` represents a decimal number with an arbitrary number of digits, stored on the
+/// heap.
+///
+/// It is intended for the calculations where even the digits of a [`Decimal192`](crate::Decimal192)
+/// are not enough, such as reconciling amounts with 18 decimal places and large integer portions,
+/// or keeping the exact result of a long product. Addition, subtraction and multiplication are
+/// always exact, so the result only needs rounding once it is converted back into a [`Decimal`].
+/// Division rounds the quotient to a number of decimal places, see [`BigDecimal::checked_div`].
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{BigDecimal, Decimal};
+/// # use core::str::FromStr;
+/// #
+/// let wei = BigDecimal::from_str("123456789012345678901234.123456789012345678").unwrap();
+/// let total = &wei * &wei;
+/// assert_eq!(total.scale(), 36);
+/// assert_eq!(total.to_decimal(), None);
+/// assert_eq!(
+///     (wei - BigDecimal::from(123456789012345678901234u128)).to_decimal(),
+///     Some(Decimal::from_str("0.123456789012345678").unwrap())
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct BigDecimal {
+    // The little endian 32 bit words of the mantissa, without any trailing zero words. Zero is
+    // represented by an empty vector and is never negative.
+    data: Vec<u32>,
+    scale: u32,
+    negative: bool,
+}
+
+impl BigDecimal {
+    /// A constant representing 0.
+    pub const ZERO: BigDecimal = BigDecimal {
+        data: Vec::new(),
+        scale: 0,
+        negative: false,
+    };
+
+    /// Returns a `BigDecimal` with a 64 bit `m` representation and corresponding `e` scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::BigDecimal;
+    /// #
+    /// let pi = BigDecimal::new(3141, 3);
+    /// assert_eq!(pi.to_string(), "3.141");
+    /// ```
+    #[must_use]
+    pub fn new(num: i64, scale: u32) -> BigDecimal {
+        Self::from_i128_with_scale(i128::from(num), scale)
+    }
+
+    /// Creates a `BigDecimal` using a 128 bit signed `m` representation and corresponding `e`
+    /// scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::BigDecimal;
+    /// #
+    /// let value = BigDecimal::from_i128_with_scale(-170141183460469231731687303715884105728, 60);
+    /// assert_eq!(
+    ///     value.to_string(),
+    ///     "-0.000000000000000000000170141183460469231731687303715884105728"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_i128_with_scale(num: i128, scale: u32) -> BigDecimal {
+        let m = num.unsigned_abs();
+        let data = vec![m as u32, (m >> 32) as u32, (m >> 64) as u32, (m >> 96) as u32];
+        BigDecimal::from_parts(data, scale, num < 0)
+    }
+
+    fn from_parts(mut data: Vec<u32>, scale: u32, negative: bool) -> BigDecimal {
+        trim(&mut data);
+        let negative = negative && !data.is_empty();
+        BigDecimal { data, scale, negative }
+    }
+
+    /// Returns the scale of the decimal number, otherwise known as `e`.
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns `true` if the decimal is negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if the decimal is positive.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(&self) -> bool {
+        !self.negative
+    }
+
+    /// Returns `true` if this BigDecimal number is equivalent to zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Computes the absolute value of `self`.
+    #[must_use]
+    pub fn abs(&self) -> BigDecimal {
+        BigDecimal {
+            negative: false,
+            ..self.clone()
+        }
+    }
+
+    /// Strips any trailing zeros from the decimal number, returning zero with a scale of 0 for
+    /// any representation of zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::BigDecimal;
+    /// # use core::str::FromStr;
+    /// #
+    /// let number = BigDecimal::from_str("3.100").unwrap();
+    /// assert_eq!(number.normalize().to_string(), "3.1");
+    /// ```
+    #[must_use]
+    pub fn normalize(&self) -> BigDecimal {
+        if self.is_zero() {
+            return BigDecimal::ZERO;
+        }
+        let mut data = self.data.clone();
+        let mut scale = self.scale;
+        while scale > 0 {
+            let mut working = data.clone();
+            if div_by_u32(&mut working, 10) != 0 {
+                break;
+            }
+            data = working;
+            scale -= 1;
+        }
+        BigDecimal::from_parts(data, scale, self.negative)
+    }
+
+    /// Returns a new `BigDecimal` number with the specified number of decimal points for
+    /// fractional portion, using Banker's Rounding.
+    #[must_use]
+    pub fn round_dp(&self, dp: u32) -> BigDecimal {
+        self.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Returns a new `BigDecimal` number with the specified number of decimal points for
+    /// fractional portion, rounding with the given strategy. If the number already has `dp` or
+    /// fewer decimal places then it is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{BigDecimal, RoundingStrategy};
+    /// # use core::str::FromStr;
+    /// #
+    /// let value = BigDecimal::from_str("-1.000000000000000000000000000000000000005").unwrap();
+    /// assert_eq!(value.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero).to_string(), "-1.01");
+    /// ```
+    #[must_use]
+    pub fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> BigDecimal {
+        if dp >= self.scale {
+            return self.clone();
+        }
+        let mut data = self.data.clone();
+        let discarded = discard_digits(&mut data, self.scale - dp, false);
+        let last_digit = div_by_u32(&mut data.clone(), 10);
+        if strategy.rounds_away(self.negative, last_digit, discarded) {
+            add_words(&mut data, &[1]);
+        }
+        BigDecimal::from_parts(data, dp, self.negative)
+    }
+
+    /// Rounds this value into a `Decimal` using Banker's Rounding, keeping as many significant
+    /// digits as fit. Returns `None` if the value exceeds the range of a `Decimal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{BigDecimal, Decimal};
+    /// # use core::str::FromStr;
+    /// #
+    /// let value = BigDecimal::from_str("1.000000000000000000000000000050000000001").unwrap();
+    /// assert_eq!(value.to_decimal(), Some(Decimal::from_str("1.0000000000000000000000000001").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        self.to_decimal_with_strategy(RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Rounds this value into a `Decimal` using the given strategy, keeping as many significant
+    /// digits as fit. Returns `None` if the value exceeds the range of a `Decimal`.
+    #[must_use]
+    pub fn to_decimal_with_strategy(&self, strategy: RoundingStrategy) -> Option<Decimal> {
+        // Only the leading digits can influence the result, so fold the rest into a sticky flag
+        // until the mantissa fits within 128 bits.
+        let mut data = self.data.clone();
+        let mut scale = self.scale;
+        let mut sticky = false;
+        while data.len() > 4 {
+            if scale < CHUNK_DIGITS as u32 {
+                // The integral portion has at least 30 digits
+                return None;
+            }
+            sticky |= div_by_u32(&mut data, POWERS_10[CHUNK_DIGITS]) != 0;
+            trim(&mut data);
+            scale -= CHUNK_DIGITS as u32;
+        }
+
+        // Whenever digits were folded away at least 30 digits remain, so another is always
+        // dropped while rounding.
+        let mut mantissa = [0u32; 4];
+        mantissa[..data.len()].copy_from_slice(&data);
+        round_exact(
+            &mantissa,
+            scale,
+            self.negative,
+            sticky,
+            Target::Precision(MAX_PRECISION_U32),
+            strategy,
+        )
+        .map(|rounded| rounded.value)
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if the scale of the
+    /// product would exceed `u32::MAX`.
+    #[must_use]
+    pub fn checked_mul(&self, other: &BigDecimal) -> Option<BigDecimal> {
+        mul_impl(self, other)
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` is zero.
+    ///
+    /// Since a quotient may have infinitely many digits, it is rounded using Banker's Rounding to
+    /// `Decimal::MAX_SCALE` decimal places, or to the larger scale of the operands if that is
+    /// greater. As with `Decimal`, any trailing zeros are then removed. Use
+    /// [`BigDecimal::div_with_scale`] to choose the number of decimal places instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::BigDecimal;
+    /// #
+    /// let third = BigDecimal::from(1).checked_div(&BigDecimal::from(3)).unwrap();
+    /// assert_eq!(third.to_string(), "0.3333333333333333333333333333");
+    /// assert_eq!(BigDecimal::new(1000, 2).checked_div(&BigDecimal::from(4)), Some(BigDecimal::new(25, 1)));
+    /// assert_eq!(BigDecimal::from(1).checked_div(&BigDecimal::ZERO), None);
+    /// ```
+    #[must_use]
+    pub fn checked_div(&self, other: &BigDecimal) -> Option<BigDecimal> {
+        div_impl(self, other)
+    }
+
+    /// Divides `self` by `other`, rounding the exact quotient once to `dp` decimal places using
+    /// the given [`RoundingStrategy`]. Returns `None` if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{BigDecimal, RoundingStrategy};
+    /// #
+    /// let two = BigDecimal::from(2);
+    /// let three = BigDecimal::from(3);
+    /// assert_eq!(two.div_with_scale(&three, 40, RoundingStrategy::ToZero).unwrap().to_string(), "0.6666666666666666666666666666666666666666");
+    /// assert_eq!(two.div_with_scale(&three, 2, RoundingStrategy::MidpointNearestEven).unwrap().to_string(), "0.67");
+    /// ```
+    #[must_use]
+    pub fn div_with_scale(&self, other: &BigDecimal, dp: u32, strategy: RoundingStrategy) -> Option<BigDecimal> {
+        if other.is_zero() {
+            return None;
+        }
+        // Truncating to one more place than needed leaves a digit to round away whenever the
+        // remainder is non-zero: self / other * 10^(dp + 1) = a * 10^(b_scale + dp + 1) / (b * 10^a_scale)
+        let scale = self.scale.checked_add(other.scale)?;
+        let numer = self.aligned(scale.checked_add(dp)?.checked_add(1)?);
+        let denom = other.aligned(scale);
+        let (mut data, sticky) = div_words(&numer, &denom);
+        let negative = self.negative != other.negative;
+        let discarded = discard_digits(&mut data, 1, sticky);
+        let last_digit = div_by_u32(&mut data.clone(), 10);
+        if strategy.rounds_away(negative, last_digit, discarded) {
+            add_words(&mut data, &[1]);
+        }
+        Some(BigDecimal::from_parts(data, dp, negative))
+    }
+
+    /// Returns the mantissa aligned to the given scale, which must be at least `self.scale()`.
+    fn aligned(&self, scale: u32) -> Vec<u32> {
+        let mut data = self.data.clone();
+        let mut remaining = scale - self.scale;
+        while remaining > 0 && !data.is_empty() {
+            let step = remaining.min(CHUNK_DIGITS as u32);
+            let carry = mul_by_u32(&mut data, POWERS_10[step as usize]);
+            if carry != 0 {
+                data.push(carry);
+            }
+            remaining -= step;
+        }
+        data
+    }
+}
+
+/// Removes any trailing zero words.
+fn trim(data: &mut Vec<u32>) {
+    while data.last() == Some(&0) {
+        data.pop();
+    }
+}
+
+/// Adds `by` to `value`, growing `value` as required.
+fn add_words(value: &mut Vec<u32>, by: &[u32]) {
+    if value.len() < by.len() {
+        value.resize(by.len(), 0);
+    }
+    let mut carry = 0u64;
+    for (i, word) in value.iter_mut().enumerate() {
+        carry += u64::from(*word) + u64::from(by.get(i).copied().unwrap_or(0));
+        *word = carry as u32;
+        carry >>= 32;
+    }
+    if carry != 0 {
+        value.push(carry as u32);
+    }
+}
+
+/// Divides `data` by the trimmed, non-zero `divisor` one bit at a time. Returns the quotient and
+/// whether the remainder is non-zero.
+fn div_words(data: &[u32], divisor: &[u32]) -> (Vec<u32>, bool) {
+    let mut quotient = vec![0u32; data.len()];
+    let mut remainder: Vec<u32> = Vec::with_capacity(divisor.len() + 1);
+    for bit in (0..data.len() * 32).rev() {
+        // Shift the next bit of the dividend into the remainder, which stays trimmed
+        let mut carry = (data[bit / 32] >> (bit % 32)) & 1;
+        for word in remainder.iter_mut() {
+            let next = *word >> 31;
+            *word = (*word << 1) | carry;
+            carry = next;
+        }
+        if carry != 0 {
+            remainder.push(carry);
+        }
+        if cmp_words(&remainder, divisor) != Ordering::Less {
+            let mut by = divisor.to_vec();
+            by.resize(remainder.len(), 0);
+            sub_by_internal(&mut remainder, &by);
+            trim(&mut remainder);
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, !remainder.is_empty())
+}
+
+/// Compares two trimmed mantissas.
+fn cmp_words(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+impl Zero for BigDecimal {
+    fn zero() -> BigDecimal {
+        BigDecimal::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        BigDecimal::is_zero(self)
+    }
+}
+
+impl One for BigDecimal {
+    fn one() -> BigDecimal {
+        BigDecimal::from(1)
+    }
+}
+
+impl From<Decimal> for BigDecimal {
+    /// Converts a `Decimal` into a `BigDecimal` without loss, retaining its scale.
+    fn from(value: Decimal) -> BigDecimal {
+        BigDecimal::from_i128_with_scale(value.mantissa(), value.scale())
+    }
+}
+
+impl TryFrom<BigDecimal> for Decimal {
+    type Error = Error;
+
+    /// Rounds a `BigDecimal` into a `Decimal` using Banker's Rounding, failing if the value
+    /// exceeds the range of a `Decimal`.
+    fn try_from(value: BigDecimal) -> Result<Decimal, Error> {
+        value.to_decimal().ok_or(out_of_range(value.is_sign_negative()))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigDecimal {
+                #[inline]
+                fn from(value: $t) -> BigDecimal {
+                    BigDecimal::from_i128_with_scale(i128::from(value), 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<u128> for BigDecimal {
+    #[inline]
+    fn from(value: u128) -> BigDecimal {
+        let data = vec![
+            value as u32,
+            (value >> 32) as u32,
+            (value >> 64) as u32,
+            (value >> 96) as u32,
+        ];
+        BigDecimal::from_parts(data, 0, false)
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = Error;
+
+    /// Parses a decimal string such as `-1_000.25`, keeping every digit.
+    fn from_str(value: &str) -> Result<BigDecimal, Error> {
+        let bytes = value.as_bytes();
+        let (negative, rest) = match bytes.first() {
+            None => return Err(Error::EmptyData),
+            Some(b'-') => (true, &bytes[1..]),
+            Some(b'+') => (false, &bytes[1..]),
+            Some(_) => (false, bytes),
+        };
+
+        let mut data = Vec::new();
+        let mut scale = 0u32;
+        let mut any_digits = false;
+        let mut point = false;
+        // Digits are gathered into a word at a time before being folded into the mantissa
+        let mut chunk = 0u32;
+        let mut chunk_digits = 0;
+        for &b in rest {
+            match b {
+                b'0'..=b'9' => {
+                    any_digits = true;
+                    chunk = chunk * 10 + u32::from(b - b'0');
+                    chunk_digits += 1;
+                    if chunk_digits == CHUNK_DIGITS {
+                        push_digits(&mut data, chunk, chunk_digits);
+                        chunk = 0;
+                        chunk_digits = 0;
+                    }
+                    if point {
+                        scale = scale
+                            .checked_add(1)
+                            .ok_or(Error::ScaleExceedsMaximumPrecision(u32::MAX))?;
+                    }
+                }
+                b'.' if point => return Err(Error::DuplicatedDecimalPoint),
+                b'.' => point = true,
+                // As with `Decimal`, separators may only follow the first digit
+                b'_' if !any_digits => return Err(Error::InvalidLeadingChar),
+                b'_' => {}
+                _ => return Err(Error::InvalidCharacter),
+            }
+        }
+        if !any_digits {
+            return Err(Error::NoDigits);
+        }
+        push_digits(&mut data, chunk, chunk_digits);
+        Ok(BigDecimal::from_parts(data, scale, negative))
+    }
+}
+
+/// Appends the `count` decimal digits held within `chunk` to the mantissa.
+fn push_digits(data: &mut Vec<u32>, chunk: u32, count: usize) {
+    let carry = mul_by_u32(data, POWERS_10[count]);
+    if carry != 0 {
+        data.push(carry);
+    }
+    let carry = manage_add_by_internal(chunk, data);
+    if carry != 0 {
+        data.push(carry);
+    }
+}
+
+impl fmt::Display for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let scale = self.scale as usize;
+        let mut chunks = Vec::new();
+        let mut working = self.data.clone();
+        while !working.is_empty() {
+            chunks.push(div_by_u32(&mut working, POWERS_10[CHUNK_DIGITS]));
+            trim(&mut working);
+        }
+        let mut digits = String::new();
+        for (i, chunk) in chunks.iter().rev().enumerate() {
+            if i == 0 {
+                write!(digits, "{chunk}")?;
+            } else {
+                write!(digits, "{chunk:09}")?;
+            }
+        }
+        if digits.len() <= scale {
+            digits.insert_str(0, &"0".repeat(scale + 1 - digits.len()));
+        }
+
+        let (whole, fraction) = digits.split_at(digits.len() - scale);
+        let mut rep = String::from(whole);
+        // As with `Decimal`, an explicit precision truncates or pads the fractional portion
+        let precision = f.precision().unwrap_or(scale);
+        if precision > 0 {
+            rep.push('.');
+            rep.push_str(&fraction[..precision.min(scale)]);
+            rep.extend(core::iter::repeat_n('0', precision.saturating_sub(scale)));
+        }
+        f.pad_integral(self.is_sign_positive(), "", &rep)
+    }
+}
+
+impl fmt::Debug for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl PartialEq for BigDecimal {
+    #[inline]
+    fn eq(&self, other: &BigDecimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl Hash for BigDecimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let n = self.normalize();
+        n.data.hash(state);
+        n.scale.hash(state);
+        n.negative.hash(state);
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    #[inline]
+    fn partial_cmp(&self, other: &BigDecimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &BigDecimal) -> Ordering {
+        let sign = |d: &BigDecimal| match (d.is_zero(), d.negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        let (a_sign, b_sign) = (sign(self), sign(other));
+        if a_sign != b_sign || a_sign == 0 {
+            return a_sign.cmp(&b_sign);
+        }
+        let scale = self.scale.max(other.scale);
+        let magnitude = cmp_words(&self.aligned(scale), &other.aligned(scale));
+        if a_sign < 0 { magnitude.reverse() } else { magnitude }
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> BigDecimal {
+        let negative = !self.negative && !self.is_zero();
+        BigDecimal { negative, ..self }
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> BigDecimal {
+        -self.clone()
+    }
+}
+
+// Addition and subtraction are always exact, so only multiplication can fail when the scale of
+// the product overflows.
+impl_binop!(impl Add for BigDecimal, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl Sub for BigDecimal, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl Mul for BigDecimal, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl Div for BigDecimal, div, DivAssign, div_assign, div_impl, "Division by zero" | "Division overflowed");
+
+fn add_impl(a: &BigDecimal, b: &BigDecimal) -> Option<BigDecimal> {
+    Some(add_sub_impl(a, b, false))
+}
+
+fn sub_impl(a: &BigDecimal, b: &BigDecimal) -> Option<BigDecimal> {
+    Some(add_sub_impl(a, b, true))
+}
+
+fn add_sub_impl(a: &BigDecimal, b: &BigDecimal, subtract: bool) -> BigDecimal {
+    let scale = a.scale.max(b.scale);
+    let mut left = a.aligned(scale);
+    let right = b.aligned(scale);
+    let b_negative = b.negative != subtract;
+    if a.negative == b_negative {
+        add_words(&mut left, &right);
+        return BigDecimal::from_parts(left, scale, a.negative);
+    }
+
+    // Subtract the smaller magnitude from the larger, taking the sign of the larger
+    let (mut larger, mut smaller, negative) = match cmp_words(&left, &right) {
+        Ordering::Less => (right, left, b_negative),
+        _ => (left, right, a.negative),
+    };
+    smaller.resize(larger.len(), 0);
+    sub_by_internal(&mut larger, &smaller);
+    BigDecimal::from_parts(larger, scale, negative)
+}
+
+fn mul_impl(a: &BigDecimal, b: &BigDecimal) -> Option<BigDecimal> {
+    let scale = a.scale.checked_add(b.scale)?;
+    let mut product = vec![0u32; a.data.len() + b.data.len()];
+    for (i, &x) in a.data.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.data.iter().enumerate() {
+            // (2^32 - 1)^2 + 2 * (2^32 - 1) = 2^64 - 1, so this never overflows
+            carry += u64::from(x) * u64::from(y) + u64::from(product[i + j]);
+            product[i + j] = carry as u32;
+            carry >>= 32;
+        }
+        product[i + b.data.len()] = carry as u32;
+    }
+    Some(BigDecimal::from_parts(product, scale, a.negative != b.negative))
+}
+
+fn div_impl(a: &BigDecimal, b: &BigDecimal) -> Option<BigDecimal> {
+    let dp = a.scale.max(b.scale).max(Decimal::MAX_SCALE);
+    a.div_with_scale(b, dp, RoundingStrategy::MidpointNearestEven)
+        .map(|quotient| quotient.normalize())
+}
+
+impl Sum for BigDecimal {
+    fn sum<I: Iterator<Item = BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl<'a> Sum<&'a BigDecimal> for BigDecimal {
+    fn sum<I: Iterator<Item = &'a BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl Product for BigDecimal {
+    fn product<I: Iterator<Item = BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::one(), |product, value| product * value)
+    }
+}
+
+impl<'a> Product<&'a BigDecimal> for BigDecimal {
+    fn product<I: Iterator<Item = &'a BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::one(), |product, value| product * value)
+    }
+}
@@ -0,0 +1,241 @@
+use crate::arithmetic_impls::{if_a_is_positive_then_max, if_xnor_then_max};
+use crate::{Decimal, Error, decimal::CalculationResult, ops};
+use core::{
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+/// Checked arithmetic through the standard operators.
+///
+/// Rather than panicking on overflow or division by zero, operations on a `Checked<Decimal>`
+/// record the first error that occurred and carry it through the remainder of the expression.
+/// The outcome can be retrieved using [`Checked::into_result`].
+///
+/// Operators are implemented for `Checked<Decimal>` with either a `Checked<Decimal>` or a
+/// `Decimal` on the right hand side, as well as for `Decimal` with a `Checked<Decimal>` on the
+/// right hand side.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Checked, Decimal, Error};
+/// # use rust_decimal_macros::dec;
+/// #
+/// let price = Checked::new(dec!(12.50));
+/// let total = price.clone() * dec!(3) + dec!(0.25);
+/// assert_eq!(total.into_result(), Ok(dec!(37.75)));
+///
+/// let total = (price / Decimal::ZERO) * dec!(3) + Decimal::MAX;
+/// assert_eq!(total.into_result(), Err(Error::DivisionByZero));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checked<T>(Result<T, Error>);
+
+impl Checked<Decimal> {
+    /// Wraps the given decimal so that subsequent operations are checked.
+    #[must_use]
+    pub const fn new(value: Decimal) -> Checked<Decimal> {
+        Checked(Ok(value))
+    }
+
+    /// Returns `true` if no error has occurred.
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.0.is_ok()
+    }
+
+    /// Returns `true` if an error has occurred.
+    #[must_use]
+    pub const fn is_err(&self) -> bool {
+        self.0.is_err()
+    }
+
+    /// Returns the result of the calculation, or the first error that occurred.
+    pub fn into_result(self) -> crate::Result<Decimal> {
+        self.0
+    }
+
+    #[inline]
+    fn apply(
+        self,
+        other: &Decimal,
+        op: fn(&Decimal, &Decimal) -> CalculationResult,
+        boundary: fn(&Decimal, &Decimal) -> Decimal,
+    ) -> Checked<Decimal> {
+        let value = match self.0 {
+            Ok(value) => value,
+            Err(_) => return self,
+        };
+        Checked(match op(&value, other) {
+            CalculationResult::Ok(result) => Ok(result),
+            CalculationResult::Overflow if boundary(&value, other).is_sign_positive() => {
+                Err(Error::ExceedsMaximumPossibleValue)
+            }
+            CalculationResult::Overflow => Err(Error::LessThanMinimumPossibleValue),
+            CalculationResult::DivByZero => Err(Error::DivisionByZero),
+        })
+    }
+}
+
+impl Default for Checked<Decimal> {
+    fn default() -> Checked<Decimal> {
+        Checked::new(Decimal::ZERO)
+    }
+}
+
+impl From<Decimal> for Checked<Decimal> {
+    #[inline]
+    fn from(value: Decimal) -> Checked<Decimal> {
+        Checked::new(value)
+    }
+}
+
+impl From<crate::Result<Decimal>> for Checked<Decimal> {
+    #[inline]
+    fn from(result: crate::Result<Decimal>) -> Checked<Decimal> {
+        Checked(result)
+    }
+}
+
+impl From<Checked<Decimal>> for crate::Result<Decimal> {
+    #[inline]
+    fn from(checked: Checked<Decimal>) -> crate::Result<Decimal> {
+        checked.0
+    }
+}
+
+impl fmt::Display for Checked<Decimal> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(value) => fmt::Display::fmt(value, f),
+            Err(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl Neg for Checked<Decimal> {
+    type Output = Checked<Decimal>;
+
+    #[inline]
+    fn neg(self) -> Checked<Decimal> {
+        Checked(self.0.map(|value| -value))
+    }
+}
+
+macro_rules! impl_checked_binop {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, $impl:ident, $boundary:ident) => {
+        impl $imp<&Decimal> for Checked<Decimal> {
+            type Output = Checked<Decimal>;
+
+            #[inline]
+            fn $method(self, other: &Decimal) -> Checked<Decimal> {
+                self.apply(other, ops::$impl, $boundary)
+            }
+        }
+
+        impl $imp<Decimal> for Checked<Decimal> {
+            type Output = Checked<Decimal>;
+
+            #[inline]
+            fn $method(self, other: Decimal) -> Checked<Decimal> {
+                self.apply(&other, ops::$impl, $boundary)
+            }
+        }
+
+        impl $imp<&Checked<Decimal>> for Checked<Decimal> {
+            type Output = Checked<Decimal>;
+
+            #[inline]
+            fn $method(self, other: &Checked<Decimal>) -> Checked<Decimal> {
+                match (&self.0, &other.0) {
+                    (Ok(_), Ok(other)) => self.apply(other, ops::$impl, $boundary),
+                    (Ok(_), Err(_)) => other.clone(),
+                    (Err(_), _) => self,
+                }
+            }
+        }
+
+        impl $imp<Checked<Decimal>> for Checked<Decimal> {
+            type Output = Checked<Decimal>;
+
+            #[inline]
+            fn $method(self, other: Checked<Decimal>) -> Checked<Decimal> {
+                match other.0 {
+                    Ok(other) => self.apply(&other, ops::$impl, $boundary),
+                    Err(_) if self.is_err() => self,
+                    Err(_) => other,
+                }
+            }
+        }
+
+        impl $imp<Checked<Decimal>> for Decimal {
+            type Output = Checked<Decimal>;
+
+            #[inline]
+            fn $method(self, other: Checked<Decimal>) -> Checked<Decimal> {
+                Checked::new(self).$method(other)
+            }
+        }
+
+        impl $imp<Checked<Decimal>> for &Decimal {
+            type Output = Checked<Decimal>;
+
+            #[inline]
+            fn $method(self, other: Checked<Decimal>) -> Checked<Decimal> {
+                Checked::new(*self).$method(other)
+            }
+        }
+
+        impl<T> $assign_imp<T> for Checked<Decimal>
+        where
+            Checked<Decimal>: $imp<T, Output = Checked<Decimal>>,
+        {
+            #[inline]
+            fn $assign_method(&mut self, other: T) {
+                let current = core::mem::take(self);
+                *self = current.$method(other);
+            }
+        }
+    };
+}
+
+impl_checked_binop!(Add, add, AddAssign, add_assign, add_impl, if_a_is_positive_then_max);
+impl_checked_binop!(Sub, sub, SubAssign, sub_assign, sub_impl, if_a_is_positive_then_max);
+impl_checked_binop!(Mul, mul, MulAssign, mul_assign, mul_impl, if_xnor_then_max);
+impl_checked_binop!(Div, div, DivAssign, div_assign, div_impl, if_xnor_then_max);
+impl_checked_binop!(Rem, rem, RemAssign, rem_assign, rem_impl, if_a_is_positive_then_max);
+
+impl<T> Sum<T> for Checked<Decimal>
+where
+    Checked<Decimal>: Add<T, Output = Checked<Decimal>>,
+{
+    /// Stops consuming the iterator once an error has occurred.
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        let mut sum = Checked::new(Decimal::ZERO);
+        for i in iter {
+            sum += i;
+            if sum.is_err() {
+                break;
+            }
+        }
+        sum
+    }
+}
+
+impl<T> Product<T> for Checked<Decimal>
+where
+    Checked<Decimal>: Mul<T, Output = Checked<Decimal>>,
+{
+    /// Stops consuming the iterator once an error has occurred.
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        let mut product = Checked::new(Decimal::ONE);
+        for i in iter {
+            product *= i;
+            if product.is_err() {
+                break;
+            }
+        }
+        product
+    }
+}
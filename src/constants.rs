@@ -26,6 +26,9 @@ pub const MAX_SCALE: u8 = 28;
 pub const MAX_SCALE_I32: i32 = MAX_SCALE as _;
 // u8 to u32 is infallible, therefore, this cast will never overflow
 pub const MAX_SCALE_U32: u32 = MAX_SCALE as _;
+// The number of digits in `Decimal::MAX`. Values that don't fit within 96 bits are rounded to
+// one digit less.
+pub const MAX_PRECISION_U32: u32 = 29;
 // 79,228,162,514,264,337,593,543,950,335
 pub const MAX_I128_REPR: i128 = 0x0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF;
 
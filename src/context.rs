@@ -0,0 +1,378 @@
+use crate::constants::{MAX_PRECISION_U32, MAX_SCALE_U32};
+use crate::decimal::CalculationResult;
+use crate::ops::{
+    self,
+    wide::{DecWide, Rounded, Target, div_truncated, round_exact},
+};
+use crate::{Decimal, Error, RoundingStrategy};
+use core::{fmt, ops::BitOr};
+
+/// The exceptional conditions that can be raised by arithmetic performed within a [`Context`].
+///
+/// These follow the conditions described by the General Decimal Arithmetic specification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// The coefficient of the result was padded with zeros since `Decimal` cannot represent a
+    /// negative scale. e.g. `12345` rounded to a precision of `2` is represented as `12000`.
+    Clamped,
+    /// A division (or remainder) was attempted with a divisor of zero.
+    DivisionByZero,
+    /// The result was rounded and non-zero digits were discarded.
+    Inexact,
+    /// The result was too large to be represented by `Decimal`.
+    Overflow,
+    /// The result was rounded, even if the discarded digits were all zero.
+    Rounded,
+}
+
+impl Signal {
+    const fn bit(self) -> u8 {
+        match self {
+            Signal::Clamped => 1,
+            Signal::DivisionByZero => 1 << 1,
+            Signal::Inexact => 1 << 2,
+            Signal::Overflow => 1 << 3,
+            Signal::Rounded => 1 << 4,
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Signal::Clamped => "Clamped",
+            Signal::DivisionByZero => "DivisionByZero",
+            Signal::Inexact => "Inexact",
+            Signal::Overflow => "Overflow",
+            Signal::Rounded => "Rounded",
+        };
+        f.pad(name)
+    }
+}
+
+impl BitOr for Signal {
+    type Output = Signals;
+
+    #[inline]
+    fn bitor(self, rhs: Signal) -> Signals {
+        Signals::new().with(self).with(rhs)
+    }
+}
+
+/// A set of [`Signal`]s, used for both the traps and the status flags of a [`Context`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Signals(u8);
+
+impl Signals {
+    /// Creates an empty set of signals.
+    #[must_use]
+    pub const fn new() -> Signals {
+        Signals(0)
+    }
+
+    /// Returns a copy of this set with the given signal added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Signal, Signals};
+    /// let signals = Signals::new().with(Signal::Inexact);
+    /// assert!(signals.contains(Signal::Inexact));
+    /// assert!(!signals.contains(Signal::Rounded));
+    /// ```
+    #[must_use]
+    pub const fn with(self, signal: Signal) -> Signals {
+        Signals(self.0 | signal.bit())
+    }
+
+    /// Returns `true` if the given signal is within this set.
+    #[must_use]
+    pub const fn contains(&self, signal: Signal) -> bool {
+        self.0 & signal.bit() != 0
+    }
+
+    /// Returns `true` if this set contains no signals.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Adds the given signal to this set.
+    pub fn insert(&mut self, signal: Signal) {
+        self.0 |= signal.bit();
+    }
+
+    /// Removes the given signal from this set.
+    pub fn remove(&mut self, signal: Signal) {
+        self.0 &= !signal.bit();
+    }
+}
+
+impl From<Signal> for Signals {
+    #[inline]
+    fn from(signal: Signal) -> Signals {
+        Signals::new().with(signal)
+    }
+}
+
+impl BitOr<Signal> for Signals {
+    type Output = Signals;
+
+    #[inline]
+    fn bitor(self, rhs: Signal) -> Signals {
+        self.with(rhs)
+    }
+}
+
+impl fmt::Debug for Signals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const ALL: [Signal; 5] = [
+            Signal::Clamped,
+            Signal::DivisionByZero,
+            Signal::Inexact,
+            Signal::Overflow,
+            Signal::Rounded,
+        ];
+        f.debug_set()
+            .entries(ALL.iter().filter(|signal| self.contains(**signal)))
+            .finish()
+    }
+}
+
+/// An arithmetic context in the spirit of the General Decimal Arithmetic specification.
+///
+/// A context carries a precision (the maximum number of significant digits of a result), a
+/// [`RoundingStrategy`] that is applied whenever a result needs to be rounded and a set of
+/// trapped [`Signal`]s. Every operation performed through the context rounds the exact result
+/// exactly once, and records any signals that were raised within the context's status flags
+/// so they can be inspected after a batch of calculations.
+///
+/// If a raised signal is trapped then the operation returns [`Error::Trapped`]. Since `Decimal`
+/// cannot represent infinities, [`Signal::Overflow`] and [`Signal::DivisionByZero`] always
+/// result in an error regardless of whether they are trapped.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Context, Decimal, RoundingStrategy, Signal};
+/// # use rust_decimal_macros::dec;
+/// #
+/// let mut ctx = Context::new(5, RoundingStrategy::MidpointAwayFromZero);
+/// assert_eq!(ctx.div(dec!(2), dec!(3)), Ok(dec!(0.66667)));
+/// assert!(ctx.status().contains(Signal::Inexact));
+///
+/// ctx.clear_status();
+/// assert_eq!(ctx.mul(dec!(1.25), dec!(2)), Ok(dec!(2.50)));
+/// assert!(ctx.status().is_empty());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Context {
+    precision: u32,
+    rounding: RoundingStrategy,
+    traps: Signals,
+    status: Signals,
+}
+
+impl Default for Context {
+    /// A context using the maximum precision along with `MidpointNearestEven` rounding. No
+    /// signals are trapped.
+    fn default() -> Context {
+        Context::new(Context::MAX_PRECISION, RoundingStrategy::MidpointNearestEven)
+    }
+}
+
+impl Context {
+    /// The maximum precision supported by a context. This is the number of digits in
+    /// `Decimal::MAX`; results that don't fit within 96 bits are rounded to one digit less.
+    pub const MAX_PRECISION: u32 = MAX_PRECISION_U32;
+
+    /// Creates a new context with the given precision and rounding strategy. No signals are
+    /// trapped.
+    ///
+    /// The precision is clamped to be between `1` and `Context::MAX_PRECISION` inclusive.
+    #[must_use]
+    pub const fn new(precision: u32, rounding: RoundingStrategy) -> Context {
+        let precision = if precision == 0 {
+            1
+        } else if precision > Context::MAX_PRECISION {
+            Context::MAX_PRECISION
+        } else {
+            precision
+        };
+        Context {
+            precision,
+            rounding,
+            traps: Signals::new(),
+            status: Signals::new(),
+        }
+    }
+
+    /// Returns a copy of this context with the given signals trapped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Context, Error, RoundingStrategy, Signal, Signals};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let mut ctx = Context::new(28, RoundingStrategy::MidpointNearestEven).with_traps(Signal::Inexact.into());
+    /// assert_eq!(ctx.div(dec!(1), dec!(4)), Ok(dec!(0.25)));
+    /// assert_eq!(ctx.div(dec!(1), dec!(3)), Err(Error::Trapped(Signal::Inexact)));
+    /// ```
+    #[must_use]
+    pub const fn with_traps(mut self, traps: Signals) -> Context {
+        self.traps = traps;
+        self
+    }
+
+    /// The maximum number of significant digits of a result.
+    #[must_use]
+    pub const fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// The rounding strategy applied when a result needs to be rounded.
+    #[must_use]
+    pub const fn rounding(&self) -> RoundingStrategy {
+        self.rounding
+    }
+
+    /// The signals that cause an operation to return an error when raised.
+    #[must_use]
+    pub const fn traps(&self) -> Signals {
+        self.traps
+    }
+
+    /// The signals that have been raised since the status was last cleared.
+    #[must_use]
+    pub const fn status(&self) -> Signals {
+        self.status
+    }
+
+    /// Sets the precision of the context, clamped to be between `1` and
+    /// `Context::MAX_PRECISION` inclusive.
+    pub fn set_precision(&mut self, precision: u32) {
+        self.precision = Context::new(precision, self.rounding).precision;
+    }
+
+    /// Sets the rounding strategy of the context.
+    pub fn set_rounding(&mut self, rounding: RoundingStrategy) {
+        self.rounding = rounding;
+    }
+
+    /// Sets the signals that cause an operation to return an error when raised.
+    pub fn set_traps(&mut self, traps: Signals) {
+        self.traps = traps;
+    }
+
+    /// Clears the status flags of the context.
+    pub fn clear_status(&mut self) {
+        self.status = Signals::new();
+    }
+
+    /// Rounds the given value to the precision of this context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Context, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let mut ctx = Context::new(3, RoundingStrategy::ToZero);
+    /// assert_eq!(ctx.round(dec!(3.14159)), Ok(dec!(3.14)));
+    /// assert_eq!(ctx.round(dec!(12345)), Ok(dec!(12300)));
+    /// ```
+    pub fn round(&mut self, value: Decimal) -> crate::Result<Decimal> {
+        let result = DecWide::from_decimal(&value).round(self.target(), self.rounding);
+        self.finish(result, value.is_sign_negative())
+    }
+
+    /// Adds two decimals, rounding the exact sum to the precision of this context.
+    pub fn add(&mut self, a: Decimal, b: Decimal) -> crate::Result<Decimal> {
+        let result = DecWide::from_sum(&a, &b).and_then(|sum| sum.round(self.target(), self.rounding));
+        self.finish(result, sum_is_negative(&a, &b))
+    }
+
+    /// Subtracts `b` from `a`, rounding the exact difference to the precision of this context.
+    pub fn sub(&mut self, a: Decimal, b: Decimal) -> crate::Result<Decimal> {
+        self.add(a, -b)
+    }
+
+    /// Multiplies two decimals, rounding the exact product to the precision of this context.
+    pub fn mul(&mut self, a: Decimal, b: Decimal) -> crate::Result<Decimal> {
+        let negative = a.is_sign_negative() ^ b.is_sign_negative();
+        let result = DecWide::from_product(&a, &b).and_then(|product| product.round(self.target(), self.rounding));
+        self.finish(result, negative)
+    }
+
+    /// Divides `a` by `b`, rounding the exact quotient to the precision of this context.
+    pub fn div(&mut self, a: Decimal, b: Decimal) -> crate::Result<Decimal> {
+        if b.is_zero() {
+            return self.raise_division_by_zero();
+        }
+        let negative = a.is_sign_negative() ^ b.is_sign_negative();
+        // Truncating beyond the maximum scale guarantees that at least one digit is rounded away,
+        // which is what allows the remainder to be folded into the rounding decision.
+        let (quotient, scale, sticky) = div_truncated(&a, &b, MAX_SCALE_U32 + 1);
+        let result = round_exact(&quotient, scale, negative, sticky, self.target(), self.rounding);
+        self.finish(result, negative)
+    }
+
+    /// Calculates the remainder of `a` divided by `b`, rounded to the precision of this context.
+    pub fn rem(&mut self, a: Decimal, b: Decimal) -> crate::Result<Decimal> {
+        match ops::rem_impl(&a, &b) {
+            CalculationResult::Ok(rem) => self.round(rem),
+            CalculationResult::Overflow => self.finish(None, a.is_sign_negative()),
+            CalculationResult::DivByZero => self.raise_division_by_zero(),
+        }
+    }
+
+    const fn target(&self) -> Target {
+        Target::Precision(self.precision)
+    }
+
+    fn raise_division_by_zero(&mut self) -> crate::Result<Decimal> {
+        self.status.insert(Signal::DivisionByZero);
+        Err(Error::DivisionByZero)
+    }
+
+    fn finish(&mut self, result: Option<Rounded>, negative: bool) -> crate::Result<Decimal> {
+        let Some(rounded) = result else {
+            self.status.insert(Signal::Overflow);
+            return Err(if negative {
+                Error::LessThanMinimumPossibleValue
+            } else {
+                Error::ExceedsMaximumPossibleValue
+            });
+        };
+
+        let mut raised = Signals::new();
+        if rounded.inexact {
+            raised.insert(Signal::Inexact);
+        }
+        if rounded.rounded {
+            raised.insert(Signal::Rounded);
+        }
+        if rounded.clamped {
+            raised.insert(Signal::Clamped);
+        }
+        self.status = Signals(self.status.0 | raised.0);
+
+        let trapped = Signals(raised.0 & self.traps.0);
+        for signal in [Signal::Inexact, Signal::Rounded, Signal::Clamped] {
+            if trapped.contains(signal) {
+                return Err(Error::Trapped(signal));
+            }
+        }
+        Ok(rounded.value)
+    }
+}
+
+// The sign of an overflowing sum is that of the operand with the larger magnitude.
+fn sum_is_negative(a: &Decimal, b: &Decimal) -> bool {
+    if a.is_sign_negative() == b.is_sign_negative() || a.abs() >= b.abs() {
+        a.is_sign_negative()
+    } else {
+        b.is_sign_negative()
+    }
+}
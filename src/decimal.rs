@@ -4,6 +4,9 @@ use crate::constants::{
     UNSIGN_MASK,
 };
 use crate::ops;
+use crate::ops::wide::{DecWide, Target};
+use crate::parse::ParseOptions;
+use crate::ratio::{self, DecimalRatio};
 use core::{
     cmp::{Ordering::Equal, *},
     fmt,
@@ -157,6 +160,76 @@ pub enum RoundingStrategy {
     ToNegativeInfinity,
     /// The number is always rounded towards positive infinity. e.g. 6.8 -> 7, -6.8 -> -6
     ToPositiveInfinity,
+    /// When a number is halfway between two others, it is rounded toward the nearest number that
+    /// is toward negative infinity. e.g. 6.4 -> 6, 6.5 -> 6, -6.5 -> -7
+    MidpointTowardNegativeInfinity,
+    /// When a number is halfway between two others, it is rounded toward the nearest number that
+    /// is toward positive infinity. e.g. 6.4 -> 6, 6.5 -> 7, -6.5 -> -6
+    MidpointTowardPositiveInfinity,
+    /// The number is rounded toward zero, unless doing so discards a non-zero amount and leaves
+    /// an even last digit, in which case it is rounded away from zero. e.g. 6.2 -> 7, 7.8 -> 7,
+    /// 6.0 -> 6, -6.2 -> -7
+    ///
+    /// Rounding an intermediate result to odd with at least two extra digits allows it to be
+    /// rounded again later without suffering from double rounding errors.
+    ToOdd,
+    /// The number is rounded toward zero, unless doing so discards a non-zero amount and leaves
+    /// a last digit of 0 or 5, in which case it is rounded away from zero. Also known as "05UP".
+    /// e.g. 6.2 -> 6, 5.2 -> 6, 10.2 -> 11, 5.0 -> 5, -5.2 -> -6
+    ///
+    /// Like [`RoundingStrategy::ToOdd`], this avoids double rounding errors when an intermediate
+    /// result keeps at least one extra digit.
+    ZeroFiveUp,
+}
+
+/// Describes how the digits discarded while rounding compare to half a unit in the last place
+/// that is kept.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Discarded {
+    /// Nothing of value was discarded, i.e. the result is exact.
+    Zero,
+    /// Less than half a unit was discarded.
+    BelowHalf,
+    /// Exactly half a unit was discarded.
+    Half,
+    /// More than half a unit was discarded.
+    AboveHalf,
+}
+
+impl RoundingStrategy {
+    /// Determines whether a truncated magnitude should be incremented by one unit in the last
+    /// place. `last_digit` is the least significant decimal digit of the truncated magnitude.
+    pub(crate) const fn rounds_away(self, negative: bool, last_digit: u32, discarded: Discarded) -> bool {
+        match (self, discarded) {
+            (_, Discarded::Zero) => false,
+            (
+                RoundingStrategy::MidpointNearestEven
+                | RoundingStrategy::MidpointAwayFromZero
+                | RoundingStrategy::MidpointTowardZero
+                | RoundingStrategy::MidpointTowardNegativeInfinity
+                | RoundingStrategy::MidpointTowardPositiveInfinity,
+                Discarded::AboveHalf,
+            ) => true,
+            (RoundingStrategy::MidpointNearestEven, Discarded::Half) => last_digit & 1 == 1,
+            (RoundingStrategy::MidpointAwayFromZero, Discarded::Half) => true,
+            (RoundingStrategy::MidpointTowardNegativeInfinity, Discarded::Half) => negative,
+            (RoundingStrategy::MidpointTowardPositiveInfinity, Discarded::Half) => !negative,
+            (
+                RoundingStrategy::MidpointNearestEven
+                | RoundingStrategy::MidpointAwayFromZero
+                | RoundingStrategy::MidpointTowardZero
+                | RoundingStrategy::MidpointTowardNegativeInfinity
+                | RoundingStrategy::MidpointTowardPositiveInfinity,
+                _,
+            ) => false,
+            (RoundingStrategy::ToZero, _) => false,
+            (RoundingStrategy::AwayFromZero, _) => true,
+            (RoundingStrategy::ToNegativeInfinity, _) => negative,
+            (RoundingStrategy::ToPositiveInfinity, _) => !negative,
+            (RoundingStrategy::ToOdd, _) => last_digit & 1 == 0,
+            (RoundingStrategy::ZeroFiveUp, _) => last_digit == 0 || last_digit == 5,
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -660,20 +733,14 @@ impl Decimal {
     pub fn from_scientific_lossy(value: &str) -> Result<Decimal, Error> {
         let mut split = value.splitn(2, ['e', 'E']);
 
-        let base = split
-            .next()
-            .ok_or_else(|| Error::from(crate::Error::FailedToParseScientificFromString))?;
-        let exp = split
-            .next()
-            .ok_or_else(|| Error::from(crate::Error::FailedToParseScientificFromString))?;
+        let base = split.next().ok_or(Error::FailedToParseScientificFromString)?;
+        let exp = split.next().ok_or(Error::FailedToParseScientificFromString)?;
 
         let mut ret = Decimal::from_str(base)?;
         let current_scale = ret.scale();
 
         if let Some(stripped) = exp.strip_prefix('-') {
-            let exp: u32 = stripped
-                .parse()
-                .map_err(|_| Error::from(crate::Error::FailedToParseScientificFromString))?;
+            let exp: u32 = stripped.parse().map_err(|_| Error::FailedToParseScientificFromString)?;
             if exp > Self::MAX_SCALE {
                 return Err(Error::ScaleExceedsMaximumPrecision(exp));
             }
@@ -684,9 +751,7 @@ impl Decimal {
                 ret.set_scale(current_scale + exp)?;
             }
         } else {
-            let exp: u32 = exp
-                .parse()
-                .map_err(|_| Error::from(crate::Error::FailedToParseScientificFromString))?;
+            let exp: u32 = exp.parse().map_err(|_| Error::FailedToParseScientificFromString)?;
             if exp <= current_scale {
                 ret.set_scale(current_scale - exp)?;
             } else if exp > 0 {
@@ -781,6 +846,106 @@ impl Decimal {
         crate::str::parse_str_radix_10_exact(str)
     }
 
+    /// Parses a string slice into a decimal. If the value has more digits than can be represented
+    /// then it is rounded using the given [`RoundingStrategy`], rather than the midpoint away from
+    /// zero rounding used by `from_str`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// #
+    /// # fn main() -> Result<(), rust_decimal::Error> {
+    /// let value = "0.00000_00000_00000_00000_00000_0015";
+    /// assert_eq!(Decimal::from_str_with_strategy(value, RoundingStrategy::MidpointNearestEven)?.to_string(), "0.0000000000000000000000000002");
+    /// assert_eq!(Decimal::from_str_with_strategy(value, RoundingStrategy::ToZero)?.to_string(), "0.0000000000000000000000000001");
+    /// assert_eq!(Decimal::from_str_with_strategy("1.5", RoundingStrategy::ToZero)?.to_string(), "1.5");
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_str_with_strategy(str: &str, strategy: RoundingStrategy) -> Result<Self, crate::Error> {
+        crate::str::parse_str_radix_10_with_strategy(str, strategy)
+    }
+
+    /// Parses a string slice into a decimal, accepting the syntax described by the given
+    /// [`ParseOptions`]. This allows for alternative separators, exponents and trailing signs
+    /// along with stricter parsing than `from_str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Error, ParseOptions};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let options = ParseOptions::new()
+    ///     .with_grouping_separator(',')
+    ///     .with_exponent(true)
+    ///     .with_rounding(None);
+    /// assert_eq!(Decimal::parse_with("1,234.5e-2", &options), Ok(dec!(12.345)));
+    /// assert_eq!(Decimal::parse_with("1e-29", &options), Err(Error::Underflow));
+    ///
+    /// let strict = ParseOptions::new().with_plus_sign(false).with_underscores(false);
+    /// assert_eq!(Decimal::parse_with("1_000", &strict), Err(Error::InvalidCharacter));
+    /// ```
+    pub fn parse_with(str: &str, options: &ParseOptions) -> Result<Self, crate::Error> {
+        crate::str::parse_str_radix_10_with_options(str, options)
+    }
+
+    /// Parses a string slice into a decimal in a `const` context, panicking if the string is not
+    /// a valid decimal. This accepts the same syntax as `from_str` along with an optional
+    /// exponent, which makes it convenient for readable constants without the `macros` feature.
+    /// Values with more digits than can be represented are rounded at the midpoint away from zero.
+    ///
+    /// When used to initialize a `const` an invalid string is reported at compile time.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the string is not a valid decimal or the value exceeds the
+    /// representable range. See [`Decimal::try_from_str_const`] for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// #
+    /// const ONE_THIRD: Decimal = Decimal::from_str_const("0.3333_3333_3333_3333_3333_3333_3333_33");
+    /// const AVOGADRO: Decimal = Decimal::from_str_const("6.022_140_76e23");
+    /// assert_eq!(ONE_THIRD.to_string(), "0.3333333333333333333333333333");
+    /// assert_eq!(AVOGADRO.to_string(), "602214076000000000000000");
+    /// ```
+    #[must_use]
+    pub const fn from_str_const(str: &str) -> Decimal {
+        match crate::str::parse_str_const(str) {
+            Ok(value) => value,
+            Err(Error::EmptyData) => panic!("Failed to parse decimal: empty string"),
+            Err(Error::NoDigits) => panic!("Failed to parse decimal: no digits"),
+            Err(Error::DuplicatedDecimalPoint) => panic!("Failed to parse decimal: duplicated decimal point"),
+            Err(Error::InvalidLeadingChar) => panic!("Failed to parse decimal: invalid leading character"),
+            Err(Error::FailedToParseScientificFromString) => panic!("Failed to parse decimal: invalid exponent"),
+            Err(Error::ExceedsMaximumPossibleValue) => panic!("Failed to parse decimal: value out of range"),
+            Err(_) => panic!("Failed to parse decimal: invalid character"),
+        }
+    }
+
+    /// Parses a string slice into a decimal in a `const` context. This accepts the same syntax as
+    /// [`Decimal::from_str_const`], returning an error rather than panicking on invalid input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Error};
+    /// #
+    /// const PARSED: Result<Decimal, Error> = Decimal::try_from_str_const("1_000.25e-2");
+    /// assert_eq!(PARSED.unwrap().to_string(), "10.0025");
+    /// assert_eq!(Decimal::try_from_str_const("1.2.3"), Err(Error::DuplicatedDecimalPoint));
+    /// assert_eq!(Decimal::try_from_str_const("1e30"), Err(Error::ExceedsMaximumPossibleValue));
+    /// ```
+    pub const fn try_from_str_const(str: &str) -> crate::Result<Decimal> {
+        crate::str::parse_str_const(str)
+    }
+
     /// Returns a string representation that is similar to [`alloc::string::ToString`] but
     /// doesn't require a heap allocation.
     ///
@@ -1003,7 +1168,7 @@ impl Decimal {
     /// assert_eq!(number.to_string(), "11.764705882352940000000000000");
     /// assert_eq!(number.scale(), 27);
     /// ```
-    pub fn rescale(&mut self, scale: u32) {
+    pub const fn rescale(&mut self, scale: u32) {
         let mut array = [self.lo, self.mid, self.hi];
         let mut value_scale = self.scale();
         ops::array::rescale_internal(&mut array, &mut value_scale, scale);
@@ -1278,8 +1443,11 @@ impl Decimal {
     /// assert_eq!(y, x.max(y));
     /// ```
     #[must_use]
-    pub fn max(self, other: Decimal) -> Decimal {
-        if self < other { other } else { self }
+    pub const fn max(self, other: Decimal) -> Decimal {
+        match ops::cmp_impl(&self, &other) {
+            Ordering::Less => other,
+            _ => self,
+        }
     }
 
     /// Returns the minimum of the two numbers.
@@ -1292,8 +1460,28 @@ impl Decimal {
     /// assert_eq!(x, x.min(y));
     /// ```
     #[must_use]
-    pub fn min(self, other: Decimal) -> Decimal {
-        if self > other { other } else { self }
+    pub const fn min(self, other: Decimal) -> Decimal {
+        match ops::cmp_impl(&self, &other) {
+            Ordering::Greater => other,
+            _ => self,
+        }
+    }
+
+    /// Compares two numbers by value, ignoring any difference in scale. This is equivalent to
+    /// [`Ord::cmp`], but may be used in a constant context.
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use rust_decimal::Decimal;
+    /// #
+    /// const LIMIT: Decimal = Decimal::from_parts(1000, 0, 0, false, 2);
+    /// const ORDER: Ordering = Decimal::ONE.cmp(&Decimal::TEN.min(LIMIT));
+    /// assert_eq!(ORDER, Ordering::Less);
+    /// assert_eq!(Decimal::TEN.cmp(&LIMIT), Ordering::Equal);
+    /// ```
+    #[must_use]
+    pub const fn cmp(&self, other: &Decimal) -> Ordering {
+        ops::cmp_impl(self, other)
     }
 
     /// Strips any trailing zero's from a `Decimal` and converts -0 to 0.
@@ -1309,7 +1497,7 @@ impl Decimal {
     /// # }
     /// ```
     #[must_use]
-    pub fn normalize(&self) -> Decimal {
+    pub const fn normalize(&self) -> Decimal {
         let mut result = *self;
         result.normalize_assign();
         result
@@ -1329,7 +1517,7 @@ impl Decimal {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn normalize_assign(&mut self) {
+    pub const fn normalize_assign(&mut self) {
         if self.is_zero() {
             self.flags = 0;
             return;
@@ -1347,7 +1535,7 @@ impl Decimal {
                 break;
             }
             scale -= 1;
-            result.copy_from_slice(&working);
+            result = working;
         }
         self.lo = result[0];
         self.mid = result[1];
@@ -1370,7 +1558,7 @@ impl Decimal {
     /// assert_eq!(number_up.round().to_string(), "8");
     /// ```
     #[must_use]
-    pub fn round(&self) -> Decimal {
+    pub const fn round(&self) -> Decimal {
         self.round_dp(0)
     }
 
@@ -1392,7 +1580,7 @@ impl Decimal {
     /// assert_eq!(tax.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero).to_string(), "3.44");
     /// ```
     #[must_use]
-    pub fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> Decimal {
+    pub const fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> Decimal {
         let old_scale = self.scale();
 
         // return early if decimal has a smaller number of fractional places than dp
@@ -1468,18 +1656,18 @@ impl Decimal {
 
         // If the decimal_portion is zero then we round based on the other data
         let mut cap = [5, 0, 0];
-        for _ in 0..(old_scale - dp - 1) {
+        let mut i = dp + 1;
+        while i < old_scale {
             ops::array::mul_by_u32(&mut cap, 10);
+            i += 1;
         }
         let order = ops::array::cmp_internal(&decimal_portion, &cap);
 
         match strategy {
             RoundingStrategy::MidpointNearestEven => {
                 match order {
-                    Ordering::Equal => {
-                        if (value[0] & 1) == 1 {
-                            ops::array::add_one_internal(&mut value);
-                        }
+                    Ordering::Equal if (value[0] & 1) == 1 => {
+                        ops::array::add_one_internal(&mut value);
                     }
                     Ordering::Greater => {
                         // Doesn't matter about the decimal portion
@@ -1522,6 +1710,36 @@ impl Decimal {
                     ops::array::add_one_internal(&mut value);
                 }
             }
+            RoundingStrategy::MidpointTowardNegativeInfinity => match order {
+                Ordering::Equal if negative => {
+                    ops::array::add_one_internal(&mut value);
+                }
+                Ordering::Greater => {
+                    ops::array::add_one_internal(&mut value);
+                }
+                _ => {}
+            },
+            RoundingStrategy::MidpointTowardPositiveInfinity => match order {
+                Ordering::Equal if !negative => {
+                    ops::array::add_one_internal(&mut value);
+                }
+                Ordering::Greater => {
+                    ops::array::add_one_internal(&mut value);
+                }
+                _ => {}
+            },
+            RoundingStrategy::ToOdd => {
+                if (value[0] & 1) == 0 && !ops::array::is_all_zero(&decimal_portion) {
+                    ops::array::add_one_internal(&mut value);
+                }
+            }
+            RoundingStrategy::ZeroFiveUp => {
+                // The last digit is 0 or 5 exactly when the value is a multiple of 5
+                let mut quotient = value;
+                if ops::array::div_by_u32(&mut quotient, 5) == 0 && !ops::array::is_all_zero(&decimal_portion) {
+                    ops::array::add_one_internal(&mut value);
+                }
+            }
             RoundingStrategy::ToZero => (),
         }
 
@@ -1544,7 +1762,7 @@ impl Decimal {
     /// assert_eq!(pi.round_dp(2).to_string(), "3.14");
     /// ```
     #[must_use]
-    pub fn round_dp(&self, dp: u32) -> Decimal {
+    pub const fn round_dp(&self, dp: u32) -> Decimal {
         self.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven)
     }
 
@@ -1711,6 +1929,144 @@ impl Decimal {
         }
     }
 
+    /// Returns a new `Decimal` rounded to a multiple of `10^exp` using the provided
+    /// [`RoundingStrategy`].
+    ///
+    /// A positive `exp` rounds to the nearest ten (`1`), hundred (`2`), thousand (`3`) and so on,
+    /// in which case the result has a scale of zero. A negative `exp` behaves the same as
+    /// [`Decimal::round_dp_with_strategy`] with `-exp` decimal places.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the rounded value overflows, e.g. when rounding up near
+    /// `Decimal::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let value = dec!(123456.78);
+    /// assert_eq!(value.round_to_power_of_ten(3, RoundingStrategy::MidpointNearestEven), dec!(123000));
+    /// assert_eq!(value.round_to_power_of_ten(3, RoundingStrategy::AwayFromZero), dec!(124000));
+    /// assert_eq!(value.round_to_power_of_ten(0, RoundingStrategy::MidpointNearestEven), dec!(123457));
+    /// assert_eq!(value.round_to_power_of_ten(-1, RoundingStrategy::MidpointNearestEven), dec!(123456.8));
+    /// ```
+    #[must_use]
+    pub fn round_to_power_of_ten(&self, exp: i32, strategy: RoundingStrategy) -> Decimal {
+        match self.checked_round_to_power_of_ten(exp, strategy) {
+            Some(rounded) => rounded,
+            None => panic!("Rounding to a power of ten overflowed"),
+        }
+    }
+
+    /// Checked rounding to a power of ten. Computes [`Decimal::round_to_power_of_ten`],
+    /// returning `None` if overflow occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(Decimal::MAX.checked_round_to_power_of_ten(3, RoundingStrategy::ToZero), Some(dec!(79228162514264337593543950000)));
+    /// assert_eq!(Decimal::MAX.checked_round_to_power_of_ten(3, RoundingStrategy::AwayFromZero), None);
+    /// ```
+    #[must_use]
+    pub fn checked_round_to_power_of_ten(&self, exp: i32, strategy: RoundingStrategy) -> Option<Decimal> {
+        DecWide::from_decimal(self)
+            .round(Target::Exponent(exp), strategy)
+            .map(|rounded| rounded.value)
+    }
+
+    /// Returns a new `Decimal` rounded to the nearest multiple of `increment` using the provided
+    /// [`RoundingStrategy`]. This is useful for cash rounding, exchange tick sizes and lot sizes.
+    /// The sign of `increment` is ignored, and the result has the same scale as `increment`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `increment` is zero or if the result overflows. The number of
+    /// increments must also be representable as a `Decimal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// // Swiss cash rounding
+    /// assert_eq!(dec!(1.23).round_to_increment(dec!(0.05), RoundingStrategy::MidpointAwayFromZero), dec!(1.25));
+    /// assert_eq!(dec!(1.225).round_to_increment(dec!(0.05), RoundingStrategy::MidpointAwayFromZero), dec!(1.25));
+    /// assert_eq!(dec!(1.225).round_to_increment(dec!(0.05), RoundingStrategy::MidpointNearestEven), dec!(1.20));
+    /// // Lot sizes
+    /// assert_eq!(dec!(1234).round_to_increment(dec!(100), RoundingStrategy::ToZero), dec!(1200));
+    /// ```
+    #[must_use]
+    pub fn round_to_increment(&self, increment: Decimal, strategy: RoundingStrategy) -> Decimal {
+        if increment.is_zero() {
+            panic!("Rounding to an increment of zero");
+        }
+        match self.checked_round_to_increment(increment, strategy) {
+            Some(rounded) => rounded,
+            None => panic!("Rounding to increment overflowed"),
+        }
+    }
+
+    /// Checked rounding to an increment. Computes [`Decimal::round_to_increment`], returning
+    /// `None` if `increment` is zero or overflow occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(10.13).checked_round_to_increment(dec!(0.25), RoundingStrategy::ToPositiveInfinity), Some(dec!(10.25)));
+    /// assert_eq!(dec!(10.13).checked_round_to_increment(Decimal::ZERO, RoundingStrategy::ToPositiveInfinity), None);
+    /// assert_eq!(Decimal::MAX.checked_round_to_increment(dec!(10), RoundingStrategy::AwayFromZero), None);
+    /// ```
+    #[must_use]
+    pub fn checked_round_to_increment(&self, increment: Decimal, strategy: RoundingStrategy) -> Option<Decimal> {
+        let increment = increment.abs();
+        // Rounding the exact quotient means the value is only ever rounded once
+        let multiple = self.div_to_integer(increment, strategy)?;
+        // The product is exact, so rather than being rounded to fit it fails if the multiple can't
+        // be held at the scale of `increment`
+        DecWide::from_product(&multiple, &increment)?
+            .round(Target::Scale(increment.scale()), strategy)
+            .map(|rounded| rounded.value)
+    }
+
+    /// Returns a new `Decimal` truncated to a multiple of `increment`, i.e. rounded toward zero.
+    /// The sign of `increment` is ignored, and the result has the same scale as `increment`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `increment` is zero or if the number of increments can't be
+    /// represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(10.49).trunc_to_increment(dec!(0.25)), dec!(10.25));
+    /// assert_eq!(dec!(-10.49).trunc_to_increment(dec!(0.25)), dec!(-10.25));
+    /// ```
+    #[must_use]
+    pub fn trunc_to_increment(&self, increment: Decimal) -> Decimal {
+        self.round_to_increment(increment, RoundingStrategy::ToZero)
+    }
+
+    /// Checked truncation to an increment. Computes [`Decimal::trunc_to_increment`], returning
+    /// `None` if `increment` is zero or overflow occurred.
+    #[must_use]
+    pub fn checked_trunc_to_increment(&self, increment: Decimal) -> Option<Decimal> {
+        self.checked_round_to_increment(increment, RoundingStrategy::ToZero)
+    }
+
     /// Convert `Decimal` to an internal representation of the underlying struct. This is useful
     /// for debugging the internal state of the object.
     ///
@@ -1812,6 +2168,192 @@ impl Decimal {
     pub fn from_f64_retain(n: f64) -> Option<Self> {
         from_f64(n, false)
     }
+
+    /// Converts this decimal to the nearest 64-bit float in the direction given by the
+    /// [`RoundingStrategy`]. The midpoint strategies choose the nearest float, while the directed
+    /// strategies choose one of the two floats surrounding the exact value. The result is always
+    /// finite, and is exact whenever the decimal can be represented as an `f64`.
+    ///
+    /// [`ToPrimitive::to_f64`] is equivalent to using [`RoundingStrategy::MidpointNearestEven`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let value = dec!(0.1);
+    /// assert_eq!(value.to_f64_with_strategy(RoundingStrategy::MidpointNearestEven), 0.1);
+    /// assert_eq!(value.to_f64_with_strategy(RoundingStrategy::ToZero), 0.09999999999999999);
+    /// assert_eq!(value.to_f64_with_strategy(RoundingStrategy::ToPositiveInfinity), 0.1);
+    /// assert_eq!(dec!(0.5).to_f64_with_strategy(RoundingStrategy::ToZero), 0.5);
+    /// ```
+    #[must_use]
+    pub fn to_f64_with_strategy(&self, strategy: RoundingStrategy) -> f64 {
+        to_float(self, f64::MANTISSA_DIGITS, strategy)
+    }
+
+    /// Converts this decimal to the nearest 32-bit float in the direction given by the
+    /// [`RoundingStrategy`]. See [`Decimal::to_f64_with_strategy`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let value = dec!(0.1);
+    /// assert_eq!(value.to_f32_with_strategy(RoundingStrategy::MidpointNearestEven), 0.1);
+    /// assert_eq!(value.to_f32_with_strategy(RoundingStrategy::ToNegativeInfinity), 0.099999994);
+    /// ```
+    #[must_use]
+    pub fn to_f32_with_strategy(&self, strategy: RoundingStrategy) -> f32 {
+        // The value is already rounded to 24 bits so this conversion is exact
+        to_float(self, f32::MANTISSA_DIGITS, strategy) as f32
+    }
+
+    /// Converts a 64-bit float into a decimal with `dp` decimal places, rounding the exact binary
+    /// value of the float once using the given [`RoundingStrategy`]. Trailing zeros are added to
+    /// reach `dp` decimal places for as long as the mantissa can hold them, and if the value
+    /// cannot be held at `dp` decimal places it is rounded to the largest scale that fits instead.
+    ///
+    /// As with [`Decimal::round_dp_with_strategy`] the rounding is based on the exact value, so
+    /// `0.125_f64` is a genuine midpoint, whereas `2.675_f64` is actually slightly below 2.675.
+    ///
+    /// Returns an error if the float is NaN, infinite or out of range, or if `dp` exceeds
+    /// `Decimal::MAX_SCALE`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Error, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let strategy = RoundingStrategy::MidpointNearestEven;
+    /// assert_eq!(Decimal::from_f64_with_scale(0.125, 2, strategy), Ok(dec!(0.12)));
+    /// assert_eq!(Decimal::from_f64_with_scale(2.675, 2, strategy), Ok(dec!(2.67)));
+    /// assert_eq!(Decimal::from_f64_with_scale(1.5, 6, strategy), Ok(dec!(1.500000)));
+    /// assert_eq!(Decimal::from_f64_with_scale(f64::NAN, 2, strategy), Err(Error::NaN));
+    /// assert_eq!(Decimal::from_f64_with_scale(-1e30, 2, strategy), Err(Error::LessThanMinimumPossibleValue));
+    /// ```
+    pub fn from_f64_with_scale(n: f64, dp: u32, strategy: RoundingStrategy) -> crate::Result<Decimal> {
+        from_f64_with_scale(n, dp, strategy)
+    }
+
+    /// Converts a 32-bit float into a decimal with `dp` decimal places, rounding the exact
+    /// binary value of the float once using the given [`RoundingStrategy`]. See
+    /// [`Decimal::from_f64_with_scale`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// let value = Decimal::from_f32_with_scale(0.1, 10, RoundingStrategy::ToZero);
+    /// assert_eq!(value, Ok(dec!(0.1000000014)));
+    /// ```
+    pub fn from_f32_with_scale(n: f32, dp: u32, strategy: RoundingStrategy) -> crate::Result<Decimal> {
+        // Every f32 is exactly representable as an f64, so this still only rounds once
+        from_f64_with_scale(f64::from(n), dp, strategy)
+    }
+
+    /// Converts a 64-bit float into the decimal with the fewest significant digits that converts
+    /// back to the same float, i.e. the digits that `Display` would produce for the float.
+    ///
+    /// Returns an error if the float is NaN, infinite or out of range, or if the shortest
+    /// representation needs more than `Decimal::MAX_SCALE` decimal places.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Error};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(Decimal::from_f64_shortest(0.1 + 0.2), Ok(dec!(0.30000000000000004)));
+    /// assert_eq!(Decimal::from_f64_shortest(1e20), Ok(dec!(100000000000000000000)));
+    /// assert_eq!(Decimal::from_f64_shortest(1.5e-29), Err(Error::Underflow));
+    /// assert_eq!(Decimal::from_f64_shortest(f64::INFINITY), Err(Error::Infinite));
+    /// ```
+    pub fn from_f64_shortest(n: f64) -> crate::Result<Decimal> {
+        decompose_f64(n)?;
+        from_shortest(n)
+    }
+
+    /// Converts a 32-bit float into the decimal with the fewest significant digits that converts
+    /// back to the same float. See [`Decimal::from_f64_shortest`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(Decimal::from_f32_shortest(0.1), Ok(dec!(0.1)));
+    /// assert_eq!(Decimal::from_f32_shortest(16777217.0), Ok(dec!(16777216)));
+    /// ```
+    pub fn from_f32_shortest(n: f32) -> crate::Result<Decimal> {
+        decompose_f64(f64::from(n))?;
+        from_shortest(n)
+    }
+
+    /// Returns the exact value of this decimal as a [`DecimalRatio`], reduced to lowest terms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::DecimalRatio;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(0.75).to_ratio(), DecimalRatio::new(3, 4));
+    /// assert_eq!(dec!(-12.500).to_ratio(), DecimalRatio::new(-25, 2));
+    /// ```
+    #[must_use]
+    pub fn to_ratio(&self) -> DecimalRatio {
+        ratio::to_ratio(self)
+    }
+
+    /// Computes `num / den` rounded to `dp` decimal places using the given strategy. Returns `None`
+    /// if `den` is zero or the result exceeds the range of a `Decimal`.
+    ///
+    /// Unlike dividing two decimals, the quotient is rounded exactly once, so directed strategies
+    /// such as [`RoundingStrategy::ToZero`] behave as expected even for repeating fractions.
+    /// If the quotient cannot be held at `dp` decimal places, it is rounded to the largest scale
+    /// that fits instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, RoundingStrategy};
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(Decimal::from_ratio(2, 3, 4, RoundingStrategy::ToZero), Some(dec!(0.6666)));
+    /// assert_eq!(Decimal::from_ratio(1, -8, 2, RoundingStrategy::MidpointNearestEven), Some(dec!(-0.12)));
+    /// assert_eq!(Decimal::from_ratio(1, 0, 2, RoundingStrategy::ToZero), None);
+    /// ```
+    #[must_use]
+    pub fn from_ratio(num: i128, den: i128, dp: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+        DecimalRatio::try_new(num, den).ok()?.to_decimal(dp, strategy)
+    }
+
+    /// Finds the fraction closest to this decimal whose denominator is no greater than
+    /// `max_denominator`, using its continued fraction expansion. A `max_denominator` below 1 is
+    /// treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::DecimalRatio;
+    /// # use rust_decimal_macros::dec;
+    /// #
+    /// assert_eq!(dec!(3.14159265358979).approximate_ratio(1000), DecimalRatio::new(355, 113));
+    /// assert_eq!(dec!(-0.3333).approximate_ratio(10), DecimalRatio::new(-1, 3));
+    /// assert_eq!(dec!(0.125).approximate_ratio(100), DecimalRatio::new(1, 8));
+    /// ```
+    #[must_use]
+    pub fn approximate_ratio(&self, max_denominator: i128) -> DecimalRatio {
+        ratio::approximate_ratio(self, max_denominator)
+    }
 }
 
 impl Default for Decimal {
@@ -2105,6 +2647,212 @@ impl FromPrimitive for Decimal {
     }
 }
 
+/// Converts a decimal into the binary floating point value with a `precision` bit significand
+/// (53 for `f64`, 24 for `f32`) using a single rounding step. The result is returned as an `f64`,
+/// which represents any `f32` value exactly.
+///
+/// The range of a decimal is well within the normal range of both float types, so there is never
+/// any overflow or subnormal rounding to consider.
+fn to_float(value: &Decimal, precision: u32, strategy: RoundingStrategy) -> f64 {
+    let negative = value.is_sign_negative();
+    let mantissa = value.mantissa().unsigned_abs();
+    if mantissa == 0 {
+        return if negative { -0.0 } else { 0.0 };
+    }
+
+    // Find `q = floor(mantissa * 2^shift / 10^scale)` with exactly `precision` bits. Estimating
+    // the shift from the bit lengths can be out by one, in which case it is reduced and retried.
+    let denominator = 10u128.pow(value.scale());
+    let mut shift =
+        i64::from(precision) + i64::from(128 - denominator.leading_zeros()) - i64::from(128 - mantissa.leading_zeros());
+    loop {
+        let mut data = [
+            mantissa as u32,
+            (mantissa >> 32) as u32,
+            (mantissa >> 64) as u32,
+            0,
+            0,
+            0,
+        ];
+        let mut divisor = denominator;
+        if shift >= 0 {
+            // The shifted mantissa is at most 149 bits, which fits comfortably
+            let mut remaining = shift as u32;
+            while remaining > 0 {
+                let step = remaining.min(64);
+                ops::wide::mul_by_u96(&mut data, 1u128 << step);
+                remaining -= step;
+            }
+        } else {
+            // Only large mantissas with small scales get here, so the divisor stays within 96 bits
+            divisor <<= -shift;
+        }
+        let remainder = ops::wide::div_by_u96(&mut data, divisor);
+        let q = u64::from(data[0]) | (u64::from(data[1]) << 32);
+        if q >> precision != 0 {
+            shift -= 1;
+            continue;
+        }
+
+        let discarded = match remainder.cmp(&(divisor - remainder)) {
+            _ if remainder == 0 => Discarded::Zero,
+            Ordering::Less => Discarded::BelowHalf,
+            Ordering::Equal => Discarded::Half,
+            Ordering::Greater => Discarded::AboveHalf,
+        };
+        // In binary the last digit is simply the lowest bit, which makes `ZeroFiveUp` behave the
+        // same as `ToOdd`
+        let q = if strategy.rounds_away(negative, (q & 1) as u32, discarded) {
+            q + 1
+        } else {
+            q
+        };
+        // Both conversions are exact: q has at most 54 bits and the power of two is in range
+        let magnitude = q as f64 * 2f64.powi(-shift as i32);
+        return if negative { -magnitude } else { magnitude };
+    }
+}
+
+/// Splits a finite float into its sign, integer significand and binary exponent, such that the
+/// value is exactly `significand * 2^exponent`.
+fn decompose_f64(n: f64) -> Result<(bool, u64, i32), Error> {
+    if n.is_nan() {
+        return Err(Error::NaN);
+    }
+    if n.is_infinite() {
+        return Err(Error::Infinite);
+    }
+    let raw = n.to_bits();
+    let biased_exponent = ((raw >> 52) & 0x7FF) as i32;
+    let mantissa = raw & 0x000F_FFFF_FFFF_FFFF;
+    let (significand, exponent) = if biased_exponent == 0 {
+        // Denormalized number
+        (mantissa, -1074)
+    } else {
+        (mantissa | 0x0010_0000_0000_0000, biased_exponent - 1075)
+    };
+    Ok((raw >> 63 == 1, significand, exponent))
+}
+
+pub(crate) fn out_of_range(negative: bool) -> Error {
+    if negative {
+        Error::LessThanMinimumPossibleValue
+    } else {
+        Error::ExceedsMaximumPossibleValue
+    }
+}
+
+fn from_f64_with_scale(n: f64, dp: u32, strategy: RoundingStrategy) -> Result<Decimal, Error> {
+    let (negative, significand, exponent) = decompose_f64(n)?;
+    if dp > MAX_SCALE_U32 {
+        return Err(Error::ScaleExceedsMaximumPrecision(dp));
+    }
+
+    let mut data = [significand as u32, (significand >> 32) as u32, 0, 0, 0, 0];
+    let (scale, sticky) = if exponent >= 0 {
+        // An integer, which is out of range once it needs more than 96 bits
+        if 64 - significand.leading_zeros() + exponent as u32 > 96 {
+            return Err(out_of_range(negative));
+        }
+        ops::wide::mul_by_u96(&mut data, 1u128 << exponent);
+        (0, false)
+    } else {
+        // Calculate the digits down to one place beyond the target, i.e. the value multiplied by
+        // 10^(dp + 1), and keep track of whether anything non-zero is discarded after that. The
+        // significand has at most 53 bits so this needs no more than 150 bits.
+        let scale = dp + 1;
+        ops::wide::mul_by_pow10(&mut data, scale);
+        let shift = exponent.unsigned_abs();
+        let sticky = if shift >= 192 {
+            let sticky = !ops::array::is_all_zero(&data);
+            data = [0; 6];
+            sticky
+        } else {
+            shr_sticky(&mut data, shift)
+        };
+        (scale, sticky)
+    };
+
+    ops::wide::round_exact(&data, scale, negative, sticky, Target::MaxScale(dp), strategy)
+        .map(|rounded| rounded.value)
+        .ok_or_else(|| out_of_range(negative))
+}
+
+/// Shifts `data` right by fewer than `32 * N` bits, returning whether any of the bits shifted out
+/// were set.
+fn shr_sticky<const N: usize>(data: &mut [u32; N], shift: u32) -> bool {
+    let (words, bits) = ((shift / 32) as usize, shift % 32);
+    let sticky = data[..words].iter().any(|w| *w != 0) || data[words] & ((1 << bits) - 1) != 0;
+    for i in 0..N {
+        let lo = data.get(i + words).copied().unwrap_or(0);
+        let hi = data.get(i + words + 1).copied().unwrap_or(0);
+        data[i] = if bits == 0 {
+            lo
+        } else {
+            (lo >> bits) | (hi << (32 - bits))
+        };
+    }
+    sticky
+}
+
+/// Creates a decimal from the shortest digits that uniquely identify a float, as produced by its
+/// `LowerExp` implementation.
+fn from_shortest(n: impl fmt::LowerExp) -> Result<Decimal, Error> {
+    use fmt::Write;
+
+    let mut buffer = arrayvec::ArrayString::<64>::new();
+    write!(buffer, "{n:e}").map_err(|_| Error::FailedToParseScientificFromString)?;
+    let (digits, exponent) = buffer.split_once('e').ok_or(Error::FailedToParseScientificFromString)?;
+    let exponent: i32 = exponent.parse().map_err(|_| Error::FailedToParseScientificFromString)?;
+    let (negative, digits) = match digits.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, digits),
+    };
+
+    let mut mantissa = 0u128;
+    let mut exponent = i64::from(exponent);
+    let mut point = false;
+    for b in digits.bytes() {
+        match b {
+            b'0'..=b'9' => {
+                // A float never has more than 17 significant digits, so this can't overflow
+                mantissa = mantissa * 10 + u128::from(b - b'0');
+                if point {
+                    exponent -= 1;
+                }
+            }
+            b'.' => point = true,
+            _ => return Err(Error::FailedToParseScientificFromString),
+        }
+    }
+
+    let (mantissa, scale) = if exponent < 0 {
+        let scale = exponent.unsigned_abs();
+        if scale > u64::from(MAX_SCALE_U32) {
+            return Err(Error::Underflow);
+        }
+        (mantissa, scale as u32)
+    } else {
+        let mantissa = u32::try_from(exponent)
+            .ok()
+            .and_then(|exponent| 10u128.checked_pow(exponent))
+            .and_then(|power| mantissa.checked_mul(power))
+            .filter(|mantissa| mantissa >> 96 == 0)
+            .ok_or_else(|| out_of_range(negative))?;
+        (mantissa, 0)
+    };
+    let mut result = Decimal::from_parts(
+        mantissa as u32,
+        (mantissa >> 32) as u32,
+        (mantissa >> 64) as u32,
+        negative,
+        scale,
+    );
+    // Keep the sign of a negative zero, as `from_f64` does
+    result.set_sign_negative(negative);
+    Ok(result)
+}
+
 #[inline]
 fn from_f64(n: f64, remove_excess_bits: bool) -> Option<Decimal> {
     // Handle the case if it is NaN, Infinity or -Infinity
@@ -2400,38 +3148,12 @@ impl ToPrimitive for Decimal {
     }
 
     fn to_f64(&self) -> Option<f64> {
-        if self.scale() == 0 {
-            // If scale is zero, we are storing a 96-bit integer value, that would
-            // always fit into i128, which in turn is always representable as f64,
-            // albeit with loss of precision for values outside of -2^53..2^53 range.
-            let integer = self.to_i128();
-            integer.map(|i| i as f64)
-        } else {
-            let neg = self.is_sign_negative();
-            let mut mantissa: u128 = self.lo.into();
-            mantissa |= (self.mid as u128) << 32;
-            mantissa |= (self.hi as u128) << 64;
-            // scale is at most 28, so this fits comfortably into a u128.
-            let scale = self.scale();
-            let precision: u128 = 10_u128.pow(scale);
-            let integral_part = mantissa / precision;
-            let frac_part = mantissa % precision;
-            let frac_f64 = (frac_part as f64) / (precision as f64);
-            let integral = integral_part as f64;
-            // If there is a fractional component then we will need to add that and remove any
-            // inaccuracies that creep in during addition. Otherwise, if the fractional component
-            // is zero we can exit early.
-            if frac_f64.is_zero() {
-                if neg {
-                    return Some(-integral);
-                }
-                return Some(integral);
-            }
-            let value = integral + frac_f64;
-            let round_to = 10f64.powi(self.scale() as i32);
-            let rounded = (value * round_to).round() / round_to;
-            if neg { Some(-rounded) } else { Some(rounded) }
-        }
+        Some(self.to_f64_with_strategy(RoundingStrategy::MidpointNearestEven))
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        // The default implementation goes through `to_f64`, which would round twice
+        Some(self.to_f32_with_strategy(RoundingStrategy::MidpointNearestEven))
     }
 }
 
@@ -2650,7 +3372,7 @@ impl Hash for Decimal {
 impl PartialOrd for Decimal {
     #[inline]
     fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
-        Some(self.cmp(other))
+        Some(Ord::cmp(self, other))
     }
 }
 
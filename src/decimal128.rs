@@ -0,0 +1,561 @@
+use crate::constants::MAX_PRECISION_U32;
+use crate::decimal::out_of_range;
+use crate::decimal192::{impl_binop, parse_exact};
+use crate::ops::array::{div_by_u32, sub_by_internal};
+use crate::ops::wide::{Limits, Target, count_digits, div_rem_wide, mul_by_pow10, round_exact, round_to_fit};
+use crate::{Decimal, Decimal192, Error, RoundingStrategy};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+use num_traits::{One, Zero};
+
+/// The largest mantissa that can be represented, i.e. 38 nines.
+const MAX_MANTISSA: i128 = 10i128.pow(38) - 1;
+
+/// `Decimal128` represents a decimal number with up to 38 significant digits and a scale between
+/// 0 and 38, matching the `DECIMAL(38, s)` type found in many SQL databases as well as Arrow's
+/// `Decimal128`.
+///
+/// The mantissa is held as an `i128`, so values read from these columns can be represented
+/// without loss. Any `Decimal` converts into a `Decimal128` without loss, while converting back
+/// rounds to the 28 decimal places available to a `Decimal`. When the exact result of an
+/// operation has more than 38 digits, it is rounded once using
+/// [`RoundingStrategy::MidpointNearestEven`], keeping as many decimal places as fit.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Decimal, Decimal128};
+/// # use core::str::FromStr;
+/// #
+/// let wei = Decimal128::from_str("1234567890.123456789012345678901234").unwrap();
+/// assert_eq!(wei.scale(), 24);
+/// assert_eq!(wei.to_decimal(), Some(Decimal::from_str("1234567890.1234567890123456789").unwrap()));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Decimal128 {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal128 {
+    /// The smallest value that can be represented by this decimal type.
+    pub const MIN: Decimal128 = Decimal128::from_parts(-MAX_MANTISSA, 0);
+    /// The largest value that can be represented by this decimal type.
+    pub const MAX: Decimal128 = Decimal128::from_parts(MAX_MANTISSA, 0);
+    /// A constant representing 0.
+    pub const ZERO: Decimal128 = Decimal128::from_parts(0, 0);
+    /// A constant representing 1.
+    pub const ONE: Decimal128 = Decimal128::from_parts(1, 0);
+    /// The maximum supported scale value.
+    pub const MAX_SCALE: u32 = 38;
+    /// The maximum number of significant digits.
+    pub const MAX_PRECISION: u32 = 38;
+
+    const fn from_parts(mantissa: i128, scale: u32) -> Decimal128 {
+        Decimal128 { mantissa, scale }
+    }
+
+    /// Creates a `Decimal128` using a 128 bit signed `m` representation and corresponding `e`
+    /// scale.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `scale` is > [`Self::MAX_SCALE`] or if `num` has more than 38
+    /// digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal128;
+    /// #
+    /// let value = Decimal128::from_i128_with_scale(-12345678901234567890123456789012345678, 38);
+    /// assert_eq!(value.to_string(), "-0.12345678901234567890123456789012345678");
+    /// ```
+    #[must_use]
+    pub fn from_i128_with_scale(num: i128, scale: u32) -> Decimal128 {
+        match Self::try_from_i128_with_scale(num, scale) {
+            Err(e) => panic!("{e}"),
+            Ok(d) => d,
+        }
+    }
+
+    /// Checked version of [`Self::from_i128_with_scale`]. Will return an error instead of
+    /// panicking at run-time.
+    pub const fn try_from_i128_with_scale(num: i128, scale: u32) -> crate::Result<Decimal128> {
+        if scale > Self::MAX_SCALE {
+            Err(Error::ScaleExceedsMaximumPrecision(scale))
+        } else if num > MAX_MANTISSA {
+            Err(Error::ExceedsMaximumPossibleValue)
+        } else if num < -MAX_MANTISSA {
+            Err(Error::LessThanMinimumPossibleValue)
+        } else {
+            Ok(Decimal128::from_parts(num, scale))
+        }
+    }
+
+    /// Returns the mantissa of the decimal number.
+    #[inline]
+    #[must_use]
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the scale of the decimal number, otherwise known as `e`.
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns `true` if the decimal is negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Returns `true` if the decimal is positive.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(&self) -> bool {
+        self.mantissa >= 0
+    }
+
+    /// Returns `true` if this Decimal128 number is equivalent to zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Computes the absolute value of `self`.
+    #[inline]
+    #[must_use]
+    pub const fn abs(&self) -> Decimal128 {
+        Decimal128::from_parts(self.mantissa.abs(), self.scale)
+    }
+
+    /// Strips any trailing zeros from the decimal number, returning zero with a scale of 0 for
+    /// any representation of zero.
+    #[must_use]
+    pub fn normalize(&self) -> Decimal128 {
+        if self.is_zero() {
+            return Decimal128::ZERO;
+        }
+        let (mut mantissa, mut scale) = (self.mantissa, self.scale);
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Decimal128::from_parts(mantissa, scale)
+    }
+
+    /// Returns a new `Decimal128` number with the specified number of decimal points for
+    /// fractional portion, using Banker's Rounding.
+    #[must_use]
+    pub fn round_dp(&self, dp: u32) -> Decimal128 {
+        self.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Returns a new `Decimal128` number with the specified number of decimal points for
+    /// fractional portion, rounding with the given strategy. If the number already has `dp` or
+    /// fewer decimal places then it is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal128, RoundingStrategy};
+    /// #
+    /// let value = Decimal128::from_i128_with_scale(-12345, 4);
+    /// assert_eq!(value.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero).to_string(), "-1.24");
+    /// ```
+    #[must_use]
+    pub fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> Decimal128 {
+        // Rounding only ever removes digits, so the result always fits
+        Decimal128::from_exact(&self.words(), self.scale, self.is_sign_negative(), false, dp, strategy).unwrap_or(*self)
+    }
+
+    /// Rounds this value into a `Decimal` using Banker's Rounding, keeping as many significant
+    /// digits as fit. Returns `None` if the value exceeds the range of a `Decimal`.
+    #[must_use]
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        self.to_decimal_with_strategy(RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Rounds this value into a `Decimal` using the given strategy, keeping as many significant
+    /// digits as fit. Returns `None` if the value exceeds the range of a `Decimal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Decimal128, RoundingStrategy};
+    /// # use core::str::FromStr;
+    /// #
+    /// let value = Decimal128::from_str("0.00000000000000000000000000012345").unwrap();
+    /// let rounded = value.to_decimal_with_strategy(RoundingStrategy::AwayFromZero).unwrap();
+    /// assert_eq!(rounded, Decimal::from_str("0.0000000000000000000000000002").unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_decimal_with_strategy(&self, strategy: RoundingStrategy) -> Option<Decimal> {
+        round_exact(
+            &self.words(),
+            self.scale,
+            self.is_sign_negative(),
+            false,
+            Target::Precision(MAX_PRECISION_U32),
+            strategy,
+        )
+        .map(|rounded| rounded.value)
+    }
+
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_add(self, other: Decimal128) -> Option<Decimal128> {
+        add_sub_impl(&self, &other, false)
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_sub(self, other: Decimal128) -> Option<Decimal128> {
+        add_sub_impl(&self, &other, true)
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, other: Decimal128) -> Option<Decimal128> {
+        mul_impl(&self, &other)
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other == 0.0` or the
+    /// division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, other: Decimal128) -> Option<Decimal128> {
+        div_impl(&self, &other)
+    }
+
+    /// Checked remainder. Computes `self % other`, returning `None` if `other == 0.0`.
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, other: Decimal128) -> Option<Decimal128> {
+        rem_impl(&self, &other)
+    }
+
+    /// Rounds the exact value `data * 10^-scale` into a `Decimal128` using a single rounding step.
+    fn from_exact<const N: usize>(
+        data: &[u32; N],
+        scale: u32,
+        negative: bool,
+        sticky: bool,
+        max_scale: u32,
+        strategy: RoundingStrategy,
+    ) -> Option<Decimal128> {
+        let limits = Limits {
+            max_scale: max_scale.min(Decimal128::MAX_SCALE),
+            max_digits: Decimal128::MAX_PRECISION,
+            fits: |data: &[u32; N]| count_digits(data) <= Decimal128::MAX_PRECISION,
+        };
+        let (data, scale) = round_to_fit(data, scale, negative, sticky, limits, strategy)?;
+        let magnitude = data[..4]
+            .iter()
+            .rev()
+            .fold(0i128, |acc, word| (acc << 32) | i128::from(*word));
+        Some(Decimal128::from_parts(
+            if negative { -magnitude } else { magnitude },
+            scale,
+        ))
+    }
+
+    fn from_rounded<const N: usize>(data: &[u32; N], scale: u32, negative: bool, sticky: bool) -> Option<Decimal128> {
+        Decimal128::from_exact(
+            data,
+            scale,
+            negative,
+            sticky,
+            Decimal128::MAX_SCALE,
+            RoundingStrategy::MidpointNearestEven,
+        )
+    }
+
+    #[inline]
+    const fn words(&self) -> [u32; 4] {
+        let m = self.mantissa.unsigned_abs();
+        [m as u32, (m >> 32) as u32, (m >> 64) as u32, (m >> 96) as u32]
+    }
+
+    /// The mantissa of this value multiplied by `10^(scale - self.scale())`.
+    fn aligned(&self, scale: u32) -> [u32; 8] {
+        let [w0, w1, w2, w3] = self.words();
+        let mut data = [w0, w1, w2, w3, 0, 0, 0, 0];
+        // 38 digits scaled by at most 10^38 always fits within 256 bits
+        mul_by_pow10(&mut data, scale - self.scale);
+        data
+    }
+}
+
+fn add_sub_impl(a: &Decimal128, b: &Decimal128, subtract: bool) -> Option<Decimal128> {
+    let scale = a.scale.max(b.scale);
+    let (mut x, mut y) = (a.aligned(scale), b.aligned(scale));
+    let a_negative = a.is_sign_negative();
+    let b_negative = b.is_sign_negative() ^ subtract;
+    if a_negative == b_negative {
+        let mut carry = 0u64;
+        for (word, &other) in x.iter_mut().zip(y.iter()) {
+            carry += u64::from(*word) + u64::from(other);
+            *word = carry as u32;
+            carry >>= 32;
+        }
+        return Decimal128::from_rounded(&x, scale, a_negative, false);
+    }
+    if x.iter().rev().cmp(y.iter().rev()) != Ordering::Less {
+        sub_by_internal(&mut x, &y);
+        Decimal128::from_rounded(&x, scale, a_negative, false)
+    } else {
+        sub_by_internal(&mut y, &x);
+        Decimal128::from_rounded(&y, scale, b_negative, false)
+    }
+}
+
+fn mul_impl(a: &Decimal128, b: &Decimal128) -> Option<Decimal128> {
+    let mut product = [0u32; 8];
+    for (i, &x) in a.words().iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.words().iter().enumerate() {
+            carry += u64::from(x) * u64::from(y) + u64::from(product[i + j]);
+            product[i + j] = carry as u32;
+            carry >>= 32;
+        }
+        product[i + 4] = carry as u32;
+    }
+    let negative = a.is_sign_negative() ^ b.is_sign_negative();
+    Decimal128::from_rounded(&product, a.scale + b.scale, negative, false)
+}
+
+fn div_impl(a: &Decimal128, b: &Decimal128) -> Option<Decimal128> {
+    if b.is_zero() {
+        return None;
+    }
+    let negative = a.is_sign_negative() ^ b.is_sign_negative();
+    let natural_scale = a.scale.saturating_sub(b.scale);
+    if a.is_zero() {
+        return Some(Decimal128::from_parts(0, natural_scale));
+    }
+
+    // Scale the dividend up far enough that the quotient has at least 40 digits, leaving at least
+    // one digit to round away once the remainder has been accounted for.
+    let (a_digits, b_digits) = (count_digits(&a.words()), count_digits(&b.words()));
+    let shift = (Decimal128::MAX_PRECISION + 2 + b_digits)
+        .saturating_sub(a_digits)
+        .max(b.scale.saturating_sub(a.scale));
+    // The dividend has at most 40 + 38 digits, or 38 + 38 digits, which fits within 320 bits
+    let [w0, w1, w2, w3] = a.words();
+    let mut quotient = [w0, w1, w2, w3, 0, 0, 0, 0, 0, 0];
+    mul_by_pow10(&mut quotient, shift);
+    let remainder = div_rem_wide(&mut quotient, &b.words());
+    let sticky = remainder.iter().any(|w| *w != 0);
+
+    let mut scale = a.scale + shift - b.scale;
+    if !sticky {
+        while scale > natural_scale {
+            let mut working = quotient;
+            if div_by_u32(&mut working, 10) != 0 {
+                break;
+            }
+            quotient = working;
+            scale -= 1;
+        }
+    }
+    Decimal128::from_rounded(&quotient, scale, negative, sticky)
+}
+
+fn rem_impl(a: &Decimal128, b: &Decimal128) -> Option<Decimal128> {
+    if b.is_zero() {
+        return None;
+    }
+    let scale = a.scale.max(b.scale);
+    let (mut x, y) = (a.aligned(scale), b.aligned(scale));
+    let remainder = div_rem_wide(&mut x, &y);
+    Decimal128::from_rounded(&remainder, scale, a.is_sign_negative(), false)
+}
+
+fn add_impl(a: &Decimal128, b: &Decimal128) -> Option<Decimal128> {
+    add_sub_impl(a, b, false)
+}
+
+fn sub_impl(a: &Decimal128, b: &Decimal128) -> Option<Decimal128> {
+    add_sub_impl(a, b, true)
+}
+
+impl Default for Decimal128 {
+    /// Returns the default value for a `Decimal128` (equivalent to `Decimal128::ZERO`).
+    fn default() -> Self {
+        Decimal128::ZERO
+    }
+}
+
+impl Zero for Decimal128 {
+    fn zero() -> Decimal128 {
+        Decimal128::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        Decimal128::is_zero(self)
+    }
+}
+
+impl One for Decimal128 {
+    fn one() -> Decimal128 {
+        Decimal128::ONE
+    }
+}
+
+impl From<Decimal> for Decimal128 {
+    /// Converts a `Decimal` into a `Decimal128` without loss, retaining its scale.
+    fn from(value: Decimal) -> Decimal128 {
+        Decimal128::from_parts(value.mantissa(), value.scale())
+    }
+}
+
+impl From<Decimal128> for Decimal192 {
+    /// Converts a `Decimal128` into a `Decimal192` without loss, retaining its scale.
+    fn from(value: Decimal128) -> Decimal192 {
+        Decimal192::from_i128_with_scale(value.mantissa, value.scale)
+    }
+}
+
+impl TryFrom<Decimal128> for Decimal {
+    type Error = Error;
+
+    /// Rounds a `Decimal128` into a `Decimal` using Banker's Rounding, failing if the value
+    /// exceeds the range of a `Decimal`.
+    fn try_from(value: Decimal128) -> Result<Decimal, Error> {
+        value.to_decimal().ok_or(out_of_range(value.is_sign_negative()))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Decimal128 {
+                #[inline]
+                fn from(value: $t) -> Decimal128 {
+                    Decimal128::from_parts(i128::from(value), 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl FromStr for Decimal128 {
+    type Err = Error;
+
+    /// Parses a decimal string such as `-1_000.25`. Digits beyond the available precision are
+    /// rounded using Banker's Rounding.
+    fn from_str(value: &str) -> Result<Decimal128, Error> {
+        let parsed = parse_exact(value)?;
+        Decimal128::from_rounded(&parsed.data, parsed.scale, parsed.negative, parsed.sticky)
+            .ok_or(out_of_range(parsed.negative))
+    }
+}
+
+impl fmt::Display for Decimal128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(&Decimal192::from(*self), f)
+    }
+}
+
+impl fmt::Debug for Decimal128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl PartialEq for Decimal128 {
+    #[inline]
+    fn eq(&self, other: &Decimal128) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal128 {}
+
+impl Hash for Decimal128 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let n = self.normalize();
+        n.mantissa.hash(state);
+        n.scale.hash(state);
+    }
+}
+
+impl PartialOrd for Decimal128 {
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal128) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal128 {
+    fn cmp(&self, other: &Decimal128) -> Ordering {
+        // Identical scales are the common case, where the mantissas can be compared directly
+        if self.scale == other.scale {
+            return self.mantissa.cmp(&other.mantissa);
+        }
+        Decimal192::from(*self).cmp(&Decimal192::from(*other))
+    }
+}
+
+impl Neg for Decimal128 {
+    type Output = Decimal128;
+
+    fn neg(self) -> Decimal128 {
+        -&self
+    }
+}
+
+impl Neg for &Decimal128 {
+    type Output = Decimal128;
+
+    fn neg(self) -> Decimal128 {
+        Decimal128::from_parts(-self.mantissa, self.scale)
+    }
+}
+
+impl_binop!(impl Add for Decimal128, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl Sub for Decimal128, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl Mul for Decimal128, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl Div for Decimal128, div, DivAssign, div_assign, div_impl, "Division by zero" | "Division overflowed");
+impl_binop!(impl Rem for Decimal128, rem, RemAssign, rem_assign, rem_impl, "Division by zero" | "Division overflowed");
+
+impl Sum for Decimal128 {
+    fn sum<I: Iterator<Item = Decimal128>>(iter: I) -> Self {
+        iter.fold(Decimal128::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl<'a> Sum<&'a Decimal128> for Decimal128 {
+    fn sum<I: Iterator<Item = &'a Decimal128>>(iter: I) -> Self {
+        iter.fold(Decimal128::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl Product for Decimal128 {
+    fn product<I: Iterator<Item = Decimal128>>(iter: I) -> Self {
+        iter.fold(Decimal128::ONE, |product, value| product * value)
+    }
+}
+
+impl<'a> Product<&'a Decimal128> for Decimal128 {
+    fn product<I: Iterator<Item = &'a Decimal128>>(iter: I) -> Self {
+        iter.fold(Decimal128::ONE, |product, value| product * value)
+    }
+}
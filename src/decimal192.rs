@@ -0,0 +1,753 @@
+use crate::constants::MAX_PRECISION_U32;
+use crate::decimal::out_of_range;
+use crate::ops::array::{div_by_u32, is_all_zero, manage_add_by_internal, mul_by_u32, sub_by_internal};
+use crate::ops::wide::{
+    DecWide, MAX_WIDE_SCALE, Target, count_digits, div_rem_wide, mul_by_pow10, round_exact, round_wide,
+};
+use crate::{Decimal, Error, RoundingStrategy};
+
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+use num_traits::{One, Zero};
+
+/// The number of 32 bit words in a buffer wide enough to hold two aligned 192 bit mantissas, or
+/// their product.
+const DOUBLE_WORDS: usize = 12;
+
+/// The number of significant digits kept while parsing, beyond which digits only affect rounding.
+const MAX_PARSED_DIGITS: u32 = 80;
+
+/// `Decimal192` represents a 192 bit decimal number, allowing for roughly 57 significant digits.
+///
+/// It is intended as an intermediate type for calculations that would exhaust the 28 digits
+/// available to a [`Decimal`], such as accumulating interest over a long horizon. Any `Decimal`
+/// converts into a `Decimal192` without loss, and the result can be rounded back into a
+/// `Decimal` once the calculation is complete.
+///
+/// The scale can be anywhere between 0 and 57 inclusive. When the exact result of an operation
+/// has too many digits to be represented, it is rounded once using
+/// [`RoundingStrategy::MidpointNearestEven`], keeping as many decimal places as fit.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Decimal, Decimal192};
+/// # use core::str::FromStr;
+/// #
+/// let third = Decimal192::ONE / Decimal192::from(3);
+/// assert_eq!(third.to_string(), "0.333333333333333333333333333333333333333333333333333333333");
+/// assert_eq!(third.to_decimal(), Some(Decimal::from_str("0.3333333333333333333333333333").unwrap()));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Decimal192(DecWide);
+
+impl Decimal192 {
+    /// The smallest value that can be represented by this decimal type.
+    pub const MIN: Decimal192 = Decimal192(DecWide::from_parts([u32::MAX; 6], 0, true));
+    /// The largest value that can be represented by this decimal type.
+    pub const MAX: Decimal192 = Decimal192(DecWide::from_parts([u32::MAX; 6], 0, false));
+    /// A constant representing 0.
+    pub const ZERO: Decimal192 = Decimal192(DecWide::from_parts([0; 6], 0, false));
+    /// A constant representing 1.
+    pub const ONE: Decimal192 = Decimal192(DecWide::from_parts([1, 0, 0, 0, 0, 0], 0, false));
+    /// The maximum supported scale value.
+    pub const MAX_SCALE: u32 = MAX_WIDE_SCALE;
+
+    /// Returns a `Decimal192` with a 64 bit `m` representation and corresponding `e` scale.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `scale` is > [`Self::MAX_SCALE`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal192;
+    /// #
+    /// let pi = Decimal192::new(3141, 3);
+    /// assert_eq!(pi.to_string(), "3.141");
+    /// ```
+    #[must_use]
+    pub fn new(num: i64, scale: u32) -> Decimal192 {
+        Self::from_i128_with_scale(i128::from(num), scale)
+    }
+
+    /// Creates a `Decimal192` using a 128 bit signed `m` representation and corresponding `e`
+    /// scale.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `scale` is > [`Self::MAX_SCALE`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal192;
+    /// #
+    /// let value = Decimal192::from_i128_with_scale(-170141183460469231731687303715884105728, 40);
+    /// assert_eq!(value.to_string(), "-0.0170141183460469231731687303715884105728");
+    /// ```
+    #[must_use]
+    pub fn from_i128_with_scale(num: i128, scale: u32) -> Decimal192 {
+        match Self::try_from_i128_with_scale(num, scale) {
+            Err(e) => panic!("{e}"),
+            Ok(d) => d,
+        }
+    }
+
+    /// Checked version of [`Self::from_i128_with_scale`]. Will return an error instead of
+    /// panicking at run-time.
+    pub const fn try_from_i128_with_scale(num: i128, scale: u32) -> crate::Result<Decimal192> {
+        if scale > Self::MAX_SCALE {
+            return Err(Error::ScaleExceedsMaximumPrecision(scale));
+        }
+        let m = num.unsigned_abs();
+        let data = [m as u32, (m >> 32) as u32, (m >> 64) as u32, (m >> 96) as u32, 0, 0];
+        Ok(Decimal192(DecWide::from_parts(data, scale, num < 0)))
+    }
+
+    /// Returns the scale of the decimal number, otherwise known as `e`.
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self) -> u32 {
+        self.0.scale()
+    }
+
+    /// Returns `true` if the decimal is negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Returns `true` if the decimal is positive.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(&self) -> bool {
+        !self.0.is_negative()
+    }
+
+    /// Returns `true` if this Decimal192 number is equivalent to zero.
+    #[inline]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        is_all_zero(self.0.data())
+    }
+
+    /// Computes the absolute value of `self`.
+    #[must_use]
+    pub fn abs(&self) -> Decimal192 {
+        Decimal192(DecWide::from_parts(*self.0.data(), self.scale(), false))
+    }
+
+    /// Strips any trailing zeros from the decimal number, returning zero with a scale of 0 for
+    /// any representation of zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal192;
+    /// # use core::str::FromStr;
+    /// #
+    /// let number = Decimal192::from_str("3.100").unwrap();
+    /// assert_eq!(number.normalize().to_string(), "3.1");
+    /// ```
+    #[must_use]
+    pub fn normalize(&self) -> Decimal192 {
+        if self.is_zero() {
+            return Decimal192::ZERO;
+        }
+        let mut data = *self.0.data();
+        let mut scale = self.scale();
+        while scale > 0 {
+            let mut working = data;
+            if div_by_u32(&mut working, 10) != 0 {
+                break;
+            }
+            data = working;
+            scale -= 1;
+        }
+        Decimal192(DecWide::from_parts(data, scale, self.is_sign_negative()))
+    }
+
+    /// Returns a new `Decimal192` number with the specified number of decimal points for
+    /// fractional portion, using Banker's Rounding.
+    #[must_use]
+    pub fn round_dp(&self, dp: u32) -> Decimal192 {
+        self.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Returns a new `Decimal192` number with the specified number of decimal points for
+    /// fractional portion, rounding with the given strategy. If the number already has `dp` or
+    /// fewer decimal places then it is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal192, RoundingStrategy};
+    /// # use core::str::FromStr;
+    /// #
+    /// let value = Decimal192::from_str("-1.2345").unwrap();
+    /// assert_eq!(value.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero).to_string(), "-1.24");
+    /// ```
+    #[must_use]
+    pub fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> Decimal192 {
+        // Rounding only ever removes digits, so the result always fits
+        round_wide(
+            self.0.data(),
+            self.scale(),
+            self.is_sign_negative(),
+            false,
+            dp,
+            strategy,
+        )
+        .map_or(*self, Decimal192)
+    }
+
+    /// Rounds this value into a `Decimal` using Banker's Rounding, keeping as many significant
+    /// digits as fit. Returns `None` if the value exceeds the range of a `Decimal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Decimal192};
+    /// # use core::str::FromStr;
+    /// #
+    /// let value = Decimal192::from_str("1.00000000000000000000000000005").unwrap();
+    /// assert_eq!(value.to_decimal(), Some(Decimal::ONE));
+    /// assert_eq!(Decimal192::MAX.to_decimal(), None);
+    /// ```
+    #[must_use]
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        self.to_decimal_with_strategy(RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Rounds this value into a `Decimal` using the given strategy, keeping as many significant
+    /// digits as fit. Returns `None` if the value exceeds the range of a `Decimal`.
+    #[must_use]
+    pub fn to_decimal_with_strategy(&self, strategy: RoundingStrategy) -> Option<Decimal> {
+        round_exact(
+            self.0.data(),
+            self.scale(),
+            self.is_sign_negative(),
+            false,
+            Target::Precision(MAX_PRECISION_U32),
+            strategy,
+        )
+        .map(|rounded| rounded.value)
+    }
+
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_add(self, other: Decimal192) -> Option<Decimal192> {
+        add_sub_impl(&self, &other, false)
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_sub(self, other: Decimal192) -> Option<Decimal192> {
+        add_sub_impl(&self, &other, true)
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, other: Decimal192) -> Option<Decimal192> {
+        mul_impl(&self, &other)
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other == 0.0` or the
+    /// division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, other: Decimal192) -> Option<Decimal192> {
+        div_impl(&self, &other)
+    }
+
+    /// Checked remainder. Computes `self % other`, returning `None` if `other == 0.0`.
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, other: Decimal192) -> Option<Decimal192> {
+        rem_impl(&self, &other)
+    }
+
+    fn from_rounded<const N: usize>(data: &[u32; N], scale: u32, negative: bool, sticky: bool) -> Option<Decimal192> {
+        round_wide(
+            data,
+            scale,
+            negative,
+            sticky,
+            MAX_WIDE_SCALE,
+            RoundingStrategy::MidpointNearestEven,
+        )
+        .map(Decimal192)
+    }
+
+    /// The mantissa of this value multiplied by `10^(scale - self.scale())`.
+    fn aligned(&self, scale: u32) -> [u32; DOUBLE_WORDS] {
+        let mut data = [0u32; DOUBLE_WORDS];
+        data[..6].copy_from_slice(self.0.data());
+        // 192 bits scaled by at most 10^57 always fits within 384 bits
+        mul_by_pow10(&mut data, scale - self.scale());
+        data
+    }
+}
+
+fn add_sub_impl(a: &Decimal192, b: &Decimal192, subtract: bool) -> Option<Decimal192> {
+    let scale = a.scale().max(b.scale());
+    let (mut x, mut y) = (a.aligned(scale), b.aligned(scale));
+    let a_negative = a.is_sign_negative();
+    let b_negative = b.is_sign_negative() ^ subtract;
+    if a_negative == b_negative {
+        let mut carry = 0u64;
+        for (word, &other) in x.iter_mut().zip(y.iter()) {
+            carry += u64::from(*word) + u64::from(other);
+            *word = carry as u32;
+            carry >>= 32;
+        }
+        return Decimal192::from_rounded(&x, scale, a_negative, false);
+    }
+    if cmp_words(&x, &y) != Ordering::Less {
+        sub_by_internal(&mut x, &y);
+        Decimal192::from_rounded(&x, scale, a_negative, false)
+    } else {
+        sub_by_internal(&mut y, &x);
+        Decimal192::from_rounded(&y, scale, b_negative, false)
+    }
+}
+
+fn mul_impl(a: &Decimal192, b: &Decimal192) -> Option<Decimal192> {
+    let mut product = [0u32; DOUBLE_WORDS];
+    for (i, &x) in a.0.data().iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.0.data().iter().enumerate() {
+            carry += u64::from(x) * u64::from(y) + u64::from(product[i + j]);
+            product[i + j] = carry as u32;
+            carry >>= 32;
+        }
+        product[i + 6] = carry as u32;
+    }
+    let negative = a.is_sign_negative() ^ b.is_sign_negative();
+    Decimal192::from_rounded(&product, a.scale() + b.scale(), negative, false)
+}
+
+fn div_impl(a: &Decimal192, b: &Decimal192) -> Option<Decimal192> {
+    if b.is_zero() {
+        return None;
+    }
+    let negative = a.is_sign_negative() ^ b.is_sign_negative();
+    let natural_scale = a.scale().saturating_sub(b.scale());
+    if a.is_zero() {
+        return Some(Decimal192(DecWide::from_parts([0; 6], natural_scale, false)));
+    }
+
+    // Scale the dividend up far enough that the quotient has at least 59 digits, leaving at least
+    // one digit to round away once the remainder has been accounted for.
+    let (a_digits, b_digits) = (count_digits(a.0.data()), count_digits(b.0.data()));
+    let shift = (MAX_WIDE_SCALE + 2 + b_digits)
+        .saturating_sub(a_digits)
+        .max(b.scale().saturating_sub(a.scale()));
+    // The dividend has at most 59 + 58 digits, or 57 + 58 digits, which fits within 448 bits
+    let mut quotient = [0u32; 14];
+    quotient[..6].copy_from_slice(a.0.data());
+    mul_by_pow10(&mut quotient, shift);
+    let remainder = div_rem_wide(&mut quotient, b.0.data());
+
+    let mut scale = a.scale() + shift - b.scale();
+    let sticky = !is_all_zero(&remainder);
+    if !sticky {
+        while scale > natural_scale {
+            let mut working = quotient;
+            if div_by_u32(&mut working, 10) != 0 {
+                break;
+            }
+            quotient = working;
+            scale -= 1;
+        }
+    }
+    Decimal192::from_rounded(&quotient, scale, negative, sticky)
+}
+
+fn rem_impl(a: &Decimal192, b: &Decimal192) -> Option<Decimal192> {
+    if b.is_zero() {
+        return None;
+    }
+    let scale = a.scale().max(b.scale());
+    let (mut x, y) = (a.aligned(scale), b.aligned(scale));
+    let remainder = div_rem_wide(&mut x, &y);
+    // The remainder is smaller than `b`, so it always fits
+    Decimal192::from_rounded(&remainder, scale, a.is_sign_negative(), false)
+}
+
+fn cmp_words<const N: usize>(a: &[u32; N], b: &[u32; N]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+impl Default for Decimal192 {
+    /// Returns the default value for a `Decimal192` (equivalent to `Decimal192::ZERO`).
+    fn default() -> Self {
+        Decimal192::ZERO
+    }
+}
+
+impl Zero for Decimal192 {
+    fn zero() -> Decimal192 {
+        Decimal192::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        Decimal192::is_zero(self)
+    }
+}
+
+impl One for Decimal192 {
+    fn one() -> Decimal192 {
+        Decimal192::ONE
+    }
+}
+
+impl From<Decimal> for Decimal192 {
+    /// Converts a `Decimal` into a `Decimal192` without loss, retaining its scale.
+    fn from(value: Decimal) -> Decimal192 {
+        let wide = DecWide::from_decimal(&value);
+        Decimal192(DecWide::from_parts(
+            *wide.data(),
+            wide.scale(),
+            wide.is_negative() && !value.is_zero(),
+        ))
+    }
+}
+
+impl TryFrom<Decimal192> for Decimal {
+    type Error = Error;
+
+    /// Rounds a `Decimal192` into a `Decimal` using Banker's Rounding, failing if the value
+    /// exceeds the range of a `Decimal`.
+    fn try_from(value: Decimal192) -> Result<Decimal, Error> {
+        value.to_decimal().ok_or(out_of_range(value.is_sign_negative()))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Decimal192 {
+                #[inline]
+                fn from(value: $t) -> Decimal192 {
+                    Decimal192::from_i128_with_scale(i128::from(value), 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<i128> for Decimal192 {
+    #[inline]
+    fn from(value: i128) -> Decimal192 {
+        Decimal192::from_i128_with_scale(value, 0)
+    }
+}
+
+impl From<u128> for Decimal192 {
+    #[inline]
+    fn from(value: u128) -> Decimal192 {
+        let data = [
+            value as u32,
+            (value >> 32) as u32,
+            (value >> 64) as u32,
+            (value >> 96) as u32,
+            0,
+            0,
+        ];
+        Decimal192(DecWide::from_parts(data, 0, false))
+    }
+}
+
+impl FromStr for Decimal192 {
+    type Err = Error;
+
+    /// Parses a decimal string such as `-1_000.25`. Digits beyond the available precision are
+    /// rounded using Banker's Rounding.
+    fn from_str(value: &str) -> Result<Decimal192, Error> {
+        let parsed = parse_exact(value)?;
+        Decimal192::from_rounded(&parsed.data, parsed.scale, parsed.negative, parsed.sticky)
+            .ok_or(out_of_range(parsed.negative))
+    }
+}
+
+/// The exact value of a parsed decimal string, as `data * 10^-scale`.
+pub(crate) struct Parsed {
+    pub data: [u32; DOUBLE_WORDS],
+    pub scale: u32,
+    pub negative: bool,
+    /// Non-zero digits were discarded beyond the end of `data`.
+    pub sticky: bool,
+}
+
+/// Parses a decimal string such as `-1_000.25` without rounding, other than to discard any digits
+/// too far beyond the first 80 significant digits to be kept by a 192 bit type.
+pub(crate) fn parse_exact(value: &str) -> Result<Parsed, Error> {
+    let bytes = value.as_bytes();
+    let (negative, rest) = match bytes.first() {
+        None => return Err(Error::EmptyData),
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        Some(_) => (false, bytes),
+    };
+
+    let mut data = [0u32; DOUBLE_WORDS];
+    let mut scale = 0u32;
+    let mut digits = 0u32;
+    let mut any_digits = false;
+    let mut point = false;
+    let mut sticky = false;
+    for &b in rest {
+        match b {
+            b'0'..=b'9' => {
+                any_digits = true;
+                let digit = u32::from(b - b'0');
+                if digits < MAX_PARSED_DIGITS {
+                    mul_by_u32(&mut data, 10);
+                    manage_add_by_internal(digit, &mut data);
+                    if digits > 0 || digit != 0 {
+                        digits += 1;
+                    }
+                } else if point {
+                    // Too far beyond the precision to matter, other than for rounding
+                    sticky |= digit != 0;
+                    continue;
+                } else {
+                    return Err(out_of_range(negative));
+                }
+                if point {
+                    scale += 1;
+                }
+            }
+            b'.' if point => return Err(Error::DuplicatedDecimalPoint),
+            b'.' => point = true,
+            b'_' => {}
+            _ => return Err(Error::InvalidCharacter),
+        }
+    }
+    if !any_digits {
+        return Err(Error::NoDigits);
+    }
+    Ok(Parsed {
+        data,
+        scale,
+        negative,
+        sticky,
+    })
+}
+
+impl fmt::Display for Decimal192 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let scale = self.scale() as usize;
+        let mut digits = String::new();
+        let mut working = *self.0.data();
+        while !is_all_zero(&working) {
+            let remainder = div_by_u32(&mut working, 10);
+            digits.insert(0, char::from(b'0' + remainder as u8));
+        }
+        while digits.len() <= scale {
+            digits.insert(0, '0');
+        }
+
+        let (whole, fraction) = digits.split_at(digits.len() - scale);
+        let mut rep = String::from(whole);
+        // As with `Decimal`, an explicit precision truncates or pads the fractional portion
+        let precision = f.precision().unwrap_or(scale);
+        if precision > 0 {
+            rep.push('.');
+            rep.push_str(&fraction[..precision.min(scale)]);
+            rep.extend(core::iter::repeat_n('0', precision.saturating_sub(scale)));
+        }
+        f.pad_integral(self.is_sign_positive(), "", &rep)
+    }
+}
+
+impl fmt::Debug for Decimal192 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl PartialEq for Decimal192 {
+    #[inline]
+    fn eq(&self, other: &Decimal192) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal192 {}
+
+impl Hash for Decimal192 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let n = self.normalize();
+        n.0.data().hash(state);
+        n.scale().hash(state);
+        n.is_sign_negative().hash(state);
+    }
+}
+
+impl PartialOrd for Decimal192 {
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal192) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal192 {
+    fn cmp(&self, other: &Decimal192) -> Ordering {
+        // Zero is neither positive nor negative, regardless of its sign
+        let sign = |d: &Decimal192| match (d.is_zero(), d.is_sign_negative()) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        let (a_sign, b_sign) = (sign(self), sign(other));
+        if a_sign != b_sign || a_sign == 0 {
+            return a_sign.cmp(&b_sign);
+        }
+        let scale = self.scale().max(other.scale());
+        let magnitude = cmp_words(&self.aligned(scale), &other.aligned(scale));
+        if a_sign < 0 { magnitude.reverse() } else { magnitude }
+    }
+}
+
+impl Neg for Decimal192 {
+    type Output = Decimal192;
+
+    fn neg(self) -> Decimal192 {
+        -&self
+    }
+}
+
+impl Neg for &Decimal192 {
+    type Output = Decimal192;
+
+    fn neg(self) -> Decimal192 {
+        let negative = !self.is_sign_negative() && !self.is_zero();
+        Decimal192(DecWide::from_parts(*self.0.data(), self.scale(), negative))
+    }
+}
+
+/// Implements a binary operator, along with its assignment counterpart, for each combination of
+/// owned and borrowed operands using a checked function, panicking with the given message when
+/// it returns `None`. A second message may be given for division by zero, and any generic
+/// parameters of the type within brackets after `impl`.
+macro_rules! impl_binop {
+    (impl $([$($generics:tt)*])? $imp:ident for $t:ty, $method:ident, $assign_imp:ident, $assign_method:ident, $checked:ident, $($message:literal)|+) => {
+        impl<$($($generics)*)?> $imp<&$t> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn $method(self, other: &$t) -> $t {
+                impl_binop!(@unwrap self, other, $checked, $($message)|+)
+            }
+        }
+
+        impl<$($($generics)*)?> $imp<$t> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn $method(self, other: $t) -> $t {
+                self.$method(&other)
+            }
+        }
+
+        impl<$($($generics)*)?> $imp<&$t> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn $method(self, other: &$t) -> $t {
+                (&self).$method(other)
+            }
+        }
+
+        impl<$($($generics)*)?> $imp<$t> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn $method(self, other: $t) -> $t {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<$($($generics)*)?> $assign_imp<$t> for $t {
+            #[inline]
+            fn $assign_method(&mut self, other: $t) {
+                *self = (&*self).$method(&other);
+            }
+        }
+
+        impl<$($($generics)*)?> $assign_imp<&$t> for $t {
+            #[inline]
+            fn $assign_method(&mut self, other: &$t) {
+                *self = (&*self).$method(other);
+            }
+        }
+    };
+    (@unwrap $a:ident, $b:ident, $checked:ident, $message:literal) => {
+        match $checked($a, $b) {
+            Some(result) => result,
+            None => panic!($message),
+        }
+    };
+    (@unwrap $a:ident, $b:ident, $checked:ident, $by_zero:literal | $message:literal) => {
+        match $checked($a, $b) {
+            Some(result) => result,
+            None if $b.is_zero() => panic!($by_zero),
+            None => panic!($message),
+        }
+    };
+}
+
+pub(crate) use impl_binop;
+
+fn add_impl(a: &Decimal192, b: &Decimal192) -> Option<Decimal192> {
+    add_sub_impl(a, b, false)
+}
+
+fn sub_impl(a: &Decimal192, b: &Decimal192) -> Option<Decimal192> {
+    add_sub_impl(a, b, true)
+}
+
+impl_binop!(impl Add for Decimal192, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl Sub for Decimal192, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl Mul for Decimal192, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl Div for Decimal192, div, DivAssign, div_assign, div_impl, "Division by zero" | "Division overflowed");
+impl_binop!(impl Rem for Decimal192, rem, RemAssign, rem_assign, rem_impl, "Division by zero" | "Division overflowed");
+
+impl Sum for Decimal192 {
+    fn sum<I: Iterator<Item = Decimal192>>(iter: I) -> Self {
+        iter.fold(Decimal192::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl<'a> Sum<&'a Decimal192> for Decimal192 {
+    fn sum<I: Iterator<Item = &'a Decimal192>>(iter: I) -> Self {
+        iter.fold(Decimal192::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl Product for Decimal192 {
+    fn product<I: Iterator<Item = Decimal192>>(iter: I) -> Self {
+        iter.fold(Decimal192::ONE, |product, value| product * value)
+    }
+}
+
+impl<'a> Product<&'a Decimal192> for Decimal192 {
+    fn product<I: Iterator<Item = &'a Decimal192>>(iter: I) -> Self {
+        iter.fold(Decimal192::ONE, |product, value| product * value)
+    }
+}
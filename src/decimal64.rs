@@ -0,0 +1,568 @@
+use crate::constants::MAX_SCALE_U32;
+use crate::decimal::out_of_range;
+use crate::decimal192::{impl_binop, parse_exact};
+use crate::ops::array::{div_by_u32, sub_by_internal};
+use crate::ops::wide::{Limits, count_digits, div_by_u96, div_rem_wide, mul_by_pow10, round_to_fit};
+use crate::{Decimal, Error, RoundingStrategy};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+use num_traits::{One, Zero};
+
+// Bits 0-55: the mantissa
+// Bits 56-62: the scale, from 0 to 28
+// Bit 63: the sign, set when negative
+const MANTISSA_MASK: u64 = (1 << 56) - 1;
+const SCALE_SHIFT: u32 = 56;
+const SCALE_MASK: u64 = 0x7F;
+const SIGN_MASK: u64 = 1 << 63;
+
+/// The number of decimal digits beyond which a mantissa can never fit within 56 bits.
+const MAX_DIGITS: u32 = 17;
+
+/// `Decimal64` represents a decimal number within 8 bytes, using a 56 bit mantissa (roughly 16
+/// significant digits) along with a scale between 0 and 28.
+///
+/// It is intended for memory bound workloads, such as large arrays of prices, where the full
+/// precision of a [`Decimal`] isn't required. Any `Decimal64` widens into a `Decimal` without
+/// loss, and arithmetic, parsing and formatting behave as they do for `Decimal`. When the exact
+/// result of an operation doesn't fit within 56 bits, it is rounded once using
+/// [`RoundingStrategy::MidpointNearestEven`], keeping as many decimal places as fit.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Decimal, Decimal64};
+/// # use core::str::FromStr;
+/// #
+/// let price = Decimal64::from_str("101.25").unwrap();
+/// let total = price * Decimal64::from(3);
+/// assert_eq!(total.to_string(), "303.75");
+/// assert_eq!(Decimal::from(total), Decimal::new(30375, 2));
+/// assert_eq!(core::mem::size_of::<Decimal64>(), 8);
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Decimal64(u64);
+
+impl Decimal64 {
+    /// The smallest value that can be represented by this decimal type.
+    pub const MIN: Decimal64 = Decimal64(SIGN_MASK | MANTISSA_MASK);
+    /// The largest value that can be represented by this decimal type.
+    pub const MAX: Decimal64 = Decimal64(MANTISSA_MASK);
+    /// A constant representing 0.
+    pub const ZERO: Decimal64 = Decimal64(0);
+    /// A constant representing 1.
+    pub const ONE: Decimal64 = Decimal64(1);
+    /// The maximum supported scale value.
+    pub const MAX_SCALE: u32 = MAX_SCALE_U32;
+
+    /// Returns a `Decimal64` with a 64 bit `m` representation and corresponding `e` scale.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `scale` is > [`Self::MAX_SCALE`] or if `num` exceeds the maximum
+    /// supported 56 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal64;
+    /// #
+    /// let pi = Decimal64::new(3141, 3);
+    /// assert_eq!(pi.to_string(), "3.141");
+    /// ```
+    #[must_use]
+    pub fn new(num: i64, scale: u32) -> Decimal64 {
+        match Self::try_new(num, scale) {
+            Err(e) => panic!("{e}"),
+            Ok(d) => d,
+        }
+    }
+
+    /// Checked version of [`Self::new`]. Will return an error instead of panicking at run-time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::Decimal64;
+    /// #
+    /// assert!(Decimal64::try_new(i64::MAX, 2).is_err());
+    /// ```
+    pub const fn try_new(num: i64, scale: u32) -> crate::Result<Decimal64> {
+        if scale > Self::MAX_SCALE {
+            return Err(Error::ScaleExceedsMaximumPrecision(scale));
+        }
+        let mantissa = num.unsigned_abs();
+        if mantissa > MANTISSA_MASK {
+            return Err(if num < 0 {
+                Error::LessThanMinimumPossibleValue
+            } else {
+                Error::ExceedsMaximumPossibleValue
+            });
+        }
+        Ok(Decimal64::from_parts(mantissa, scale, num < 0))
+    }
+
+    const fn from_parts(mantissa: u64, scale: u32, negative: bool) -> Decimal64 {
+        let sign = if negative && mantissa != 0 { SIGN_MASK } else { 0 };
+        Decimal64(sign | ((scale as u64) << SCALE_SHIFT) | mantissa)
+    }
+
+    /// Returns the mantissa of the decimal number.
+    #[inline]
+    #[must_use]
+    pub const fn mantissa(&self) -> i64 {
+        let mantissa = (self.0 & MANTISSA_MASK) as i64;
+        if self.is_sign_negative() { -mantissa } else { mantissa }
+    }
+
+    /// Returns the scale of the decimal number, otherwise known as `e`.
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self) -> u32 {
+        ((self.0 >> SCALE_SHIFT) & SCALE_MASK) as u32
+    }
+
+    /// Returns `true` if the decimal is negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.0 & SIGN_MASK != 0
+    }
+
+    /// Returns `true` if the decimal is positive.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(&self) -> bool {
+        self.0 & SIGN_MASK == 0
+    }
+
+    /// Returns `true` if this Decimal64 number is equivalent to zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.0 & MANTISSA_MASK == 0
+    }
+
+    /// Computes the absolute value of `self`.
+    #[inline]
+    #[must_use]
+    pub const fn abs(&self) -> Decimal64 {
+        Decimal64(self.0 & !SIGN_MASK)
+    }
+
+    /// Strips any trailing zeros from the decimal number, returning zero with a scale of 0 for
+    /// any representation of zero.
+    #[must_use]
+    pub fn normalize(&self) -> Decimal64 {
+        let mut mantissa = self.0 & MANTISSA_MASK;
+        let mut scale = self.scale();
+        if mantissa == 0 {
+            return Decimal64::ZERO;
+        }
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Decimal64::from_parts(mantissa, scale, self.is_sign_negative())
+    }
+
+    /// Returns a new `Decimal64` number with the specified number of decimal points for
+    /// fractional portion, using Banker's Rounding.
+    #[must_use]
+    pub fn round_dp(&self, dp: u32) -> Decimal64 {
+        self.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Returns a new `Decimal64` number with the specified number of decimal points for
+    /// fractional portion, rounding with the given strategy. If the number already has `dp` or
+    /// fewer decimal places then it is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal64, RoundingStrategy};
+    /// #
+    /// let value = Decimal64::new(-12345, 4);
+    /// assert_eq!(value.round_dp_with_strategy(2, RoundingStrategy::AwayFromZero).to_string(), "-1.24");
+    /// ```
+    #[must_use]
+    pub fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> Decimal64 {
+        // Rounding only ever removes digits, so the result always fits
+        Decimal64::from_exact(
+            &self.words(),
+            self.scale(),
+            self.is_sign_negative(),
+            false,
+            dp,
+            strategy,
+        )
+        .unwrap_or(*self)
+    }
+
+    /// Narrows a `Decimal` into a `Decimal64`, rounding with the given strategy to keep as many
+    /// decimal places as fit within 56 bits. Returns `None` if the integral part of the value
+    /// doesn't fit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, Decimal64, RoundingStrategy};
+    /// # use core::str::FromStr;
+    /// #
+    /// let value = Decimal::from_str("2.718281828459045235360287471").unwrap();
+    /// let narrowed = Decimal64::from_decimal_with_strategy(value, RoundingStrategy::ToZero).unwrap();
+    /// assert_eq!(narrowed.to_string(), "2.7182818284590452");
+    /// assert_eq!(Decimal64::from_decimal_with_strategy(Decimal::MAX, RoundingStrategy::ToZero), None);
+    /// ```
+    #[must_use]
+    pub fn from_decimal_with_strategy(value: Decimal, strategy: RoundingStrategy) -> Option<Decimal64> {
+        let m = value.mantissa_array3();
+        Decimal64::from_exact(
+            &[m[0], m[1], m[2], 0],
+            value.scale(),
+            value.is_sign_negative(),
+            false,
+            Decimal64::MAX_SCALE,
+            strategy,
+        )
+    }
+
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_add(self, other: Decimal64) -> Option<Decimal64> {
+        add_sub_impl(&self, &other, false)
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_sub(self, other: Decimal64) -> Option<Decimal64> {
+        add_sub_impl(&self, &other, true)
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, other: Decimal64) -> Option<Decimal64> {
+        mul_impl(&self, &other)
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other == 0.0` or the
+    /// division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, other: Decimal64) -> Option<Decimal64> {
+        div_impl(&self, &other)
+    }
+
+    /// Checked remainder. Computes `self % other`, returning `None` if `other == 0.0`.
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, other: Decimal64) -> Option<Decimal64> {
+        rem_impl(&self, &other)
+    }
+
+    /// Rounds the exact value `data * 10^-scale` into a `Decimal64` using a single rounding step.
+    fn from_exact<const N: usize>(
+        data: &[u32; N],
+        scale: u32,
+        negative: bool,
+        sticky: bool,
+        max_scale: u32,
+        strategy: RoundingStrategy,
+    ) -> Option<Decimal64> {
+        let limits = Limits {
+            max_scale: max_scale.min(Decimal64::MAX_SCALE),
+            max_digits: MAX_DIGITS,
+            fits: |data: &[u32; N]| data[2..].iter().all(|w| *w == 0) && low64(data) <= MANTISSA_MASK,
+        };
+        let (data, scale) = round_to_fit(data, scale, negative, sticky, limits, strategy)?;
+        Some(Decimal64::from_parts(low64(&data), scale, negative))
+    }
+
+    fn from_rounded<const N: usize>(data: &[u32; N], scale: u32, negative: bool, sticky: bool) -> Option<Decimal64> {
+        Decimal64::from_exact(
+            data,
+            scale,
+            negative,
+            sticky,
+            Decimal64::MAX_SCALE,
+            RoundingStrategy::MidpointNearestEven,
+        )
+    }
+
+    #[inline]
+    const fn words(&self) -> [u32; 2] {
+        [self.0 as u32, ((self.0 & MANTISSA_MASK) >> 32) as u32]
+    }
+
+    /// The mantissa of this value multiplied by `10^(scale - self.scale())`.
+    fn aligned(&self, scale: u32) -> [u32; 6] {
+        let [lo, hi] = self.words();
+        let mut data = [lo, hi, 0, 0, 0, 0];
+        // 56 bits scaled by at most 10^28 always fits within 192 bits
+        mul_by_pow10(&mut data, scale - self.scale());
+        data
+    }
+}
+
+#[inline]
+fn low64<const N: usize>(data: &[u32; N]) -> u64 {
+    u64::from(data[0]) | (u64::from(data[1]) << 32)
+}
+
+fn add_sub_impl(a: &Decimal64, b: &Decimal64, subtract: bool) -> Option<Decimal64> {
+    let scale = a.scale().max(b.scale());
+    let (mut x, mut y) = (a.aligned(scale), b.aligned(scale));
+    let a_negative = a.is_sign_negative();
+    let b_negative = b.is_sign_negative() ^ subtract;
+    if a_negative == b_negative {
+        let mut carry = 0u64;
+        for (word, &other) in x.iter_mut().zip(y.iter()) {
+            carry += u64::from(*word) + u64::from(other);
+            *word = carry as u32;
+            carry >>= 32;
+        }
+        return Decimal64::from_rounded(&x, scale, a_negative, false);
+    }
+    if x.iter().rev().cmp(y.iter().rev()) != Ordering::Less {
+        sub_by_internal(&mut x, &y);
+        Decimal64::from_rounded(&x, scale, a_negative, false)
+    } else {
+        sub_by_internal(&mut y, &x);
+        Decimal64::from_rounded(&y, scale, b_negative, false)
+    }
+}
+
+fn mul_impl(a: &Decimal64, b: &Decimal64) -> Option<Decimal64> {
+    // Both mantissas are at most 56 bits, so the product always fits within 128 bits
+    let product = u128::from(a.0 & MANTISSA_MASK) * u128::from(b.0 & MANTISSA_MASK);
+    let data = [
+        product as u32,
+        (product >> 32) as u32,
+        (product >> 64) as u32,
+        (product >> 96) as u32,
+    ];
+    let negative = a.is_sign_negative() ^ b.is_sign_negative();
+    Decimal64::from_rounded(&data, a.scale() + b.scale(), negative, false)
+}
+
+fn div_impl(a: &Decimal64, b: &Decimal64) -> Option<Decimal64> {
+    if b.is_zero() {
+        return None;
+    }
+    let negative = a.is_sign_negative() ^ b.is_sign_negative();
+    let natural_scale = a.scale().saturating_sub(b.scale());
+    if a.is_zero() {
+        return Some(Decimal64::from_parts(0, natural_scale, false));
+    }
+
+    // Scale the dividend up far enough that the quotient has at least 19 digits, leaving at least
+    // one digit to round away once the remainder has been accounted for.
+    let (a_digits, b_digits) = (count_digits(&a.words()), count_digits(&b.words()));
+    let shift = (MAX_DIGITS + 2 + b_digits)
+        .saturating_sub(a_digits)
+        .max(b.scale().saturating_sub(a.scale()));
+    // The dividend has at most 19 + 17 digits, or 17 + 28 digits, which fits within 192 bits
+    let [lo, hi] = a.words();
+    let mut quotient = [lo, hi, 0, 0, 0, 0];
+    mul_by_pow10(&mut quotient, shift);
+    let remainder = div_by_u96(&mut quotient, u128::from(b.0 & MANTISSA_MASK));
+
+    let mut scale = a.scale() + shift - b.scale();
+    if remainder == 0 {
+        while scale > natural_scale {
+            let mut working = quotient;
+            if div_by_u32(&mut working, 10) != 0 {
+                break;
+            }
+            quotient = working;
+            scale -= 1;
+        }
+    }
+    Decimal64::from_rounded(&quotient, scale, negative, remainder != 0)
+}
+
+fn rem_impl(a: &Decimal64, b: &Decimal64) -> Option<Decimal64> {
+    if b.is_zero() {
+        return None;
+    }
+    let scale = a.scale().max(b.scale());
+    let (mut x, y) = (a.aligned(scale), b.aligned(scale));
+    let remainder = div_rem_wide(&mut x, &y);
+    Decimal64::from_rounded(&remainder, scale, a.is_sign_negative(), false)
+}
+
+fn add_impl(a: &Decimal64, b: &Decimal64) -> Option<Decimal64> {
+    add_sub_impl(a, b, false)
+}
+
+fn sub_impl(a: &Decimal64, b: &Decimal64) -> Option<Decimal64> {
+    add_sub_impl(a, b, true)
+}
+
+impl Default for Decimal64 {
+    /// Returns the default value for a `Decimal64` (equivalent to `Decimal64::ZERO`).
+    fn default() -> Self {
+        Decimal64::ZERO
+    }
+}
+
+impl Zero for Decimal64 {
+    fn zero() -> Decimal64 {
+        Decimal64::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        Decimal64::is_zero(self)
+    }
+}
+
+impl One for Decimal64 {
+    fn one() -> Decimal64 {
+        Decimal64::ONE
+    }
+}
+
+impl From<Decimal64> for Decimal {
+    /// Widens a `Decimal64` into a `Decimal` without loss, retaining its scale.
+    fn from(value: Decimal64) -> Decimal {
+        let [lo, mid] = value.words();
+        Decimal::from_parts(lo, mid, 0, value.is_sign_negative(), value.scale())
+    }
+}
+
+impl TryFrom<Decimal> for Decimal64 {
+    type Error = Error;
+
+    /// Narrows a `Decimal` into a `Decimal64` using Banker's Rounding, failing if the integral
+    /// part of the value doesn't fit within 56 bits.
+    fn try_from(value: Decimal) -> Result<Decimal64, Error> {
+        Decimal64::from_decimal_with_strategy(value, RoundingStrategy::MidpointNearestEven)
+            .ok_or(out_of_range(value.is_sign_negative()))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Decimal64 {
+                #[inline]
+                fn from(value: $t) -> Decimal64 {
+                    Decimal64::new(i64::from(value), 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, u8, u16, u32);
+
+impl FromStr for Decimal64 {
+    type Err = Error;
+
+    /// Parses a decimal string such as `-1_000.25`. Digits beyond the available precision are
+    /// rounded using Banker's Rounding.
+    fn from_str(value: &str) -> Result<Decimal64, Error> {
+        let parsed = parse_exact(value)?;
+        Decimal64::from_rounded(&parsed.data, parsed.scale, parsed.negative, parsed.sticky)
+            .ok_or(out_of_range(parsed.negative))
+    }
+}
+
+impl fmt::Display for Decimal64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(&Decimal::from(*self), f)
+    }
+}
+
+impl fmt::Debug for Decimal64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl PartialEq for Decimal64 {
+    #[inline]
+    fn eq(&self, other: &Decimal64) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal64 {}
+
+impl Hash for Decimal64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalize().0.hash(state);
+    }
+}
+
+impl PartialOrd for Decimal64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal64) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal64 {
+    fn cmp(&self, other: &Decimal64) -> Ordering {
+        // Identical scales are the common case, where the mantissas can be compared directly
+        if self.scale() == other.scale() {
+            return self.mantissa().cmp(&other.mantissa());
+        }
+        Decimal::from(*self).cmp(&Decimal::from(*other))
+    }
+}
+
+impl Neg for Decimal64 {
+    type Output = Decimal64;
+
+    fn neg(self) -> Decimal64 {
+        -&self
+    }
+}
+
+impl Neg for &Decimal64 {
+    type Output = Decimal64;
+
+    fn neg(self) -> Decimal64 {
+        Decimal64::from_parts(self.0 & MANTISSA_MASK, self.scale(), self.is_sign_positive())
+    }
+}
+
+impl_binop!(impl Add for Decimal64, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl Sub for Decimal64, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl Mul for Decimal64, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl Div for Decimal64, div, DivAssign, div_assign, div_impl, "Division by zero" | "Division overflowed");
+impl_binop!(impl Rem for Decimal64, rem, RemAssign, rem_assign, rem_impl, "Division by zero" | "Division overflowed");
+
+impl Sum for Decimal64 {
+    fn sum<I: Iterator<Item = Decimal64>>(iter: I) -> Self {
+        iter.fold(Decimal64::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl<'a> Sum<&'a Decimal64> for Decimal64 {
+    fn sum<I: Iterator<Item = &'a Decimal64>>(iter: I) -> Self {
+        iter.fold(Decimal64::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl Product for Decimal64 {
+    fn product<I: Iterator<Item = Decimal64>>(iter: I) -> Self {
+        iter.fold(Decimal64::ONE, |product, value| product * value)
+    }
+}
+
+impl<'a> Product<&'a Decimal64> for Decimal64 {
+    fn product<I: Iterator<Item = &'a Decimal64>>(iter: I) -> Self {
+        iter.fold(Decimal64::ONE, |product, value| product * value)
+    }
+}
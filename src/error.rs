@@ -1,4 +1,4 @@
-use crate::Decimal;
+use crate::{Decimal, Signal};
 use core::fmt;
 
 /// Error type for the library.
@@ -7,6 +7,10 @@ pub enum Error {
     /// Represents a failure to convert to/from `Decimal` to the specified type. This is typically
     /// due to type constraints (e.g. `Decimal::MAX` cannot be converted into `i32`).
     ConversionTo(&'static str),
+    /// Arithmetic or a comparison was attempted between amounts of two different currencies.
+    CurrencyMismatch(&'static str, &'static str),
+    /// A division or remainder operation was attempted with a divisor of zero.
+    DivisionByZero,
     /// The decimal string contained more than one decimal point.
     DuplicatedDecimalPoint,
     /// Could not represent a Decimal instance because there no data left.
@@ -15,18 +19,26 @@ pub enum Error {
     ExceedsMaximumPossibleValue,
     /// A string could not represent a scientific number.
     FailedToParseScientificFromString,
+    /// A floating point infinity cannot be represented as a `Decimal`.
+    Infinite,
     /// A character could not represent a Decimal instance
     InvalidCharacter,
     /// The string must start with a digit, `+` or `-`.
     InvalidLeadingChar,
     /// The value provided is less than `Decimal::MIN`.
     LessThanMinimumPossibleValue,
+    /// A floating point NaN cannot be represented as a `Decimal`.
+    NaN,
     /// The string did not contain any digits.
     NoDigits,
     /// The scale provided exceeds the maximum scale that `Decimal` can represent.
     ScaleExceedsMaximumPrecision(u32),
+    /// A signal that is trapped by the arithmetic `Context` was raised by an operation.
+    Trapped(Signal),
     /// An underflow is when there are more fractional digits than can be represented within `Decimal`.
     Underflow,
+    /// The currency code is not a recognised ISO 4217 code.
+    UnknownCurrency,
     /// The radix is not supported. Must be between 2 and 36.
     UnsupportedRadix,
 }
@@ -44,6 +56,12 @@ impl fmt::Display for Error {
             Self::ConversionTo(ref type_name) => {
                 write!(f, "Error while converting to {type_name}")
             }
+            Self::CurrencyMismatch(ref left, ref right) => {
+                write!(f, "Cannot combine amounts in {left} and {right}.")
+            }
+            Self::DivisionByZero => {
+                write!(f, "Division by zero.")
+            }
             Self::DuplicatedDecimalPoint => {
                 write!(f, "The decimal string contained more than one decimal point.")
             }
@@ -56,6 +74,9 @@ impl fmt::Display for Error {
             Self::FailedToParseScientificFromString => {
                 write!(f, "A string could not represent a scientific number.")
             }
+            Self::Infinite => {
+                write!(f, "An infinite value can not be represented.")
+            }
             Self::InvalidCharacter => {
                 write!(f, "A character could not represent a Decimal instance.")
             }
@@ -65,6 +86,9 @@ impl fmt::Display for Error {
             Self::LessThanMinimumPossibleValue => {
                 write!(f, "Number less than minimum value that can be represented.")
             }
+            Self::NaN => {
+                write!(f, "A NaN value can not be represented.")
+            }
             Self::NoDigits => {
                 write!(f, "The string did not contain any digits.")
             }
@@ -75,9 +99,15 @@ impl fmt::Display for Error {
                     Decimal::MAX_SCALE
                 )
             }
+            Self::Trapped(ref signal) => {
+                write!(f, "The {signal} signal was raised and is trapped by the context.")
+            }
             Self::Underflow => {
                 write!(f, "Number has a high precision that can not be represented.")
             }
+            Self::UnknownCurrency => {
+                write!(f, "The currency code is not a recognised ISO 4217 code.")
+            }
             Self::UnsupportedRadix => {
                 write!(f, "The radix is not supported. Must be between 2 and 36.")
             }
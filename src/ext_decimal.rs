@@ -0,0 +1,298 @@
+use crate::decimal192::impl_binop;
+use crate::{Decimal, Error};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+#[cfg(feature = "diesel")]
+use diesel::{deserialize::FromSqlRow, expression::AsExpression, sql_types::Numeric};
+
+/// `ExtDecimal` extends [`Decimal`] with the special values `NaN`, `Infinity` and `-Infinity`
+/// that a PostgreSQL `NUMERIC` column may hold.
+///
+/// Arithmetic and comparison follow IEEE 754 semantics rather than panicking: overflow and
+/// division of a non-zero value by zero produce an infinity, indeterminate forms such as
+/// `0 / 0` or `∞ - ∞` produce `NaN`, and `NaN` is unordered and not equal to anything, including
+/// itself. [`Self::total_cmp`] provides the total ordering used by PostgreSQL, in which `NaN` is
+/// greater than every other value.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Decimal, ExtDecimal};
+/// # use core::str::FromStr;
+/// #
+/// let one = ExtDecimal::from(Decimal::ONE);
+/// let zero = ExtDecimal::from(Decimal::ZERO);
+/// assert_eq!(one / zero, ExtDecimal::PosInf);
+/// assert!((zero / zero).is_nan());
+/// assert_eq!(ExtDecimal::from_str("-Infinity").unwrap(), ExtDecimal::NegInf);
+/// assert!(ExtDecimal::NegInf < one);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "diesel", derive(FromSqlRow, AsExpression), diesel(sql_type = Numeric))]
+pub enum ExtDecimal {
+    /// A finite decimal number.
+    Finite(Decimal),
+    /// Not a number, the result of an indeterminate operation such as `0 / 0`.
+    NaN,
+    /// Positive infinity.
+    PosInf,
+    /// Negative infinity.
+    NegInf,
+}
+
+impl ExtDecimal {
+    /// Returns `true` if this value is `NaN`.
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(&self) -> bool {
+        matches!(self, ExtDecimal::NaN)
+    }
+
+    /// Returns `true` if this value is positive or negative infinity.
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(&self) -> bool {
+        matches!(self, ExtDecimal::PosInf | ExtDecimal::NegInf)
+    }
+
+    /// Returns `true` if this value is neither infinite nor `NaN`.
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(&self) -> bool {
+        matches!(self, ExtDecimal::Finite(_))
+    }
+
+    /// Returns `true` if this value is negative infinity or a finite value with a negative sign.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        match self {
+            ExtDecimal::Finite(value) => value.is_sign_negative(),
+            ExtDecimal::NegInf => true,
+            ExtDecimal::NaN | ExtDecimal::PosInf => false,
+        }
+    }
+
+    /// Returns the finite value as a `Decimal`, or `None` if this value is infinite or `NaN`.
+    #[inline]
+    #[must_use]
+    pub const fn to_decimal(&self) -> Option<Decimal> {
+        match self {
+            ExtDecimal::Finite(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    #[must_use]
+    pub fn abs(&self) -> ExtDecimal {
+        match self {
+            ExtDecimal::Finite(value) => ExtDecimal::Finite(value.abs()),
+            ExtDecimal::NaN => ExtDecimal::NaN,
+            ExtDecimal::PosInf | ExtDecimal::NegInf => ExtDecimal::PosInf,
+        }
+    }
+
+    /// Returns the ordering between `self` and `other` used by PostgreSQL, in which `NaN` is
+    /// equal to itself and greater than every other value, including positive infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, ExtDecimal};
+    /// # use core::cmp::Ordering;
+    /// #
+    /// let mut values = [ExtDecimal::NaN, ExtDecimal::PosInf, ExtDecimal::from(Decimal::ONE)];
+    /// values.sort_by(ExtDecimal::total_cmp);
+    /// assert_eq!(values[0], ExtDecimal::from(Decimal::ONE));
+    /// assert!(values[2].is_nan());
+    /// assert_eq!(ExtDecimal::NaN.total_cmp(&ExtDecimal::NaN), Ordering::Equal);
+    /// ```
+    #[must_use]
+    pub fn total_cmp(&self, other: &ExtDecimal) -> Ordering {
+        let rank = |value: &ExtDecimal| match value {
+            ExtDecimal::NegInf => 0,
+            ExtDecimal::Finite(_) => 1,
+            ExtDecimal::PosInf => 2,
+            ExtDecimal::NaN => 3,
+        };
+        match (self, other) {
+            (ExtDecimal::Finite(a), ExtDecimal::Finite(b)) => a.cmp(b),
+            _ => rank(self).cmp(&rank(other)),
+        }
+    }
+
+    fn infinity(negative: bool) -> ExtDecimal {
+        if negative {
+            ExtDecimal::NegInf
+        } else {
+            ExtDecimal::PosInf
+        }
+    }
+}
+
+impl Default for ExtDecimal {
+    /// Returns the default value for an `ExtDecimal` (equivalent to zero).
+    fn default() -> Self {
+        ExtDecimal::Finite(Decimal::ZERO)
+    }
+}
+
+impl From<Decimal> for ExtDecimal {
+    #[inline]
+    fn from(value: Decimal) -> ExtDecimal {
+        ExtDecimal::Finite(value)
+    }
+}
+
+impl TryFrom<ExtDecimal> for Decimal {
+    type Error = Error;
+
+    /// Converts a finite `ExtDecimal` into a `Decimal`, failing for infinities and `NaN`.
+    fn try_from(value: ExtDecimal) -> Result<Decimal, Error> {
+        value.to_decimal().ok_or(Error::ConversionTo("Decimal"))
+    }
+}
+
+impl FromStr for ExtDecimal {
+    type Err = Error;
+
+    /// Parses a decimal string, or one of the special values `NaN`, `Infinity` and `-Infinity`.
+    /// As with PostgreSQL, the special values are case insensitive and infinity may be
+    /// abbreviated to `inf`.
+    fn from_str(value: &str) -> Result<ExtDecimal, Error> {
+        let (negative, unsigned) = match value.as_bytes().first() {
+            Some(b'-') => (true, &value[1..]),
+            Some(b'+') => (false, &value[1..]),
+            _ => (false, value),
+        };
+        if unsigned.eq_ignore_ascii_case("infinity") || unsigned.eq_ignore_ascii_case("inf") {
+            Ok(ExtDecimal::infinity(negative))
+        } else if value.eq_ignore_ascii_case("nan") {
+            Ok(ExtDecimal::NaN)
+        } else {
+            Decimal::from_str(value).map(ExtDecimal::Finite)
+        }
+    }
+}
+
+impl fmt::Display for ExtDecimal {
+    /// Formats finite values as a `Decimal` would, and special values using the PostgreSQL
+    /// spelling of `NaN`, `Infinity` and `-Infinity`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ExtDecimal::Finite(value) => fmt::Display::fmt(value, f),
+            ExtDecimal::NaN => f.pad("NaN"),
+            ExtDecimal::PosInf => f.pad("Infinity"),
+            ExtDecimal::NegInf => f.pad("-Infinity"),
+        }
+    }
+}
+
+impl PartialEq for ExtDecimal {
+    fn eq(&self, other: &ExtDecimal) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for ExtDecimal {
+    /// Compares two values, returning `None` if either is `NaN`.
+    fn partial_cmp(&self, other: &ExtDecimal) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            Some(self.total_cmp(other))
+        }
+    }
+}
+
+impl Neg for ExtDecimal {
+    type Output = ExtDecimal;
+
+    fn neg(self) -> ExtDecimal {
+        -&self
+    }
+}
+
+impl Neg for &ExtDecimal {
+    type Output = ExtDecimal;
+
+    fn neg(self) -> ExtDecimal {
+        match self {
+            ExtDecimal::Finite(value) => ExtDecimal::Finite(-*value),
+            ExtDecimal::NaN => ExtDecimal::NaN,
+            ExtDecimal::PosInf => ExtDecimal::NegInf,
+            ExtDecimal::NegInf => ExtDecimal::PosInf,
+        }
+    }
+}
+
+// Every operation produces a value, so the messages below can never be reached
+impl_binop!(impl Add for ExtDecimal, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl Sub for ExtDecimal, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl Mul for ExtDecimal, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl Div for ExtDecimal, div, DivAssign, div_assign, div_impl, "Division overflowed");
+impl_binop!(impl Rem for ExtDecimal, rem, RemAssign, rem_assign, rem_impl, "Division overflowed");
+
+fn add_impl(a: &ExtDecimal, b: &ExtDecimal) -> Option<ExtDecimal> {
+    Some(match (a, b) {
+        (ExtDecimal::NaN, _) | (_, ExtDecimal::NaN) => ExtDecimal::NaN,
+        (ExtDecimal::PosInf, ExtDecimal::NegInf) | (ExtDecimal::NegInf, ExtDecimal::PosInf) => ExtDecimal::NaN,
+        (ExtDecimal::PosInf, _) | (_, ExtDecimal::PosInf) => ExtDecimal::PosInf,
+        (ExtDecimal::NegInf, _) | (_, ExtDecimal::NegInf) => ExtDecimal::NegInf,
+        (ExtDecimal::Finite(a), ExtDecimal::Finite(b)) => {
+            // Addition can only overflow when both operands share the same sign
+            a.checked_add(*b)
+                .map_or(ExtDecimal::infinity(a.is_sign_negative()), ExtDecimal::Finite)
+        }
+    })
+}
+
+fn sub_impl(a: &ExtDecimal, b: &ExtDecimal) -> Option<ExtDecimal> {
+    add_impl(a, &-b)
+}
+
+fn mul_impl(a: &ExtDecimal, b: &ExtDecimal) -> Option<ExtDecimal> {
+    let negative = a.is_sign_negative() != b.is_sign_negative();
+    Some(match (a, b) {
+        (ExtDecimal::NaN, _) | (_, ExtDecimal::NaN) => ExtDecimal::NaN,
+        (ExtDecimal::Finite(a), ExtDecimal::Finite(b)) => a
+            .checked_mul(*b)
+            .map_or(ExtDecimal::infinity(negative), ExtDecimal::Finite),
+        (ExtDecimal::Finite(value), _) | (_, ExtDecimal::Finite(value)) if value.is_zero() => ExtDecimal::NaN,
+        _ => ExtDecimal::infinity(negative),
+    })
+}
+
+fn div_impl(a: &ExtDecimal, b: &ExtDecimal) -> Option<ExtDecimal> {
+    let negative = a.is_sign_negative() != b.is_sign_negative();
+    Some(match (a, b) {
+        (ExtDecimal::NaN, _) | (_, ExtDecimal::NaN) => ExtDecimal::NaN,
+        (ExtDecimal::Finite(a), ExtDecimal::Finite(b)) => {
+            if b.is_zero() {
+                if a.is_zero() {
+                    ExtDecimal::NaN
+                } else {
+                    ExtDecimal::infinity(negative)
+                }
+            } else {
+                a.checked_div(*b)
+                    .map_or(ExtDecimal::infinity(negative), ExtDecimal::Finite)
+            }
+        }
+        (ExtDecimal::Finite(_), _) => ExtDecimal::Finite(Decimal::ZERO),
+        (_, ExtDecimal::Finite(_)) => ExtDecimal::infinity(negative),
+        _ => ExtDecimal::NaN,
+    })
+}
+
+fn rem_impl(a: &ExtDecimal, b: &ExtDecimal) -> Option<ExtDecimal> {
+    Some(match (a, b) {
+        (ExtDecimal::Finite(a), ExtDecimal::Finite(b)) => a.checked_rem(*b).map_or(ExtDecimal::NaN, ExtDecimal::Finite),
+        (ExtDecimal::Finite(a), ExtDecimal::PosInf | ExtDecimal::NegInf) => ExtDecimal::Finite(*a),
+        _ => ExtDecimal::NaN,
+    })
+}
@@ -0,0 +1,371 @@
+use crate::decimal::out_of_range;
+use crate::decimal192::{impl_binop, parse_exact};
+use crate::ops::wide::{DecWide, Target, div_truncated, round_exact};
+use crate::{Decimal, Error, RoundingStrategy};
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+#[cfg(feature = "diesel")]
+use diesel::{deserialize::FromSqlRow, expression::AsExpression, sql_types::Numeric};
+use num_traits::{One, Zero};
+
+/// The largest mantissa that can be represented, matching the 96 bits available to a `Decimal`.
+const MAX_MANTISSA: i128 = (1 << 96) - 1;
+
+/// `FixedDecimal` represents a decimal number with a scale of `SCALE` that is fixed by its type,
+/// such as a `NUMERIC(18, 2)` column. The value is held as a 96 bit mantissa, so any
+/// `FixedDecimal` converts into a [`Decimal`] without loss.
+///
+/// Arithmetic always preserves `SCALE`. Addition, subtraction and remainder are exact, while
+/// multiplication and division round their result to `SCALE` decimal places using
+/// [`RoundingStrategy::MidpointNearestEven`], or the strategy given to [`Self::mul_with_strategy`]
+/// and [`Self::div_with_strategy`].
+///
+/// `SCALE` must not exceed [`Decimal::MAX_SCALE`], which is checked at compile time.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{FixedDecimal, RoundingStrategy};
+/// # use core::str::FromStr;
+/// #
+/// type Money = FixedDecimal<2>;
+///
+/// let price = Money::from_str("19.99").unwrap();
+/// let quantity = Money::from_str("3").unwrap();
+/// assert_eq!((price * quantity).to_string(), "59.97");
+/// assert_eq!((price / quantity).to_string(), "6.66");
+/// assert_eq!(
+///     price.div_with_strategy(quantity, RoundingStrategy::AwayFromZero).unwrap().to_string(),
+///     "6.67"
+/// );
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "diesel", derive(FromSqlRow, AsExpression), diesel(sql_type = Numeric))]
+pub struct FixedDecimal<const SCALE: u32>(i128);
+
+impl<const SCALE: u32> FixedDecimal<SCALE> {
+    const VALID_SCALE: () = assert!(SCALE <= Decimal::MAX_SCALE, "SCALE exceeds the maximum supported scale");
+
+    /// The smallest value that can be represented by this decimal type.
+    pub const MIN: FixedDecimal<SCALE> = FixedDecimal::from_valid(-MAX_MANTISSA);
+    /// The largest value that can be represented by this decimal type.
+    pub const MAX: FixedDecimal<SCALE> = FixedDecimal::from_valid(MAX_MANTISSA);
+    /// A constant representing 0.
+    pub const ZERO: FixedDecimal<SCALE> = FixedDecimal::from_valid(0);
+    /// A constant representing 1.
+    pub const ONE: FixedDecimal<SCALE> = FixedDecimal::from_valid(10i128.pow(SCALE));
+
+    const fn from_valid(mantissa: i128) -> FixedDecimal<SCALE> {
+        let () = Self::VALID_SCALE;
+        FixedDecimal(mantissa)
+    }
+
+    /// Creates a `FixedDecimal` from its mantissa, representing `mantissa * 10^-SCALE`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `mantissa` exceeds the maximum supported 96 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::FixedDecimal;
+    /// #
+    /// let value = FixedDecimal::<2>::from_mantissa(-12345);
+    /// assert_eq!(value.to_string(), "-123.45");
+    /// ```
+    #[must_use]
+    pub fn from_mantissa(mantissa: i128) -> FixedDecimal<SCALE> {
+        match Self::try_from_mantissa(mantissa) {
+            Err(e) => panic!("{e}"),
+            Ok(d) => d,
+        }
+    }
+
+    /// Checked version of [`Self::from_mantissa`]. Will return an error instead of panicking at
+    /// run-time.
+    pub const fn try_from_mantissa(mantissa: i128) -> crate::Result<FixedDecimal<SCALE>> {
+        if mantissa > MAX_MANTISSA {
+            Err(Error::ExceedsMaximumPossibleValue)
+        } else if mantissa < -MAX_MANTISSA {
+            Err(Error::LessThanMinimumPossibleValue)
+        } else {
+            Ok(FixedDecimal::from_valid(mantissa))
+        }
+    }
+
+    /// Returns the mantissa of the decimal number.
+    #[inline]
+    #[must_use]
+    pub const fn mantissa(&self) -> i128 {
+        self.0
+    }
+
+    /// Returns the scale of the decimal number, which is always `SCALE`.
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self) -> u32 {
+        SCALE
+    }
+
+    /// Returns `true` if this FixedDecimal number is equivalent to zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the decimal is negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns `true` if the decimal is positive.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(&self) -> bool {
+        self.0 >= 0
+    }
+
+    /// Computes the absolute value of `self`.
+    #[inline]
+    #[must_use]
+    pub const fn abs(&self) -> FixedDecimal<SCALE> {
+        FixedDecimal(self.0.abs())
+    }
+
+    /// Converts a `Decimal` into a `FixedDecimal`, rounding it to `SCALE` decimal places with the
+    /// given strategy. Returns `None` if the result doesn't fit within 96 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, FixedDecimal, RoundingStrategy};
+    /// #
+    /// let value = FixedDecimal::<2>::from_decimal_with_strategy(Decimal::new(-1005, 3), RoundingStrategy::ToZero);
+    /// assert_eq!(value.unwrap().to_string(), "-1.00");
+    /// ```
+    #[must_use]
+    pub fn from_decimal_with_strategy(value: Decimal, strategy: RoundingStrategy) -> Option<FixedDecimal<SCALE>> {
+        let rounded = round_exact(
+            &value.mantissa_array3(),
+            value.scale(),
+            value.is_sign_negative(),
+            false,
+            Target::Scale(SCALE),
+            strategy,
+        )?;
+        FixedDecimal::from_rounded(rounded.value)
+    }
+
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, other: FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+        FixedDecimal::try_from_mantissa(self.0 + other.0).ok()
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, other: FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+        FixedDecimal::try_from_mantissa(self.0 - other.0).ok()
+    }
+
+    /// Checked multiplication. Computes `self * other` rounded to `SCALE` decimal places using
+    /// Banker's Rounding, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, other: FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+        self.mul_with_strategy(other, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Computes `self * other` rounded to `SCALE` decimal places using the given strategy,
+    /// returning `None` if overflow occurred.
+    #[must_use]
+    pub fn mul_with_strategy(
+        self,
+        other: FixedDecimal<SCALE>,
+        strategy: RoundingStrategy,
+    ) -> Option<FixedDecimal<SCALE>> {
+        let product = DecWide::from_product(&Decimal::from(self), &Decimal::from(other))?;
+        FixedDecimal::from_rounded(product.round(Target::Scale(SCALE), strategy)?.value)
+    }
+
+    /// Checked division. Computes `self / other` rounded to `SCALE` decimal places using Banker's
+    /// Rounding, returning `None` if `other == 0.0` or the division results in overflow.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, other: FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+        self.div_with_strategy(other, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Computes `self / other` rounded to `SCALE` decimal places using the given strategy,
+    /// returning `None` if `other == 0.0` or the division results in overflow.
+    #[must_use]
+    pub fn div_with_strategy(
+        self,
+        other: FixedDecimal<SCALE>,
+        strategy: RoundingStrategy,
+    ) -> Option<FixedDecimal<SCALE>> {
+        if other.is_zero() {
+            return None;
+        }
+        // Truncating to one more place than needed leaves a digit to round away whenever the
+        // remainder is non-zero
+        let (quotient, scale, sticky) = div_truncated(&Decimal::from(self), &Decimal::from(other), SCALE + 1);
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+        let rounded = round_exact(&quotient, scale, negative, sticky, Target::Scale(SCALE), strategy)?;
+        FixedDecimal::from_rounded(rounded.value)
+    }
+
+    /// Checked remainder. Computes `self % other`, returning `None` if `other == 0.0`.
+    #[inline]
+    #[must_use]
+    pub fn checked_rem(self, other: FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+        self.0.checked_rem(other.0).map(FixedDecimal)
+    }
+
+    /// Takes the mantissa of a `Decimal` that has already been rounded to `SCALE` decimal places.
+    /// Returns `None` if the coefficient couldn't be padded out to `SCALE` within 96 bits.
+    fn from_rounded(value: Decimal) -> Option<FixedDecimal<SCALE>> {
+        (value.scale() == SCALE).then(|| FixedDecimal::from_valid(value.mantissa()))
+    }
+}
+
+fn add_impl<const SCALE: u32>(a: &FixedDecimal<SCALE>, b: &FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+    a.checked_add(*b)
+}
+
+fn sub_impl<const SCALE: u32>(a: &FixedDecimal<SCALE>, b: &FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+    a.checked_sub(*b)
+}
+
+fn mul_impl<const SCALE: u32>(a: &FixedDecimal<SCALE>, b: &FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+    a.checked_mul(*b)
+}
+
+fn div_impl<const SCALE: u32>(a: &FixedDecimal<SCALE>, b: &FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+    a.checked_div(*b)
+}
+
+fn rem_impl<const SCALE: u32>(a: &FixedDecimal<SCALE>, b: &FixedDecimal<SCALE>) -> Option<FixedDecimal<SCALE>> {
+    a.checked_rem(*b)
+}
+
+impl<const SCALE: u32> Zero for FixedDecimal<SCALE> {
+    fn zero() -> FixedDecimal<SCALE> {
+        FixedDecimal::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        FixedDecimal::is_zero(self)
+    }
+}
+
+impl<const SCALE: u32> One for FixedDecimal<SCALE> {
+    fn one() -> FixedDecimal<SCALE> {
+        FixedDecimal::ONE
+    }
+}
+
+impl<const SCALE: u32> From<FixedDecimal<SCALE>> for Decimal {
+    /// Converts a `FixedDecimal` into a `Decimal` without loss, with a scale of `SCALE`.
+    fn from(value: FixedDecimal<SCALE>) -> Decimal {
+        Decimal::from_i128_with_scale(value.0, SCALE)
+    }
+}
+
+impl<const SCALE: u32> TryFrom<Decimal> for FixedDecimal<SCALE> {
+    type Error = Error;
+
+    /// Converts a `Decimal` into a `FixedDecimal`, rounding it to `SCALE` decimal places using
+    /// Banker's Rounding. Fails if the result doesn't fit within 96 bits.
+    fn try_from(value: Decimal) -> Result<FixedDecimal<SCALE>, Error> {
+        FixedDecimal::from_decimal_with_strategy(value, RoundingStrategy::MidpointNearestEven)
+            .ok_or(out_of_range(value.is_sign_negative()))
+    }
+}
+
+impl<const SCALE: u32> FromStr for FixedDecimal<SCALE> {
+    type Err = Error;
+
+    /// Parses a decimal string such as `-1_000.25`, rounding it once to `SCALE` decimal places
+    /// using Banker's Rounding.
+    fn from_str(value: &str) -> Result<FixedDecimal<SCALE>, Error> {
+        let parsed = parse_exact(value)?;
+        round_exact(
+            &parsed.data,
+            parsed.scale,
+            parsed.negative,
+            parsed.sticky,
+            Target::Scale(SCALE),
+            RoundingStrategy::MidpointNearestEven,
+        )
+        .and_then(|rounded| FixedDecimal::from_rounded(rounded.value))
+        .ok_or(out_of_range(parsed.negative))
+    }
+}
+
+impl<const SCALE: u32> fmt::Display for FixedDecimal<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(&Decimal::from(*self), f)
+    }
+}
+
+impl<const SCALE: u32> fmt::Debug for FixedDecimal<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const SCALE: u32> Neg for FixedDecimal<SCALE> {
+    type Output = FixedDecimal<SCALE>;
+
+    fn neg(self) -> FixedDecimal<SCALE> {
+        FixedDecimal(-self.0)
+    }
+}
+
+impl<const SCALE: u32> Neg for &FixedDecimal<SCALE> {
+    type Output = FixedDecimal<SCALE>;
+
+    fn neg(self) -> FixedDecimal<SCALE> {
+        FixedDecimal(-self.0)
+    }
+}
+
+impl_binop!(impl[const SCALE: u32] Add for FixedDecimal<SCALE>, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl[const SCALE: u32] Sub for FixedDecimal<SCALE>, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl[const SCALE: u32] Mul for FixedDecimal<SCALE>, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl[const SCALE: u32] Div for FixedDecimal<SCALE>, div, DivAssign, div_assign, div_impl, "Division by zero" | "Division overflowed");
+impl_binop!(impl[const SCALE: u32] Rem for FixedDecimal<SCALE>, rem, RemAssign, rem_assign, rem_impl, "Division by zero" | "Division overflowed");
+
+impl<const SCALE: u32> Sum for FixedDecimal<SCALE> {
+    fn sum<I: Iterator<Item = FixedDecimal<SCALE>>>(iter: I) -> Self {
+        iter.fold(FixedDecimal::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl<'a, const SCALE: u32> Sum<&'a FixedDecimal<SCALE>> for FixedDecimal<SCALE> {
+    fn sum<I: Iterator<Item = &'a FixedDecimal<SCALE>>>(iter: I) -> Self {
+        iter.fold(FixedDecimal::ZERO, |sum, value| sum + value)
+    }
+}
+
+impl<const SCALE: u32> Product for FixedDecimal<SCALE> {
+    fn product<I: Iterator<Item = FixedDecimal<SCALE>>>(iter: I) -> Self {
+        iter.fold(FixedDecimal::ONE, |product, value| product * value)
+    }
+}
+
+impl<'a, const SCALE: u32> Product<&'a FixedDecimal<SCALE>> for FixedDecimal<SCALE> {
+    fn product<I: Iterator<Item = &'a FixedDecimal<SCALE>>>(iter: I) -> Self {
+        iter.fold(FixedDecimal::ONE, |product, value| product * value)
+    }
+}
@@ -0,0 +1,430 @@
+use crate::decimal192::impl_binop;
+use crate::{Context, Decimal, RoundingStrategy};
+
+#[cfg(feature = "maths")]
+use crate::MathematicalOps;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "maths")]
+use num_traits::ToPrimitive;
+
+/// `DecimalInterval` is a closed interval `[lo, hi]` that is guaranteed to contain the exact
+/// result of the calculations that produced it.
+///
+/// Every operation computes its bounds with directed rounding: the lower bound is rounded using
+/// [`RoundingStrategy::ToNegativeInfinity`] and the upper bound using
+/// [`RoundingStrategy::ToPositiveInfinity`], each at full [`Context::MAX_PRECISION`]. The width of
+/// the resulting interval is therefore a rigorous bound on the rounding error accumulated along
+/// the way.
+///
+/// The checked operations return `None`, and the operators panic, if a bound overflows or a
+/// divisor contains zero.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::DecimalInterval;
+/// # use rust_decimal_macros::dec;
+/// #
+/// let price = DecimalInterval::from(dec!(10));
+/// let third = price / DecimalInterval::from(dec!(3));
+/// assert_eq!(third.to_string(), "[3.3333333333333333333333333333, 3.3333333333333333333333333334]");
+/// assert!(!third.contains(&dec!(3.3333)));
+/// assert_eq!(third.width(), Some(dec!(0.0000000000000000000000000001)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalInterval {
+    lo: Decimal,
+    hi: Decimal,
+}
+
+// Euler's number and the logarithms used for range reduction, each enclosed by its neighbouring
+// 28 decimal place values
+#[cfg(feature = "maths")]
+const E: DecimalInterval = DecimalInterval {
+    lo: Decimal::from_str_const("2.7182818284590452353602874713"),
+    hi: Decimal::from_str_const("2.7182818284590452353602874714"),
+};
+#[cfg(feature = "maths")]
+const LN_2: DecimalInterval = DecimalInterval {
+    lo: Decimal::from_str_const("0.6931471805599453094172321214"),
+    hi: Decimal::from_str_const("0.6931471805599453094172321215"),
+};
+#[cfg(feature = "maths")]
+const LN_10: DecimalInterval = DecimalInterval {
+    lo: Decimal::from_str_const("2.3025850929940456840179914546"),
+    hi: Decimal::from_str_const("2.3025850929940456840179914547"),
+};
+// The smallest positive decimal, below which series terms can no longer be distinguished
+#[cfg(feature = "maths")]
+const EPSILON: Decimal = Decimal::from_str_const("1e-28");
+// 1 ± 10^-27, used to step a square root bound outwards until it is verified
+#[cfg(feature = "maths")]
+const ONE_MINUS_EPSILON: Decimal = Decimal::from_str_const("0.999_999_999_999_999_999_999_999_999");
+#[cfg(feature = "maths")]
+const ONE_PLUS_EPSILON: Decimal = Decimal::from_str_const("1.000_000_000_000_000_000_000_000_001");
+
+impl DecimalInterval {
+    /// Creates the interval `[lo, hi]`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `lo` is greater than `hi`.
+    #[must_use]
+    pub fn new(lo: Decimal, hi: Decimal) -> DecimalInterval {
+        match Self::checked_new(lo, hi) {
+            Some(interval) => interval,
+            None => panic!("Lower bound exceeds upper bound"),
+        }
+    }
+
+    /// Checked version of [`Self::new`]. Returns `None` if `lo` is greater than `hi`.
+    #[must_use]
+    pub fn checked_new(lo: Decimal, hi: Decimal) -> Option<DecimalInterval> {
+        if lo > hi {
+            return None;
+        }
+        Some(DecimalInterval { lo, hi })
+    }
+
+    /// Returns the lower bound.
+    #[inline]
+    #[must_use]
+    pub const fn lo(&self) -> Decimal {
+        self.lo
+    }
+
+    /// Returns the upper bound.
+    #[inline]
+    #[must_use]
+    pub const fn hi(&self) -> Decimal {
+        self.hi
+    }
+
+    /// Returns the width `hi - lo`, rounded up so that it never understates the uncertainty.
+    /// Returns `None` if the width exceeds the range of a `Decimal`.
+    #[must_use]
+    pub fn width(&self) -> Option<Decimal> {
+        up().sub(self.hi, self.lo).ok()
+    }
+
+    /// Returns the value halfway between the bounds, rounded to the nearest representable value.
+    /// The result always lies within the interval.
+    #[must_use]
+    pub fn midpoint(&self) -> Decimal {
+        // Halving first means the sum can't overflow
+        let half = Decimal::TWO;
+        (self.lo / half + self.hi / half).clamp(self.lo, self.hi)
+    }
+
+    /// Returns `true` if `value` lies within the interval, including its bounds.
+    #[must_use]
+    pub fn contains(&self, value: &Decimal) -> bool {
+        self.lo <= *value && *value <= self.hi
+    }
+
+    /// Returns `true` if the interval contains zero.
+    #[must_use]
+    pub fn contains_zero(&self) -> bool {
+        self.contains(&Decimal::ZERO)
+    }
+
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, other: DecimalInterval) -> Option<DecimalInterval> {
+        add_impl(&self, &other)
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, other: DecimalInterval) -> Option<DecimalInterval> {
+        sub_impl(&self, &other)
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, other: DecimalInterval) -> Option<DecimalInterval> {
+        mul_impl(&self, &other)
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` contains zero or
+    /// overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, other: DecimalInterval) -> Option<DecimalInterval> {
+        div_impl(&self, &other)
+    }
+
+    /// Computes an interval containing the square root of every value in `self`. Returns `None`
+    /// if the interval contains a negative number.
+    ///
+    /// Each bound is verified by squaring it with directed rounding, so the result is rigorous.
+    #[cfg(feature = "maths")]
+    #[must_use]
+    pub fn sqrt(&self) -> Option<DecimalInterval> {
+        if self.lo.is_sign_negative() && !self.lo.is_zero() {
+            return None;
+        }
+        Some(DecimalInterval {
+            lo: sqrt_lower(&self.lo)?,
+            hi: sqrt_upper(&self.hi)?,
+        })
+    }
+
+    /// Computes an interval containing `e` raised to the power of every value in `self`. Returns
+    /// `None` on overflow.
+    ///
+    /// The series expansion is itself evaluated with interval arithmetic, and its truncation
+    /// error is added to the bounds.
+    #[cfg(feature = "maths")]
+    #[must_use]
+    pub fn exp(&self) -> Option<DecimalInterval> {
+        Some(DecimalInterval {
+            lo: exp_bounds(&self.lo)?.lo,
+            hi: exp_bounds(&self.hi)?.hi,
+        })
+    }
+
+    /// Computes an interval containing the natural logarithm of every value in `self`. Returns
+    /// `None` if the interval contains zero or a negative number.
+    ///
+    /// The series expansion is itself evaluated with interval arithmetic, and its truncation
+    /// error is added to the bounds.
+    #[cfg(feature = "maths")]
+    #[must_use]
+    pub fn ln(&self) -> Option<DecimalInterval> {
+        if self.lo.is_sign_negative() || self.lo.is_zero() {
+            return None;
+        }
+        Some(DecimalInterval {
+            lo: ln_bounds(&self.lo)?.lo,
+            hi: ln_bounds(&self.hi)?.hi,
+        })
+    }
+}
+
+impl From<Decimal> for DecimalInterval {
+    /// Creates the degenerate interval `[value, value]`, for values known exactly.
+    #[inline]
+    fn from(value: Decimal) -> DecimalInterval {
+        DecimalInterval { lo: value, hi: value }
+    }
+}
+
+impl fmt::Display for DecimalInterval {
+    /// Formats the interval as `[lo, hi]`, applying any precision to both bounds. The bounds are
+    /// rounded outward so that the printed interval still encloses this one.
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match f.precision() {
+            Some(precision) => {
+                let dp = u32::try_from(precision).unwrap_or(u32::MAX);
+                let lo = self.lo.round_dp_with_strategy(dp, RoundingStrategy::ToNegativeInfinity);
+                let hi = self.hi.round_dp_with_strategy(dp, RoundingStrategy::ToPositiveInfinity);
+                write!(f, "[{lo:.precision$}, {hi:.precision$}]")
+            }
+            None => write!(f, "[{}, {}]", self.lo, self.hi),
+        }
+    }
+}
+
+impl Neg for DecimalInterval {
+    type Output = DecimalInterval;
+
+    fn neg(self) -> DecimalInterval {
+        -&self
+    }
+}
+
+impl Neg for &DecimalInterval {
+    type Output = DecimalInterval;
+
+    fn neg(self) -> DecimalInterval {
+        DecimalInterval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl_binop!(impl Add for DecimalInterval, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl Sub for DecimalInterval, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl Mul for DecimalInterval, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl Div for DecimalInterval, div, DivAssign, div_assign, div_impl, "Division by an interval containing zero or overflowed");
+
+fn down() -> Context {
+    Context::new(Context::MAX_PRECISION, RoundingStrategy::ToNegativeInfinity)
+}
+
+fn up() -> Context {
+    Context::new(Context::MAX_PRECISION, RoundingStrategy::ToPositiveInfinity)
+}
+
+fn add_impl(a: &DecimalInterval, b: &DecimalInterval) -> Option<DecimalInterval> {
+    Some(DecimalInterval {
+        lo: down().add(a.lo, b.lo).ok()?,
+        hi: up().add(a.hi, b.hi).ok()?,
+    })
+}
+
+fn sub_impl(a: &DecimalInterval, b: &DecimalInterval) -> Option<DecimalInterval> {
+    Some(DecimalInterval {
+        lo: down().sub(a.lo, b.hi).ok()?,
+        hi: up().sub(a.hi, b.lo).ok()?,
+    })
+}
+
+fn mul_impl(a: &DecimalInterval, b: &DecimalInterval) -> Option<DecimalInterval> {
+    extremes(a, b, Context::mul)
+}
+
+fn div_impl(a: &DecimalInterval, b: &DecimalInterval) -> Option<DecimalInterval> {
+    if b.contains_zero() {
+        return None;
+    }
+    extremes(a, b, Context::div)
+}
+
+/// Applies `op` to every combination of bounds, taking the smallest result rounded down and the
+/// largest rounded up. This covers every sign combination for multiplication and division.
+fn extremes(
+    a: &DecimalInterval,
+    b: &DecimalInterval,
+    op: fn(&mut Context, Decimal, Decimal) -> crate::Result<Decimal>,
+) -> Option<DecimalInterval> {
+    let pairs = [(a.lo, b.lo), (a.lo, b.hi), (a.hi, b.lo), (a.hi, b.hi)];
+    let mut result: Option<DecimalInterval> = None;
+    for (x, y) in pairs {
+        let lo = op(&mut down(), x, y).ok()?;
+        let hi = op(&mut up(), x, y).ok()?;
+        result = Some(match result {
+            Some(r) => DecimalInterval {
+                lo: r.lo.min(lo),
+                hi: r.hi.max(hi),
+            },
+            None => DecimalInterval { lo, hi },
+        });
+    }
+    result
+}
+
+/// Returns the largest value found whose square doesn't exceed `value`.
+#[cfg(feature = "maths")]
+fn sqrt_lower(value: &Decimal) -> Option<Decimal> {
+    let mut root = value.sqrt()?;
+    // Rounding the square up can only overstate it, so the check never accepts a bound too large.
+    // A square too large to represent certainly exceeds the value.
+    while up().mul(root, root).ok().is_none_or(|square| square > *value) {
+        root = down().mul(root, ONE_MINUS_EPSILON).ok()?;
+    }
+    Some(root)
+}
+
+/// Returns the smallest value found whose square isn't less than `value`.
+#[cfg(feature = "maths")]
+fn sqrt_upper(value: &Decimal) -> Option<Decimal> {
+    let mut root = value.sqrt()?;
+    if root.is_zero() && !value.is_zero() {
+        root = EPSILON;
+    }
+    // A square too large to represent certainly isn't less than the value
+    while down().mul(root, root).is_ok_and(|square| square < *value) {
+        root = up().mul(root, ONE_PLUS_EPSILON).ok()?;
+    }
+    Some(root)
+}
+
+/// Encloses `e^value`, using `e^value = e^n * e^r` where `n` is the integral part of `value`.
+#[cfg(feature = "maths")]
+fn exp_bounds(value: &Decimal) -> Option<DecimalInterval> {
+    if value.is_sign_negative() {
+        return match exp_bounds(&-*value) {
+            Some(positive) => div_impl(&DecimalInterval::from(Decimal::ONE), &positive),
+            // e^-value exceeds Decimal::MAX, so e^value is below the smallest positive decimal
+            None => Some(DecimalInterval {
+                lo: Decimal::ZERO,
+                hi: EPSILON,
+            }),
+        };
+    }
+    let integral = value.trunc();
+    let fraction = DecimalInterval::from(value - integral);
+
+    // The Taylor series for e^r, where 0 <= r < 1
+    let mut sum = DecimalInterval::from(Decimal::ONE);
+    let mut term = sum;
+    for k in 1u32.. {
+        term = div_impl(&mul_impl(&term, &fraction)?, &DecimalInterval::from(Decimal::from(k)))?;
+        sum = add_impl(&sum, &term)?;
+        if term.hi <= EPSILON {
+            // Each remaining term is less than half of the one before, so together they are
+            // smaller than the last term added
+            sum.hi = up().add(sum.hi, term.hi).ok()?;
+            break;
+        }
+    }
+
+    // e^n by repeated squaring
+    let mut power = E;
+    let mut n = integral.to_u32()?;
+    while n > 0 {
+        if n & 1 == 1 {
+            sum = mul_impl(&sum, &power)?;
+        }
+        n >>= 1;
+        if n > 0 {
+            power = mul_impl(&power, &power)?;
+        }
+    }
+    Some(sum)
+}
+
+/// Encloses `ln(value)` for a positive `value`, using `ln(value) = k ln(10) + j ln(2) + ln(m)`
+/// where `m` is close to one.
+#[cfg(feature = "maths")]
+fn ln_bounds(value: &Decimal) -> Option<DecimalInterval> {
+    if *value == Decimal::ONE {
+        return Some(DecimalInterval::from(Decimal::ZERO));
+    }
+    // Dividing by a power of ten only moves the decimal point, so is exact
+    let mantissa = value.mantissa();
+    let digits = mantissa.ilog10();
+    let tens = i64::from(digits) - i64::from(value.scale());
+    let mut reduced = DecimalInterval::from(Decimal::from_i128_with_scale(mantissa, digits));
+    let mut twos = 0;
+    let two = DecimalInterval::from(Decimal::TWO);
+    while reduced.hi > Decimal::new(15, 1) {
+        reduced = div_impl(&reduced, &two)?;
+        twos += 1;
+    }
+
+    // ln(m) = 2 atanh(z) where z = (m - 1) / (m + 1), so |z| < 0.2
+    let one = DecimalInterval::from(Decimal::ONE);
+    let z = div_impl(&sub_impl(&reduced, &one)?, &add_impl(&reduced, &one)?)?;
+    let z_squared = mul_impl(&z, &z)?;
+    let mut sum = z;
+    let mut power = z;
+    for k in 1u32.. {
+        power = mul_impl(&power, &z_squared)?;
+        let term = div_impl(&power, &DecimalInterval::from(Decimal::from(2 * k + 1)))?;
+        sum = add_impl(&sum, &term)?;
+        let magnitude = term.lo.abs().max(term.hi.abs());
+        if magnitude <= EPSILON {
+            // The remaining terms shrink by a factor of at least 25, so are bounded by the last
+            sum = add_impl(
+                &sum,
+                &DecimalInterval {
+                    lo: -magnitude,
+                    hi: magnitude,
+                },
+            )?;
+            break;
+        }
+    }
+
+    let mut result = mul_impl(&sum, &two)?;
+    result = add_impl(&result, &mul_impl(&LN_10, &DecimalInterval::from(Decimal::from(tens)))?)?;
+    add_impl(&result, &mul_impl(&LN_2, &DecimalInterval::from(Decimal::from(twos)))?)
+}
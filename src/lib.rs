@@ -5,21 +5,37 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 extern crate alloc;
 
+mod allocation;
+mod checked;
 mod constants;
+mod context;
 mod decimal;
+mod decimal128;
+mod decimal192;
+mod decimal64;
 mod error;
+mod ext_decimal;
+mod fixed_decimal;
+mod interval;
 mod ops;
+mod parse;
+mod ratio;
+mod saturating;
 pub mod str;
 
 // We purposely place this here for documentation ordering
 mod arithmetic_impls;
 
+#[cfg(feature = "big-decimal")]
+mod big_decimal;
 #[cfg(feature = "borsh")]
 mod borsh;
 #[cfg(feature = "rust-fuzz")]
 mod fuzz;
 #[cfg(feature = "maths")]
 mod maths;
+#[cfg(feature = "money")]
+mod money;
 #[cfg(feature = "db-diesel-mysql")]
 mod mysql;
 #[cfg(any(
@@ -55,11 +71,31 @@ mod serde;
     )
 ))]
 pub mod serde;
+#[cfg(any(feature = "rand-0_9", feature = "rand-0_10"))]
+mod stochastic;
 
+pub use allocation::AllocationStrategy;
+#[cfg(feature = "big-decimal")]
+pub use big_decimal::BigDecimal;
+pub use checked::Checked;
+pub use context::{Context, Signal, Signals};
 pub use decimal::{Decimal, RoundingStrategy};
+pub use decimal64::Decimal64;
+pub use decimal128::Decimal128;
+pub use decimal192::Decimal192;
 pub use error::Error;
+pub use ext_decimal::ExtDecimal;
+pub use fixed_decimal::FixedDecimal;
+pub use interval::DecimalInterval;
 #[cfg(feature = "maths")]
 pub use maths::MathematicalOps;
+#[cfg(feature = "money")]
+pub use money::{Currency, Money};
+pub use parse::ParseOptions;
+pub use ratio::DecimalRatio;
+pub use saturating::Saturating;
+#[cfg(any(feature = "rand-0_9", feature = "rand-0_10"))]
+pub use stochastic::StochasticRounding;
 
 /// A convenience module appropriate for glob imports (`use rust_decimal::prelude::*;`).
 pub mod prelude {
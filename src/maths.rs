@@ -1,10 +1,10 @@
 use crate::prelude::*;
 use num_traits::pow::Pow;
 
-// Approximation of 1/ln(10) = 0.4342944819032518276511289189
-const LN10_INVERSE: Decimal = Decimal::from_parts_raw(1763037029, 1670682625, 235431510, 1835008);
+// Approximation of 1/ln(10)
+const LN10_INVERSE: Decimal = Decimal::from_str_const("0.4342944819032518276511289189");
 // PI / 8
-const EIGHTH_PI: Decimal = Decimal::from_parts_raw(2822163429, 3244459792, 212882598, 1835008);
+const EIGHTH_PI: Decimal = Decimal::from_str_const("0.3926990816987241548078304229");
 
 // Table representing {index}! — used in tests to verify factorial values.
 #[cfg(test)]
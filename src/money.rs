@@ -0,0 +1,527 @@
+use crate::{Decimal, Error, RoundingStrategy};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+/// An ISO 4217 currency, along with the number of minor units (decimal places) it is quoted in.
+///
+/// Only the currencies within the embedded ISO 4217 table can be represented, which excludes
+/// precious metals and other codes that have no minor units defined.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::Currency;
+/// # use core::str::FromStr;
+/// #
+/// let dinar = Currency::from_str("KWD").unwrap();
+/// assert_eq!(dinar.minor_units(), 3);
+/// assert_eq!(Currency::JPY.minor_units(), 0);
+/// assert!(Currency::from_code("ABC").is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Currency {
+    code: &'static str,
+    minor_units: u32,
+}
+
+impl Currency {
+    /// The euro.
+    pub const EUR: Currency = Currency::new("EUR", 2);
+    /// The pound sterling.
+    pub const GBP: Currency = Currency::new("GBP", 2);
+    /// The Japanese yen.
+    pub const JPY: Currency = Currency::new("JPY", 0);
+    /// The United States dollar.
+    pub const USD: Currency = Currency::new("USD", 2);
+
+    const fn new(code: &'static str, minor_units: u32) -> Currency {
+        Currency { code, minor_units }
+    }
+
+    /// Looks up a currency by its three letter ISO 4217 code, such as `USD`. Codes are matched
+    /// case sensitively, so `usd` is not recognised.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Currency> {
+        CURRENCIES
+            .binary_search_by(|currency| currency.code.cmp(code))
+            .ok()
+            .map(|index| CURRENCIES[index])
+    }
+
+    /// Returns the three letter ISO 4217 code of the currency.
+    #[inline]
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the number of decimal places that amounts in this currency are quoted in, e.g. 2
+    /// for `USD` and 0 for `JPY`.
+    #[inline]
+    #[must_use]
+    pub const fn minor_units(&self) -> u32 {
+        self.minor_units
+    }
+}
+
+impl FromStr for Currency {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<Currency, Error> {
+        Currency::from_code(code).ok_or(Error::UnknownCurrency)
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.pad(self.code)
+    }
+}
+
+/// `Money` pairs a [`Decimal`] amount with the [`Currency`] it is denominated in.
+///
+/// Amounts keep whatever scale they are given, so that intermediate results aren't rounded
+/// prematurely. [`Self::round`] and [`Self::round_with_strategy`] round an amount to the minor
+/// units of its currency once it is ready to be presented or settled.
+///
+/// Adding, subtracting or comparing amounts of different currencies is refused: the checked
+/// [`Self::try_add`] and [`Self::try_sub`] return [`Error::CurrencyMismatch`], the operators
+/// panic, and comparisons return `None`.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Currency, Decimal, Money, RoundingStrategy};
+/// # use core::str::FromStr;
+/// #
+/// let price = Money::from_str("USD 12.34").unwrap();
+/// let total = price * Decimal::new(15, 2);
+/// assert_eq!(total.to_string(), "USD 1.8510");
+/// assert_eq!(total.round().to_string(), "USD 1.85");
+/// assert_eq!(total.round_with_strategy(RoundingStrategy::AwayFromZero).to_string(), "USD 1.86");
+///
+/// let fee = Money::from_str("1.5 EUR").unwrap();
+/// assert!(price.try_add(fee).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Creates an amount of the given currency, retaining the scale of `amount`.
+    #[must_use]
+    pub const fn new(amount: Decimal, currency: Currency) -> Money {
+        Money { amount, currency }
+    }
+
+    /// Creates a zero amount of the given currency, quoted in its minor units.
+    #[must_use]
+    pub fn zero(currency: Currency) -> Money {
+        Money::new(Decimal::new(0, currency.minor_units), currency)
+    }
+
+    /// Returns the amount, without its currency.
+    #[inline]
+    #[must_use]
+    pub const fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Returns the currency the amount is denominated in.
+    #[inline]
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns `true` if the amount is zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    /// Returns `true` if the amount is negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.amount.is_sign_negative()
+    }
+
+    /// Computes the absolute value of the amount.
+    #[must_use]
+    pub fn abs(&self) -> Money {
+        Money::new(self.amount.abs(), self.currency)
+    }
+
+    /// Rounds the amount to the minor units of its currency using Banker's Rounding. Amounts
+    /// with fewer decimal places are padded with trailing zeros.
+    #[must_use]
+    pub fn round(&self) -> Money {
+        self.round_with_strategy(RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Rounds the amount to the minor units of its currency using the given strategy. Amounts
+    /// with fewer decimal places are padded with trailing zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Currency, Decimal, Money, RoundingStrategy};
+    /// #
+    /// let yen = Money::new(Decimal::new(12345, 2), Currency::JPY);
+    /// assert_eq!(yen.round_with_strategy(RoundingStrategy::ToZero).to_string(), "JPY 123");
+    /// let dollars = Money::new(Decimal::from(5), Currency::USD);
+    /// assert_eq!(dollars.round_with_strategy(RoundingStrategy::ToZero).to_string(), "USD 5.00");
+    /// ```
+    #[must_use]
+    pub fn round_with_strategy(&self, strategy: RoundingStrategy) -> Money {
+        let minor_units = self.currency.minor_units;
+        let mut amount = self.amount.round_dp_with_strategy(minor_units, strategy);
+        amount.rescale(minor_units);
+        Money::new(amount, self.currency)
+    }
+
+    /// Adds two amounts of the same currency, failing if the currencies differ or the sum
+    /// overflows.
+    pub fn try_add(self, other: Money) -> crate::Result<Money> {
+        let currency = self.same_currency(&other)?;
+        match self.amount.checked_add(other.amount) {
+            Some(amount) => Ok(Money::new(amount, currency)),
+            None => Err(crate::decimal::out_of_range(self.is_sign_negative())),
+        }
+    }
+
+    /// Subtracts `other` from `self`, failing if the currencies differ or the difference
+    /// overflows.
+    pub fn try_sub(self, other: Money) -> crate::Result<Money> {
+        self.try_add(-other)
+    }
+
+    /// Checked multiplication by a factor. Returns `None` if overflow occurred.
+    #[must_use]
+    pub fn checked_mul(self, factor: Decimal) -> Option<Money> {
+        Some(Money::new(self.amount.checked_mul(factor)?, self.currency))
+    }
+
+    /// Checked division by a divisor. Returns `None` if `divisor` is zero or overflow occurred.
+    #[must_use]
+    pub fn checked_div(self, divisor: Decimal) -> Option<Money> {
+        Some(Money::new(self.amount.checked_div(divisor)?, self.currency))
+    }
+
+    fn same_currency(&self, other: &Money) -> crate::Result<Currency> {
+        if self.currency == other.currency {
+            Ok(self.currency)
+        } else {
+            Err(Error::CurrencyMismatch(self.currency.code, other.currency.code))
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats the amount after its currency code, e.g. `USD 12.34`. Any precision given is
+    /// applied to the amount.
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.currency.code)?;
+        f.write_str(" ")?;
+        fmt::Display::fmt(&self.amount, f)
+    }
+}
+
+impl FromStr for Money {
+    type Err = Error;
+
+    /// Parses an amount with its currency code either before or after it, separated by
+    /// whitespace, such as `USD 12.34` or `12.34 USD`.
+    fn from_str(value: &str) -> Result<Money, Error> {
+        let (first, second) = value
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or(Error::UnknownCurrency)?;
+        let second = second.trim_start();
+        let (code, amount) = if first.bytes().all(|b| b.is_ascii_alphabetic()) {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        Ok(Money::new(Decimal::from_str(amount)?, Currency::from_str(code)?))
+    }
+}
+
+impl PartialOrd for Money {
+    /// Compares the amounts, returning `None` if the currencies differ.
+    fn partial_cmp(&self, other: &Money) -> Option<Ordering> {
+        self.same_currency(other).ok()?;
+        Some(self.amount.cmp(&other.amount))
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money::new(-self.amount, self.currency)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    #[inline]
+    fn add(self, other: Money) -> Money {
+        match self.try_add(other) {
+            Ok(sum) => sum,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl AddAssign for Money {
+    #[inline]
+    fn add_assign(&mut self, other: Money) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    #[inline]
+    fn sub(self, other: Money) -> Money {
+        match self.try_sub(other) {
+            Ok(difference) => difference,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl SubAssign for Money {
+    #[inline]
+    fn sub_assign(&mut self, other: Money) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<Decimal> for Money {
+    type Output = Money;
+
+    #[inline]
+    fn mul(self, factor: Decimal) -> Money {
+        Money::new(self.amount * factor, self.currency)
+    }
+}
+
+impl MulAssign<Decimal> for Money {
+    #[inline]
+    fn mul_assign(&mut self, factor: Decimal) {
+        *self = *self * factor;
+    }
+}
+
+impl Div<Decimal> for Money {
+    type Output = Money;
+
+    #[inline]
+    fn div(self, divisor: Decimal) -> Money {
+        Money::new(self.amount / divisor, self.currency)
+    }
+}
+
+impl DivAssign<Decimal> for Money {
+    #[inline]
+    fn div_assign(&mut self, divisor: Decimal) {
+        *self = *self / divisor;
+    }
+}
+
+/// The active ISO 4217 currencies with a defined number of minor units, sorted by code.
+const CURRENCIES: &[Currency] = &[
+    Currency::new("AED", 2),
+    Currency::new("AFN", 2),
+    Currency::new("ALL", 2),
+    Currency::new("AMD", 2),
+    Currency::new("ANG", 2),
+    Currency::new("AOA", 2),
+    Currency::new("ARS", 2),
+    Currency::new("AUD", 2),
+    Currency::new("AWG", 2),
+    Currency::new("AZN", 2),
+    Currency::new("BAM", 2),
+    Currency::new("BBD", 2),
+    Currency::new("BDT", 2),
+    Currency::new("BGN", 2),
+    Currency::new("BHD", 3),
+    Currency::new("BIF", 0),
+    Currency::new("BMD", 2),
+    Currency::new("BND", 2),
+    Currency::new("BOB", 2),
+    Currency::new("BOV", 2),
+    Currency::new("BRL", 2),
+    Currency::new("BSD", 2),
+    Currency::new("BTN", 2),
+    Currency::new("BWP", 2),
+    Currency::new("BYN", 2),
+    Currency::new("BZD", 2),
+    Currency::new("CAD", 2),
+    Currency::new("CDF", 2),
+    Currency::new("CHE", 2),
+    Currency::new("CHF", 2),
+    Currency::new("CHW", 2),
+    Currency::new("CLF", 4),
+    Currency::new("CLP", 0),
+    Currency::new("CNY", 2),
+    Currency::new("COP", 2),
+    Currency::new("COU", 2),
+    Currency::new("CRC", 2),
+    Currency::new("CUP", 2),
+    Currency::new("CVE", 2),
+    Currency::new("CZK", 2),
+    Currency::new("DJF", 0),
+    Currency::new("DKK", 2),
+    Currency::new("DOP", 2),
+    Currency::new("DZD", 2),
+    Currency::new("EGP", 2),
+    Currency::new("ERN", 2),
+    Currency::new("ETB", 2),
+    Currency::EUR,
+    Currency::new("FJD", 2),
+    Currency::new("FKP", 2),
+    Currency::GBP,
+    Currency::new("GEL", 2),
+    Currency::new("GHS", 2),
+    Currency::new("GIP", 2),
+    Currency::new("GMD", 2),
+    Currency::new("GNF", 0),
+    Currency::new("GTQ", 2),
+    Currency::new("GYD", 2),
+    Currency::new("HKD", 2),
+    Currency::new("HNL", 2),
+    Currency::new("HTG", 2),
+    Currency::new("HUF", 2),
+    Currency::new("IDR", 2),
+    Currency::new("ILS", 2),
+    Currency::new("INR", 2),
+    Currency::new("IQD", 3),
+    Currency::new("IRR", 2),
+    Currency::new("ISK", 0),
+    Currency::new("JMD", 2),
+    Currency::new("JOD", 3),
+    Currency::JPY,
+    Currency::new("KES", 2),
+    Currency::new("KGS", 2),
+    Currency::new("KHR", 2),
+    Currency::new("KMF", 0),
+    Currency::new("KPW", 2),
+    Currency::new("KRW", 0),
+    Currency::new("KWD", 3),
+    Currency::new("KYD", 2),
+    Currency::new("KZT", 2),
+    Currency::new("LAK", 2),
+    Currency::new("LBP", 2),
+    Currency::new("LKR", 2),
+    Currency::new("LRD", 2),
+    Currency::new("LSL", 2),
+    Currency::new("LYD", 3),
+    Currency::new("MAD", 2),
+    Currency::new("MDL", 2),
+    Currency::new("MGA", 2),
+    Currency::new("MKD", 2),
+    Currency::new("MMK", 2),
+    Currency::new("MNT", 2),
+    Currency::new("MOP", 2),
+    Currency::new("MRU", 2),
+    Currency::new("MUR", 2),
+    Currency::new("MVR", 2),
+    Currency::new("MWK", 2),
+    Currency::new("MXN", 2),
+    Currency::new("MXV", 2),
+    Currency::new("MYR", 2),
+    Currency::new("MZN", 2),
+    Currency::new("NAD", 2),
+    Currency::new("NGN", 2),
+    Currency::new("NIO", 2),
+    Currency::new("NOK", 2),
+    Currency::new("NPR", 2),
+    Currency::new("NZD", 2),
+    Currency::new("OMR", 3),
+    Currency::new("PAB", 2),
+    Currency::new("PEN", 2),
+    Currency::new("PGK", 2),
+    Currency::new("PHP", 2),
+    Currency::new("PKR", 2),
+    Currency::new("PLN", 2),
+    Currency::new("PYG", 0),
+    Currency::new("QAR", 2),
+    Currency::new("RON", 2),
+    Currency::new("RSD", 2),
+    Currency::new("RUB", 2),
+    Currency::new("RWF", 0),
+    Currency::new("SAR", 2),
+    Currency::new("SBD", 2),
+    Currency::new("SCR", 2),
+    Currency::new("SDG", 2),
+    Currency::new("SEK", 2),
+    Currency::new("SGD", 2),
+    Currency::new("SHP", 2),
+    Currency::new("SLE", 2),
+    Currency::new("SOS", 2),
+    Currency::new("SRD", 2),
+    Currency::new("SSP", 2),
+    Currency::new("STN", 2),
+    Currency::new("SVC", 2),
+    Currency::new("SYP", 2),
+    Currency::new("SZL", 2),
+    Currency::new("THB", 2),
+    Currency::new("TJS", 2),
+    Currency::new("TMT", 2),
+    Currency::new("TND", 3),
+    Currency::new("TOP", 2),
+    Currency::new("TRY", 2),
+    Currency::new("TTD", 2),
+    Currency::new("TWD", 2),
+    Currency::new("TZS", 2),
+    Currency::new("UAH", 2),
+    Currency::new("UGX", 0),
+    Currency::USD,
+    Currency::new("USN", 2),
+    Currency::new("UYI", 0),
+    Currency::new("UYU", 2),
+    Currency::new("UYW", 4),
+    Currency::new("UZS", 2),
+    Currency::new("VED", 2),
+    Currency::new("VES", 2),
+    Currency::new("VND", 0),
+    Currency::new("VUV", 0),
+    Currency::new("WST", 2),
+    Currency::new("XAF", 0),
+    Currency::new("XCD", 2),
+    Currency::new("XCG", 2),
+    Currency::new("XOF", 0),
+    Currency::new("XPF", 0),
+    Currency::new("YER", 2),
+    Currency::new("ZAR", 2),
+    Currency::new("ZMW", 2),
+    Currency::new("ZWG", 2),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn currencies_are_sorted_by_code() {
+        // Lookups rely on a binary search of the table
+        assert!(CURRENCIES.windows(2).all(|pair| pair[0].code < pair[1].code));
+        assert!(
+            CURRENCIES
+                .iter()
+                .all(|c| c.code.len() == 3 && c.code.bytes().all(|b| b.is_ascii_uppercase()))
+        );
+    }
+}
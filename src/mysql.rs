@@ -1,4 +1,4 @@
-use crate::Decimal;
+use crate::{Decimal, FixedDecimal};
 use diesel::{
     deserialize::{self, FromSql},
     mysql::Mysql,
@@ -26,6 +26,21 @@ impl FromSql<Numeric, Mysql> for Decimal {
     }
 }
 
+#[cfg(feature = "diesel")]
+impl<const SCALE: u32> ToSql<Numeric, Mysql> for FixedDecimal<SCALE> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        write!(out, "{}", *self).map(|_| IsNull::No).map_err(|e| e.into())
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl<const SCALE: u32> FromSql<Numeric, Mysql> for FixedDecimal<SCALE> {
+    fn from_sql(numeric: diesel::mysql::MysqlValue) -> deserialize::Result<Self> {
+        let value = <Decimal as FromSql<Numeric, Mysql>>::from_sql(numeric)?;
+        Ok(FixedDecimal::try_from(value)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
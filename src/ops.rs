@@ -17,5 +17,4 @@ pub(crate) use div::div_impl;
 pub(crate) use mul::mul_impl;
 pub(crate) use rem::rem_impl;
 
-#[cfg(feature = "maths")]
 pub(crate) mod wide;
@@ -2,16 +2,16 @@ use crate::constants::{MAX_I32_SCALE, POWERS_10, SCALE_MASK, SCALE_SHIFT, SIGN_M
 use crate::decimal::{CalculationResult, Decimal};
 use crate::ops::common::{Buf24, Dec64};
 
-pub(crate) fn add_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
+pub(crate) const fn add_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
     add_sub_internal(d1, d2, false)
 }
 
-pub(crate) fn sub_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
+pub(crate) const fn sub_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
     add_sub_internal(d1, d2, true)
 }
 
 #[inline]
-fn add_sub_internal(d1: &Decimal, d2: &Decimal, subtract: bool) -> CalculationResult {
+const fn add_sub_internal(d1: &Decimal, d2: &Decimal, subtract: bool) -> CalculationResult {
     if d1.is_zero() {
         // 0 - x or 0 + x
         let mut result = *d2;
@@ -85,14 +85,14 @@ fn add_sub_internal(d1: &Decimal, d2: &Decimal, subtract: bool) -> CalculationRe
 }
 
 #[inline(always)]
-fn rescale32(num: u32, rescale_factor: i32) -> Option<u32> {
+const fn rescale32(num: u32, rescale_factor: i32) -> Option<u32> {
     if rescale_factor > MAX_I32_SCALE {
         return None;
     }
     num.checked_mul(POWERS_10[rescale_factor as usize])
 }
 
-fn fast_add(lo1: u32, lo2: u32, flags: u32, subtract: bool) -> CalculationResult {
+const fn fast_add(lo1: u32, lo2: u32, flags: u32, subtract: bool) -> CalculationResult {
     if subtract {
         // Sub can't overflow because we're ensuring the bigger number always subtracts the smaller number
         if lo1 < lo2 {
@@ -106,7 +106,7 @@ fn fast_add(lo1: u32, lo2: u32, flags: u32, subtract: bool) -> CalculationResult
     CalculationResult::Ok(Decimal::from_parts_raw(lo, mid, 0, flags))
 }
 
-fn aligned_add(lhs: Dec64, rhs: Dec64, negative: bool, scale: u32, subtract: bool) -> CalculationResult {
+const fn aligned_add(lhs: Dec64, rhs: Dec64, negative: bool, scale: u32, subtract: bool) -> CalculationResult {
     if subtract {
         // Signs differ, so subtract
         let mut result = Dec64 {
@@ -154,7 +154,7 @@ fn aligned_add(lhs: Dec64, rhs: Dec64, negative: bool, scale: u32, subtract: boo
     }
 }
 
-fn flip_sign(result: &mut Dec64) {
+const fn flip_sign(result: &mut Dec64) {
     // Bitwise not the high portion
     result.hi = !result.hi;
     let low64 = ((result.low64 as i64).wrapping_neg()) as u64;
@@ -165,7 +165,7 @@ fn flip_sign(result: &mut Dec64) {
     result.negative = !result.negative;
 }
 
-fn reduce_scale(result: &mut Dec64) {
+const fn reduce_scale(result: &mut Dec64) {
     let mut low64 = result.low64;
     let mut hi = result.hi;
 
@@ -194,7 +194,7 @@ fn reduce_scale(result: &mut Dec64) {
 
 // Assumption going into this function is that the LHS is the larger number and will "absorb" the
 // smaller number.
-fn unaligned_add(
+const fn unaligned_add(
     lhs: Dec64,
     rhs: Dec64,
     negative: bool,
@@ -285,13 +285,15 @@ fn unaligned_add(
             POWERS_10[9] as u64
         };
         tmp64 = 0;
-        for (index, part) in buffer.data.iter_mut().enumerate() {
-            tmp64 = tmp64.wrapping_add((*part as u64) * power);
-            *part = tmp64 as u32;
+        let mut index = 0;
+        while index < buffer.data.len() {
+            tmp64 = tmp64.wrapping_add((buffer.data[index] as u64) * power);
+            buffer.data[index] = tmp64 as u32;
             tmp64 >>= 32;
             if index + 1 > upper_word {
                 break;
             }
+            index += 1;
         }
 
         if tmp64 & U32_MASK > 0 {
@@ -322,11 +324,13 @@ fn unaligned_add(
         };
 
         if carry {
-            for part in buffer.data.iter_mut().skip(3) {
-                *part = part.wrapping_sub(1);
-                if *part > 0 {
+            let mut index = 3;
+            while index < buffer.data.len() {
+                buffer.data[index] = buffer.data[index].wrapping_sub(1);
+                if buffer.data[index] > 0 {
                     break;
                 }
+                index += 1;
             }
 
             if buffer.data[upper_word] == 0 && upper_word < 3 {
@@ -352,16 +356,18 @@ fn unaligned_add(
         };
 
         if carry {
-            for (index, part) in buffer.data.iter_mut().enumerate().skip(3) {
+            let mut index = 3;
+            while index < buffer.data.len() {
                 if upper_word < index {
-                    *part = 1;
+                    buffer.data[index] = 1;
                     upper_word = index;
                     break;
                 }
-                *part = part.wrapping_add(1);
-                if *part > 0 {
+                buffer.data[index] = buffer.data[index].wrapping_add(1);
+                if buffer.data[index] > 0 {
                     break;
                 }
+                index += 1;
             }
         }
     }
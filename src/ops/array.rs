@@ -3,19 +3,23 @@ use crate::constants::{MAX_SCALE_U32, POWERS_10, U32_MASK};
 /// Rescales the given decimal to new scale.
 /// e.g. with 1.23 and new scale 3 rescale the value to 1.230
 #[inline]
-pub(crate) fn rescale_internal(value: &mut [u32; 3], value_scale: &mut u32, new_scale: u32) {
+pub(crate) const fn rescale_internal(value: &mut [u32; 3], value_scale: &mut u32, new_scale: u32) {
     rescale::<true>(value, value_scale, new_scale);
 }
 
 #[inline(always)]
-fn rescale<const ROUND: bool>(value: &mut [u32; 3], value_scale: &mut u32, new_scale: u32) {
+const fn rescale<const ROUND: bool>(value: &mut [u32; 3], value_scale: &mut u32, new_scale: u32) {
     if *value_scale == new_scale {
         // Nothing to do
         return;
     }
 
     if is_all_zero(value) {
-        *value_scale = new_scale.min(MAX_SCALE_U32);
+        *value_scale = if new_scale < MAX_SCALE_U32 {
+            new_scale
+        } else {
+            MAX_SCALE_U32
+        };
         return;
     }
 
@@ -38,13 +42,15 @@ fn rescale<const ROUND: bool>(value: &mut [u32; 3], value_scale: &mut u32, new_s
             remainder = div_by_u32(value, 10);
         }
         if ROUND && remainder >= 5 {
-            for part in value.iter_mut() {
-                let digit = u64::from(*part) + 1u64;
+            let mut i = 0;
+            while i < value.len() {
+                let digit = value[i] as u64 + 1u64;
                 remainder = if digit > U32_MASK { 1 } else { 0 };
-                *part = (digit & U32_MASK) as u32;
+                value[i] = (digit & U32_MASK) as u32;
                 if remainder == 0 {
                     break;
                 }
+                i += 1;
             }
         }
         *value_scale = new_scale;
@@ -53,7 +59,7 @@ fn rescale<const ROUND: bool>(value: &mut [u32; 3], value_scale: &mut u32, new_s
         let mut working = [value[0], value[1], value[2]];
         while let Some(diff_minus_one) = diff.checked_sub(1) {
             if mul_by_10(&mut working) == 0 {
-                value.copy_from_slice(&working);
+                *value = working;
                 diff = diff_minus_one;
             } else {
                 break;
@@ -64,46 +70,35 @@ fn rescale<const ROUND: bool>(value: &mut [u32; 3], value_scale: &mut u32, new_s
 }
 
 #[inline]
-pub(crate) fn truncate_internal(value: &mut [u32; 3], value_scale: &mut u32, desired_scale: u32) {
+pub(crate) const fn truncate_internal(value: &mut [u32; 3], value_scale: &mut u32, desired_scale: u32) {
     rescale::<false>(value, value_scale, desired_scale);
 }
 
-pub(crate) fn add_by_internal_flattened(value: &mut [u32; 3], by: u32) -> u32 {
+pub(crate) const fn add_by_internal_flattened(value: &mut [u32; 3], by: u32) -> u32 {
     manage_add_by_internal(by, value)
 }
 
 #[inline]
-pub(crate) fn add_one_internal(value: &mut [u32; 3]) -> u32 {
+pub(crate) const fn add_one_internal(value: &mut [u32; 3]) -> u32 {
     manage_add_by_internal(1, value)
 }
 
 // `u64 as u32` are safe because of widening and 32bits shifts
 #[inline]
-pub(crate) fn manage_add_by_internal<const N: usize>(initial_carry: u32, value: &mut [u32; N]) -> u32 {
-    let mut carry = u64::from(initial_carry);
-    let mut iter = 0..value.len();
-    let mut sum = 0;
-
-    let mut sum_fn = |local_carry: &mut u64, idx| {
-        sum = u64::from(value[idx]).wrapping_add(*local_carry);
+pub(crate) const fn manage_add_by_internal(initial_carry: u32, value: &mut [u32]) -> u32 {
+    let mut carry = initial_carry as u64;
+    let mut idx = 0;
+    while idx < value.len() && (idx == 0 || carry > 0) {
+        let sum = (value[idx] as u64).wrapping_add(carry);
         value[idx] = (sum & U32_MASK) as u32;
-        *local_carry = sum.wrapping_shr(32);
-    };
-
-    if let Some(idx) = iter.next() {
-        sum_fn(&mut carry, idx);
-    }
-
-    for idx in iter {
-        if carry > 0 {
-            sum_fn(&mut carry, idx);
-        }
+        carry = sum.wrapping_shr(32);
+        idx += 1;
     }
 
     carry as u32
 }
 
-pub(crate) fn sub_by_internal(value: &mut [u32], by: &[u32]) -> u32 {
+pub(crate) const fn sub_by_internal(value: &mut [u32], by: &[u32]) -> u32 {
     // The way this works is similar to long subtraction
     // Let's assume we're working with bytes for simplicity in an example:
     //   257 - 8 = 249
@@ -135,19 +130,18 @@ pub(crate) fn sub_by_internal(value: &mut [u32], by: &[u32]) -> u32 {
     let mut overflow = 0;
     let vl = value.len();
     let bl = by.len();
-    for i in 0..vl {
-        if i >= bl {
-            break;
-        }
+    let mut i = 0;
+    while i < vl && i < bl {
         let (lo, hi) = sub_part(value[i], by[i], overflow);
         value[i] = lo;
         overflow = hi;
+        i += 1;
     }
     overflow
 }
 
-fn sub_part(left: u32, right: u32, overflow: u32) -> (u32, u32) {
-    let part = 0x1_0000_0000u64 + u64::from(left) - (u64::from(right) + u64::from(overflow));
+const fn sub_part(left: u32, right: u32, overflow: u32) -> (u32, u32) {
+    let part = 0x1_0000_0000u64 + left as u64 - (right as u64 + overflow as u64);
     let lo = part as u32;
     let hi = 1 - ((part >> 32) as u32);
     (lo, hi)
@@ -155,39 +149,43 @@ fn sub_part(left: u32, right: u32, overflow: u32) -> (u32, u32) {
 
 // Returns overflow
 #[inline]
-pub(crate) fn mul_by_10(bits: &mut [u32; 3]) -> u32 {
+pub(crate) const fn mul_by_10(bits: &mut [u32; 3]) -> u32 {
     let mut overflow = 0u64;
-    for b in bits.iter_mut() {
-        let result = u64::from(*b) * 10u64 + overflow;
+    let mut i = 0;
+    while i < bits.len() {
+        let result = bits[i] as u64 * 10u64 + overflow;
         let hi = (result >> 32) & U32_MASK;
         let lo = (result & U32_MASK) as u32;
-        *b = lo;
+        bits[i] = lo;
         overflow = hi;
+        i += 1;
     }
 
     overflow as u32
 }
 
 // Returns overflow
-pub(crate) fn mul_by_u32(bits: &mut [u32], m: u32) -> u32 {
+pub(crate) const fn mul_by_u32(bits: &mut [u32], m: u32) -> u32 {
     let mut overflow = 0;
-    for b in bits.iter_mut() {
-        let (lo, hi) = mul_part(*b, m, overflow);
-        *b = lo;
+    let mut i = 0;
+    while i < bits.len() {
+        let (lo, hi) = mul_part(bits[i], m, overflow);
+        bits[i] = lo;
         overflow = hi;
+        i += 1;
     }
     overflow
 }
 
-pub(crate) fn mul_part(left: u32, right: u32, high: u32) -> (u32, u32) {
-    let result = u64::from(left) * u64::from(right) + u64::from(high);
+pub(crate) const fn mul_part(left: u32, right: u32, high: u32) -> (u32, u32) {
+    let result = left as u64 * right as u64 + high as u64;
     let hi = ((result >> 32) & U32_MASK) as u32;
     let lo = (result & U32_MASK) as u32;
     (lo, hi)
 }
 
 // Returns remainder
-pub(crate) fn div_by_u32<const N: usize>(bits: &mut [u32; N], divisor: u32) -> u32 {
+pub(crate) const fn div_by_u32(bits: &mut [u32], divisor: u32) -> u32 {
     if divisor == 0 {
         // Divide by zero
         panic!("Internal error: divide by zero");
@@ -196,11 +194,13 @@ pub(crate) fn div_by_u32<const N: usize>(bits: &mut [u32; N], divisor: u32) -> u
         0
     } else {
         let mut remainder = 0u32;
-        let divisor = u64::from(divisor);
-        for part in bits.iter_mut().rev() {
-            let temp = (u64::from(remainder) << 32) + u64::from(*part);
+        let divisor = divisor as u64;
+        let mut i = bits.len();
+        while i > 0 {
+            i -= 1;
+            let temp = ((remainder as u64) << 32) + bits[i] as u64;
             remainder = (temp % divisor) as u32;
-            *part = (temp / divisor) as u32;
+            bits[i] = (temp / divisor) as u32;
         }
 
         remainder
@@ -225,22 +225,24 @@ pub(crate) const fn div_by_power<const POWER: usize>(bits: &mut [u32; 3]) -> u32
 }
 
 #[inline]
-pub(crate) fn shl1_internal(bits: &mut [u32], carry: u32) -> u32 {
+pub(crate) const fn shl1_internal(bits: &mut [u32], carry: u32) -> u32 {
     let mut carry = carry;
-    for part in bits.iter_mut() {
-        let b = *part >> 31;
-        *part = (*part << 1) | carry;
+    let mut i = 0;
+    while i < bits.len() {
+        let b = bits[i] >> 31;
+        bits[i] = (bits[i] << 1) | carry;
         carry = b;
+        i += 1;
     }
     carry
 }
 
 #[inline]
-pub(crate) fn cmp_internal(left: &[u32; 3], right: &[u32; 3]) -> core::cmp::Ordering {
+pub(crate) const fn cmp_internal(left: &[u32; 3], right: &[u32; 3]) -> core::cmp::Ordering {
     let left_hi: u32 = left[2];
     let right_hi: u32 = right[2];
-    let left_lo: u64 = (u64::from(left[1]) << 32) | u64::from(left[0]);
-    let right_lo: u64 = (u64::from(right[1]) << 32) | u64::from(right[0]);
+    let left_lo: u64 = ((left[1] as u64) << 32) | left[0] as u64;
+    let right_lo: u64 = ((right[1] as u64) << 32) | right[0] as u64;
     if left_hi < right_hi || (left_hi <= right_hi && left_lo < right_lo) {
         core::cmp::Ordering::Less
     } else if left_hi == right_hi && left_lo == right_lo {
@@ -251,8 +253,15 @@ pub(crate) fn cmp_internal(left: &[u32; 3], right: &[u32; 3]) -> core::cmp::Orde
 }
 
 #[inline]
-pub(crate) fn is_all_zero<const N: usize>(bits: &[u32; N]) -> bool {
-    bits.iter().all(|b| *b == 0)
+pub(crate) const fn is_all_zero(bits: &[u32]) -> bool {
+    let mut i = 0;
+    while i < bits.len() {
+        if bits[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
 }
 
 #[cfg(test)]
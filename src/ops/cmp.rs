@@ -4,7 +4,7 @@ use crate::ops::common::Dec64;
 
 use core::cmp::Ordering;
 
-pub(crate) fn cmp_impl(d1: &Decimal, d2: &Decimal) -> Ordering {
+pub(crate) const fn cmp_impl(d1: &Decimal, d2: &Decimal) -> Ordering {
     if d2.is_zero() {
         return if d1.is_zero() {
             return Ordering::Equal;
@@ -42,7 +42,7 @@ pub(crate) fn cmp_impl(d1: &Decimal, d2: &Decimal) -> Ordering {
     }
 }
 
-pub(in crate::ops) fn cmp_internal(d1: &Dec64, d2: &Dec64) -> Ordering {
+pub(in crate::ops) const fn cmp_internal(d1: &Dec64, d2: &Dec64) -> Ordering {
     // This function ignores sign
     let mut d1_low = d1.low64;
     let mut d1_high = d1.hi;
@@ -63,14 +63,23 @@ pub(in crate::ops) fn cmp_internal(d1: &Dec64, d2: &Dec64) -> Ordering {
     }
 
     // They're the same scale, do a standard bitwise comparison
-    let hi_order = d1_high.cmp(&d2_high);
-    if hi_order != Ordering::Equal {
-        return hi_order;
+    if d1_high != d2_high {
+        return if d1_high < d2_high {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+    }
+    if d1_low == d2_low {
+        Ordering::Equal
+    } else if d1_low < d2_low {
+        Ordering::Less
+    } else {
+        Ordering::Greater
     }
-    d1_low.cmp(&d2_low)
 }
 
-fn rescale(low64: &mut u64, high: &mut u32, diff: u32) -> bool {
+const fn rescale(low64: &mut u64, high: &mut u32, diff: u32) -> bool {
     let mut diff = diff as i32;
     // We need to modify d1 by 10^diff to get it to the same scale as d2
     loop {
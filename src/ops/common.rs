@@ -32,15 +32,15 @@ impl Buf12 {
         self.data[2]
     }
     #[inline(always)]
-    pub fn set_lo(&mut self, value: u32) {
+    pub const fn set_lo(&mut self, value: u32) {
         self.data[0] = value;
     }
     #[inline(always)]
-    pub fn set_mid(&mut self, value: u32) {
+    pub const fn set_mid(&mut self, value: u32) {
         self.data[1] = value;
     }
     #[inline(always)]
-    pub fn set_hi(&mut self, value: u32) {
+    pub const fn set_hi(&mut self, value: u32) {
         self.data[2] = value;
     }
 
@@ -50,7 +50,7 @@ impl Buf12 {
     }
 
     #[inline(always)]
-    pub fn set_low64(&mut self, value: u64) {
+    pub const fn set_low64(&mut self, value: u64) {
         self.data[1] = (value >> 32) as u32;
         self.data[0] = value as u32;
     }
@@ -61,7 +61,7 @@ impl Buf12 {
     }
 
     #[inline(always)]
-    pub fn set_high64(&mut self, value: u64) {
+    pub const fn set_high64(&mut self, value: u64) {
         self.data[2] = (value >> 32) as u32;
         self.data[1] = value as u32;
     }
@@ -71,7 +71,7 @@ impl Buf12 {
     // we're going to overflow. Because x is ultimately used to lookup inside the POWERS array, it
     // must be a valid value 0 <= x <= 9
     #[inline]
-    pub fn find_scale(&self, scale: i32) -> Option<usize> {
+    pub const fn find_scale(&self, scale: i32) -> Option<usize> {
         const OVERFLOW_MAX_9_HI: u32 = 4;
         const OVERFLOW_MAX_8_HI: u32 = 42;
         const OVERFLOW_MAX_7_HI: u32 = 429;
@@ -143,7 +143,7 @@ impl Buf12 {
 
 // This is a table of the largest values that will not overflow when multiplied
 // by a given power as represented by the index.
-static POWER_OVERFLOW_VALUES: [Buf12; 8] = [
+const POWER_OVERFLOW_VALUES: [Buf12; 8] = [
     Buf12 {
         data: [2576980377, 2576980377, 429496729],
     },
@@ -235,7 +235,7 @@ impl Buf16 {
         ((self.data[1] as u64) << 32) | (self.data[0] as u64)
     }
 
-    pub fn set_low64(&mut self, value: u64) {
+    pub const fn set_low64(&mut self, value: u64) {
         self.data[1] = (value >> 32) as u32;
         self.data[0] = value as u32;
     }
@@ -244,7 +244,7 @@ impl Buf16 {
         ((self.data[2] as u64) << 32) | (self.data[1] as u64)
     }
 
-    pub fn set_mid64(&mut self, value: u64) {
+    pub const fn set_mid64(&mut self, value: u64) {
         self.data[2] = (value >> 32) as u32;
         self.data[1] = value as u32;
     }
@@ -253,7 +253,7 @@ impl Buf16 {
         ((self.data[3] as u64) << 32) | (self.data[2] as u64)
     }
 
-    pub fn set_high64(&mut self, value: u64) {
+    pub const fn set_high64(&mut self, value: u64) {
         self.data[3] = (value >> 32) as u32;
         self.data[2] = value as u32;
     }
@@ -275,7 +275,7 @@ impl Buf24 {
         ((self.data[1] as u64) << 32) | (self.data[0] as u64)
     }
 
-    pub fn set_low64(&mut self, value: u64) {
+    pub const fn set_low64(&mut self, value: u64) {
         self.data[1] = (value >> 32) as u32;
         self.data[0] = value as u32;
     }
@@ -285,7 +285,7 @@ impl Buf24 {
         ((self.data[3] as u64) << 32) | (self.data[2] as u64)
     }
 
-    pub fn set_mid64(&mut self, value: u64) {
+    pub const fn set_mid64(&mut self, value: u64) {
         self.data[3] = (value >> 32) as u32;
         self.data[2] = value as u32;
     }
@@ -295,7 +295,7 @@ impl Buf24 {
         ((self.data[5] as u64) << 32) | (self.data[4] as u64)
     }
 
-    pub fn set_high64(&mut self, value: u64) {
+    pub const fn set_high64(&mut self, value: u64) {
         self.data[5] = (value >> 32) as u32;
         self.data[4] = value as u32;
     }
@@ -323,7 +323,7 @@ impl Buf24 {
     // in an Option. If it failed due to overflow, we return None.
     // * `upper` - Index of last non-zero value in self.
     // * `scale` - Current scale factor for this value.
-    pub fn rescale(&mut self, upper: usize, scale: u32) -> Option<u32> {
+    pub const fn rescale(&mut self, upper: usize, scale: u32) -> Option<u32> {
         let mut scale = scale as i32;
         let mut upper = upper;
 
@@ -361,10 +361,12 @@ impl Buf24 {
                 let high_quotient = high / power;
                 remainder = high - high_quotient * power;
 
-                for item in self.data.iter_mut().rev().skip(6 - upper) {
-                    let num = (*item as u64).wrapping_add((remainder as u64) << 32);
-                    *item = (num / power as u64) as u32;
-                    remainder = (num as u32).wrapping_sub(item.wrapping_mul(power));
+                let mut index = upper;
+                while index > 0 {
+                    index -= 1;
+                    let num = (self.data[index] as u64).wrapping_add((remainder as u64) << 32);
+                    self.data[index] = (num / power as u64) as u32;
+                    remainder = (num as u32).wrapping_sub(self.data[index].wrapping_mul(power));
                 }
 
                 self.data[upper] = high_quotient;
@@ -410,12 +412,14 @@ impl Buf24 {
                 // If we carried then propagate through the portions
                 if carried {
                     let mut pos = 0;
-                    for (index, value) in self.data.iter_mut().enumerate().skip(1) {
+                    let mut index = 1;
+                    while index < self.data.len() {
                         pos = index;
-                        *value = value.wrapping_add(1);
-                        if *value != 0 {
+                        self.data[index] = self.data[index].wrapping_add(1);
+                        if self.data[index] != 0 {
                             break;
                         }
+                        index += 1;
                     }
 
                     // If we ended up rounding over the 96 bits then we'll try to rescale down (again)
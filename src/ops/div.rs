@@ -2,11 +2,9 @@ use crate::constants::{MAX_SCALE_I32, POWERS_10};
 use crate::decimal::{CalculationResult, Decimal};
 use crate::ops::common::{Buf12, Buf16, Dec64};
 
-use core::cmp::Ordering;
-
 impl Buf12 {
     // Returns true if successful, else false for an overflow
-    fn add32(&mut self, value: u32) -> Result<(), DivError> {
+    const fn add32(&mut self, value: u32) -> Result<(), DivError> {
         let value = value as u64;
         let new = self.low64().wrapping_add(value);
         self.set_low64(new);
@@ -22,7 +20,7 @@ impl Buf12 {
     // Divide a Decimal union by a 32 bit divisor.
     // Self is overwritten with the quotient.
     // Return value is a 32 bit remainder.
-    fn div32(&mut self, divisor: u32) -> u32 {
+    const fn div32(&mut self, divisor: u32) -> u32 {
         let divisor64 = divisor as u64;
         // See if we can get by using a simple u64 division
         if self.data[2] != 0 {
@@ -55,7 +53,7 @@ impl Buf12 {
 
     // Divide the number by a power constant
     // Returns true if division was successful
-    fn div32_const(&mut self, pow: u32) -> bool {
+    const fn div32_const(&mut self, pow: u32) -> bool {
         let pow64 = pow as u64;
         let high64 = self.high64();
         let lo = self.data[0] as u64;
@@ -78,7 +76,7 @@ impl Buf16 {
     // Consequently, it will return the quotient as a 32 bit number and overwrite self with the
     // 64 bit remainder.
     #[inline]
-    pub(super) fn partial_divide_64(&mut self, divisor: u64) -> u32 {
+    pub(super) const fn partial_divide_64(&mut self, divisor: u64) -> u32 {
         // We make this assertion here, however below we pivot based on the data
         debug_assert!(divisor > self.mid64());
 
@@ -151,7 +149,7 @@ impl Buf16 {
     // Does a partial divide with a 96 bit divisor. The divisor in this case must require 96 bits
     // otherwise various assumptions fail (e.g. 32 bit quotient).
     #[inline]
-    pub(super) fn partial_divide_96(&mut self, divisor: &Buf12) -> u32 {
+    pub(super) const fn partial_divide_96(&mut self, divisor: &Buf12) -> u32 {
         let dividend = self.high64();
         let divisor_hi = divisor.data[2];
         if dividend < divisor_hi as u64 {
@@ -217,7 +215,7 @@ enum DivError {
     Overflow,
 }
 
-pub(crate) fn div_impl(dividend: &Decimal, divisor: &Decimal) -> CalculationResult {
+pub(crate) const fn div_impl(dividend: &Decimal, divisor: &Decimal) -> CalculationResult {
     if divisor.is_zero() {
         return CalculationResult::DivByZero;
     }
@@ -355,7 +353,7 @@ struct DivResult {
 // Remainder processing loop for the 32-bit divisor path.
 // Kept out-of-line to reduce code size of div_impl's fast path.
 #[inline(never)]
-fn div_remainder_32(
+const fn div_remainder_32(
     mut remainder: u32,
     divisor32: u32,
     mut quotient: Buf12,
@@ -371,7 +369,7 @@ fn div_remainder_32(
             if scale >= 0 {
                 break;
             }
-            power_scale = 9usize.min((-scale) as usize);
+            power_scale = if -scale < 9 { (-scale) as usize } else { 9 };
         } else {
             // We may need to normalize later, so set the flag appropriately
             require_unscale = true;
@@ -408,7 +406,10 @@ fn div_remainder_32(
 
                 // If we need to round, try to do so.
                 if round {
-                    scale = round_up(&mut quotient, scale)?;
+                    scale = match round_up(&mut quotient, scale) {
+                        Ok(scale) => scale,
+                        Err(e) => return Err(e),
+                    };
                 }
                 break;
             }
@@ -427,7 +428,10 @@ fn div_remainder_32(
         let remainder_quotient = (remainder_scaled / (divisor32 as u64)) as u32;
         remainder = (remainder_scaled - remainder_quotient as u64 * divisor32 as u64) as u32;
         if let Err(DivError::Overflow) = quotient.add32(remainder_quotient) {
-            scale = unscale_from_overflow(&mut quotient, scale, remainder != 0)?;
+            scale = match unscale_from_overflow(&mut quotient, scale, remainder != 0) {
+                Ok(scale) => scale,
+                Err(e) => return Err(e),
+            };
             break;
         }
     }
@@ -440,7 +444,7 @@ fn div_remainder_32(
 }
 
 // Remainder processing loop for the 64-bit divisor path.
-fn div_remainder_64(
+const fn div_remainder_64(
     mut remainder: Buf16,
     divisor64: u64,
     mut quotient: Buf12,
@@ -456,7 +460,7 @@ fn div_remainder_64(
             if scale >= 0 {
                 break;
             }
-            power_scale = 9usize.min((-scale) as usize);
+            power_scale = if -scale < 9 { (-scale) as usize } else { 9 };
         } else {
             // We may need to normalize later, so set the flag appropriately
             require_unscale = true;
@@ -494,7 +498,10 @@ fn div_remainder_64(
 
                 // If we need to round, try to do so.
                 if round {
-                    scale = round_up(&mut quotient, scale)?;
+                    scale = match round_up(&mut quotient, scale) {
+                        Ok(scale) => scale,
+                        Err(e) => return Err(e),
+                    };
                 }
                 break;
             }
@@ -513,7 +520,10 @@ fn div_remainder_64(
 
         let tmp = remainder.partial_divide_64(divisor64);
         if let Err(DivError::Overflow) = quotient.add32(tmp) {
-            scale = unscale_from_overflow(&mut quotient, scale, remainder.low64() != 0)?;
+            scale = match unscale_from_overflow(&mut quotient, scale, remainder.low64() != 0) {
+                Ok(scale) => scale,
+                Err(e) => return Err(e),
+            };
             break;
         }
     }
@@ -526,7 +536,7 @@ fn div_remainder_64(
 }
 
 // Remainder processing loop for the 96-bit divisor path.
-fn div_remainder_96(
+const fn div_remainder_96(
     mut remainder: Buf16,
     divisor: Buf12,
     mut quotient: Buf12,
@@ -542,7 +552,7 @@ fn div_remainder_96(
             if scale >= 0 {
                 break;
             }
-            power_scale = 9usize.min((-scale) as usize);
+            power_scale = if -scale < 9 { (-scale) as usize } else { 9 };
         } else {
             // We may need to normalize later, so set the flag appropriately
             require_unscale = true;
@@ -572,25 +582,26 @@ fn div_remainder_96(
                     let tmp = remainder.data[1] >> 31;
                     rem_low64 <<= 1;
                     remainder.set_low64(rem_low64);
-                    remainder.data[2] = (&remainder.data[2] << 1) + tmp;
-
-                    match remainder.data[2].cmp(&divisor.data[2]) {
-                        Ordering::Less => false,
-                        Ordering::Equal => {
-                            let divisor_low64 = divisor.low64();
-                            if rem_low64 > divisor_low64 {
-                                true
-                            } else {
-                                rem_low64 == divisor_low64 && (quotient.data[0] & 1) != 0
-                            }
+                    remainder.data[2] = (remainder.data[2] << 1) + tmp;
+
+                    if remainder.data[2] == divisor.data[2] {
+                        let divisor_low64 = divisor.low64();
+                        if rem_low64 > divisor_low64 {
+                            true
+                        } else {
+                            rem_low64 == divisor_low64 && (quotient.data[0] & 1) != 0
                         }
-                        Ordering::Greater => true,
+                    } else {
+                        remainder.data[2] > divisor.data[2]
                     }
                 };
 
                 // If we need to round, try to do so.
                 if round {
-                    scale = round_up(&mut quotient, scale)?;
+                    scale = match round_up(&mut quotient, scale) {
+                        Ok(scale) => scale,
+                        Err(e) => return Err(e),
+                    };
                 }
                 break;
             }
@@ -616,7 +627,10 @@ fn div_remainder_96(
 
         let tmp = remainder.partial_divide_96(&divisor);
         if let Err(DivError::Overflow) = quotient.add32(tmp) {
-            scale = unscale_from_overflow(&mut quotient, scale, (remainder.low64() | remainder.high64()) != 0)?;
+            scale = match unscale_from_overflow(&mut quotient, scale, (remainder.low64() | remainder.high64()) != 0) {
+                Ok(scale) => scale,
+                Err(e) => return Err(e),
+            };
             break;
         }
     }
@@ -630,7 +644,7 @@ fn div_remainder_96(
 
 // Multiply num by power (multiple of 10). Power must be 32 bits.
 // Returns the overflow, if any
-fn increase_scale(num: &mut Buf12, power: u64) -> u32 {
+const fn increase_scale(num: &mut Buf12, power: u64) -> u32 {
     let mut tmp = (num.data[0] as u64) * power;
     num.data[0] = tmp as u32;
     tmp >>= 32;
@@ -643,7 +657,7 @@ fn increase_scale(num: &mut Buf12, power: u64) -> u32 {
 }
 
 // Multiply num by power (multiple of 10). Power must be 32 bits.
-fn increase_scale64(num: &mut Buf16, power: u64) {
+const fn increase_scale64(num: &mut Buf16, power: u64) {
     let mut tmp = (num.data[0] as u64) * power;
     num.data[0] = tmp as u32;
     tmp >>= 32;
@@ -656,7 +670,7 @@ fn increase_scale64(num: &mut Buf16, power: u64) {
 // to undo the overflow and rounding instead.
 // Returns the updated scale.
 #[inline]
-fn unscale_from_overflow(num: &mut Buf12, scale: i32, sticky: bool) -> Result<i32, DivError> {
+const fn unscale_from_overflow(num: &mut Buf12, scale: i32, sticky: bool) -> Result<i32, DivError> {
     let scale = scale - 1;
     if scale < 0 {
         return Err(DivError::Overflow);
@@ -687,7 +701,7 @@ fn unscale_from_overflow(num: &mut Buf12, scale: i32, sticky: bool) -> Result<i3
 }
 
 #[inline]
-fn round_up(num: &mut Buf12, scale: i32) -> Result<i32, DivError> {
+const fn round_up(num: &mut Buf12, scale: i32) -> Result<i32, DivError> {
     let low64 = num.low64().wrapping_add(1);
     num.set_low64(low64);
     if low64 != 0 {
@@ -701,7 +715,7 @@ fn round_up(num: &mut Buf12, scale: i32) -> Result<i32, DivError> {
     unscale_from_overflow(num, scale, true)
 }
 
-fn unscale(num: &mut Buf12, scale: i32) -> i32 {
+const fn unscale(num: &mut Buf12, scale: i32) -> i32 {
     // Since 10 = 2 * 5, there must be a factor of 2 for every power of 10 we can extract.
     // We use this as a quick test on whether to try a given power.
     let mut scale = scale;
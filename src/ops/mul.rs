@@ -2,7 +2,7 @@ use crate::constants::{BIG_POWERS_10, MAX_I64_SCALE, U32_MAX};
 use crate::decimal::{CalculationResult, Decimal};
 use crate::ops::common::Buf24;
 
-pub(crate) fn mul_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
+pub(crate) const fn mul_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
     if d1.is_zero() || d2.is_zero() {
         // We should think about this - does zero need to maintain precision? This treats it like
         // an absolute which I think is ok, especially since we have is_zero() functions etc.
@@ -147,7 +147,7 @@ pub(crate) fn mul_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
 }
 
 #[inline(always)]
-fn mul_by_32bit_lhs(d1: u64, d2: &Decimal, product: &mut Buf24) {
+const fn mul_by_32bit_lhs(d1: u64, d2: &Decimal, product: &mut Buf24) {
     let mut tmp = d1 * d2.lo() as u64;
     product.data[0] = tmp as u32;
     tmp = (d1 * d2.mid() as u64).wrapping_add(tmp >> 32);
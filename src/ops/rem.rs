@@ -2,7 +2,7 @@ use crate::constants::{MAX_I32_SCALE, MAX_SCALE_I32, POWERS_10};
 use crate::decimal::{CalculationResult, Decimal};
 use crate::ops::common::{Buf12, Buf16, Buf24, Dec64};
 
-pub(crate) fn rem_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
+pub(crate) const fn rem_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
     if d2.is_zero() {
         return CalculationResult::DivByZero;
     }
@@ -127,7 +127,7 @@ pub(crate) fn rem_impl(d1: &Decimal, d2: &Decimal) -> CalculationResult {
     CalculationResult::Ok(d1.to_decimal())
 }
 
-fn rem_full(d1: &Dec64, d2: &Buf12, scale: i32) -> CalculationResult {
+const fn rem_full(d1: &Dec64, d2: &Buf12, scale: i32) -> CalculationResult {
     let mut scale = scale;
 
     // First normalize the divisor
@@ -152,13 +152,12 @@ fn rem_full(d1: &Dec64, d2: &Buf12, scale: i32) -> CalculationResult {
         let mut tmp64 = buffer.data[0] as u64 * power;
         buffer.data[0] = tmp64 as u32;
 
-        for (index, part) in buffer.data.iter_mut().enumerate().skip(1) {
-            if index > upper {
-                break;
-            }
+        let mut index = 1;
+        while index < buffer.data.len() && index <= upper {
             tmp64 >>= 32;
-            tmp64 = tmp64.wrapping_add((*part as u64).wrapping_mul(power));
-            *part = tmp64 as u32;
+            tmp64 = tmp64.wrapping_add((buffer.data[index] as u64).wrapping_mul(power));
+            buffer.data[index] = tmp64 as u32;
+            index += 1;
         }
         // If we have overflow then also process that
         if upper == 6 {
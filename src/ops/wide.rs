@@ -9,18 +9,29 @@
 //
 // DecWide keeps a 192-bit mantissa (~57.8 decimal digits) throughout the
 // computation, only truncating to 96 bits at the very end.
+//
+// The exact intermediates are also used to implement operations that must round
+// exactly once (e.g. arithmetic under a `Context`), see `round_exact`.
 
-use crate::Decimal;
-use crate::constants::POWERS_10;
+use crate::constants::{MAX_PRECISION_U32, MAX_SCALE_U32, POWERS_10};
+use crate::decimal::Discarded;
+use crate::ops::array::{div_by_u32, is_all_zero, mul_by_u32, sub_by_internal};
+use crate::{Decimal, RoundingStrategy};
 
 /// Maximum power of 10 that fits in a u32 (10^9 = 1,000,000,000).
 /// Duplicated here so `wide.rs` compiles regardless of `legacy-ops`.
 const MAX_I32_SCALE: i32 = 9;
 
+/// The largest scale of a `DecWide` produced by `round_wide`.
+pub(crate) const MAX_WIDE_SCALE: u32 = 57;
+
+/// The number of decimal digits that always fit within 192 bits.
+const MAX_WIDE_PRECISION: u32 = 57;
+
 /// Extended precision decimal with 192-bit mantissa.
 /// Used as an intermediate representation to avoid precision loss in
 /// iterative operations.
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub(crate) struct DecWide {
     /// 192-bit mantissa stored as 6 × 32-bit words (little-endian)
     data: [u32; 6],
@@ -35,7 +46,7 @@ struct Buf48 {
 
 impl DecWide {
     #[inline]
-    const fn is_zero(&self) -> bool {
+    pub const fn is_zero(&self) -> bool {
         let mut i = 0;
         while i < 6 {
             if self.data[i] != 0 {
@@ -46,6 +57,26 @@ impl DecWide {
         true
     }
 
+    #[inline]
+    pub const fn from_parts(data: [u32; 6], scale: u32, negative: bool) -> Self {
+        DecWide { data, scale, negative }
+    }
+
+    #[inline]
+    pub const fn data(&self) -> &[u32; 6] {
+        &self.data
+    }
+
+    #[inline]
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
     #[inline]
     pub const fn from_decimal(d: &Decimal) -> Self {
         let m = d.mantissa_array3();
@@ -56,7 +87,33 @@ impl DecWide {
         }
     }
 
-    pub fn to_decimal(&self) -> Option<Decimal> {
+    /// The exact sum of two decimals. Scales are aligned first so that a zero operand retains
+    /// its scale, mirroring `Decimal` addition.
+    pub fn from_sum(a: &Decimal, b: &Decimal) -> Option<DecWide> {
+        let (mut a, mut b) = (DecWide::from_decimal(a), DecWide::from_decimal(b));
+        align_scales(&mut a, &mut b)?;
+        a.checked_add(&b)
+    }
+
+    /// The exact product of two decimals.
+    pub fn from_product(a: &Decimal, b: &Decimal) -> Option<DecWide> {
+        if a.is_zero() || b.is_zero() {
+            return Some(DecWide {
+                data: [0; 6],
+                scale: a.scale() + b.scale(),
+                negative: a.is_sign_negative() ^ b.is_sign_negative(),
+            });
+        }
+        DecWide::from_decimal(a).checked_mul(&DecWide::from_decimal(b))
+    }
+
+    /// Rounds this value into a `Decimal` using a single rounding step.
+    pub fn round(&self, target: Target, strategy: RoundingStrategy) -> Option<Rounded> {
+        round_exact(&self.data, self.scale, self.negative, false, target, strategy)
+    }
+
+    #[cfg(feature = "maths")]
+    pub fn to_decimal(self) -> Option<Decimal> {
         let mut data = self.data;
         let mut scale = self.scale as i32;
         let mut upper = upper_word_6(&data);
@@ -163,10 +220,10 @@ impl DecWide {
     /// Add two DecWide values, keeping 192-bit precision.
     pub fn checked_add(&self, other: &DecWide) -> Option<DecWide> {
         if self.is_zero() {
-            return Some(other.clone());
+            return Some(*other);
         }
         if other.is_zero() {
-            return Some(self.clone());
+            return Some(*self);
         }
 
         if self.negative != other.negative {
@@ -175,7 +232,7 @@ impl DecWide {
         }
 
         // Same sign: align scales, then add mantissas
-        let (mut a, mut b) = (self.clone(), other.clone());
+        let (mut a, mut b) = (*self, *other);
         align_scales(&mut a, &mut b)?;
 
         let mut carry = 0u64;
@@ -223,7 +280,7 @@ impl DecWide {
     /// Core subtraction with explicit sign for `other`.
     fn checked_sub_impl(&self, other: &DecWide, other_negative: bool) -> Option<DecWide> {
         if other.is_zero() {
-            return Some(self.clone());
+            return Some(*self);
         }
         if self.is_zero() {
             return Some(DecWide {
@@ -235,13 +292,13 @@ impl DecWide {
 
         if self.negative != other_negative {
             // Different effective signs: a - (-b) = a + b
-            let mut b = other.clone();
+            let mut b = *other;
             b.negative = self.negative; // same sign as self
             return self.checked_add(&b);
         }
 
         // Same effective sign: align and subtract
-        let (mut a, mut b_val) = (self.clone(), other.clone());
+        let (mut a, mut b_val) = (*self, *other);
         b_val.negative = other_negative;
         align_scales(&mut a, &mut b_val)?;
 
@@ -273,12 +330,13 @@ impl DecWide {
     }
 
     /// Divide by a small u32 value (for Taylor series: divide by i).
+    #[cfg(feature = "maths")]
     pub fn checked_div_u32(&self, divisor: u32) -> Option<DecWide> {
         if divisor == 0 {
             return None;
         }
         if self.is_zero() || divisor == 1 {
-            return Some(self.clone());
+            return Some(*self);
         }
 
         let mut data = self.data;
@@ -343,6 +401,7 @@ impl DecWide {
 
     /// Check if this value's magnitude is less than or equal to 1e-28.
     /// Uses a fast path that avoids the expensive to_decimal() rescale in most cases.
+    #[cfg(feature = "maths")]
     #[inline]
     pub const fn magnitude_le_28(&self) -> bool {
         if self.is_zero() {
@@ -368,6 +427,7 @@ impl DecWide {
     }
 
     /// Negate in place
+    #[cfg(feature = "maths")]
     #[inline]
     pub fn negate(&mut self) {
         if !self.is_zero() {
@@ -375,13 +435,14 @@ impl DecWide {
         }
     }
 
+    #[cfg(feature = "maths")]
     pub const fn one() -> DecWide {
         DecWide::from_decimal(&Decimal::ONE)
     }
 }
 
 #[inline]
-fn add_one<const N: usize>(data: &mut [u32; N]) {
+pub(crate) fn add_one<const N: usize>(data: &mut [u32; N]) {
     let mut carry = 1u64;
     for word in data.iter_mut() {
         carry += *word as u64;
@@ -557,7 +618,7 @@ fn rescale_buf<const N: usize, const TARGET: usize>(
                 break;
             }
         }
-        if carry || data.get(TARGET + 1).map_or(false, |&w| w > 0) {
+        if carry || data.get(TARGET + 1).is_some_and(|&w| w > 0) {
             if *scale <= 0 {
                 return None;
             }
@@ -588,12 +649,466 @@ fn rescale_buf<const N: usize, const TARGET: usize>(
     Some(())
 }
 
+/// The constraint to satisfy when rounding an exact intermediate into a `Decimal`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Target {
+    /// Keep at most the given number of significant digits, using the largest scale possible.
+    Precision(u32),
+    /// Keep the given number of decimal places. Trailing zeros are added if the exact value has
+    /// fewer decimal places, for as long as the coefficient fits within 96 bits.
+    Scale(u32),
+    /// Like `Scale`, but drops further decimal places if the value cannot be held at the given
+    /// scale, i.e. keeps the largest scale up to the given one that fits within 96 bits.
+    MaxScale(u32),
+    /// Keep the digits down to the `10^exponent` position, i.e. round to a multiple of
+    /// `10^exponent`. Unlike `Scale`, trailing zeros are never added.
+    Exponent(i32),
+}
+
+/// A `Decimal` produced by `round_exact`, along with what happened to the exact value.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Rounded {
+    pub value: Decimal,
+    /// Digits were discarded from the exact value, even if they were all zero.
+    pub rounded: bool,
+    /// Non-zero digits were discarded from the exact value.
+    pub inexact: bool,
+    /// The coefficient was padded with zeros since `Decimal` cannot represent a negative scale.
+    pub clamped: bool,
+}
+
+/// Rounds the exact value `mantissa * 10^-scale` into a `Decimal` using a single rounding step.
+///
+/// `sticky` indicates that non-zero digits beyond the end of `mantissa` have already been
+/// discarded (e.g. a division remainder). When set, `scale` must exceed the target so that at
+/// least one digit is dropped here.
+///
+/// Returns `None` if the rounded value cannot be represented.
+pub(crate) fn round_exact<const N: usize>(
+    mantissa: &[u32; N],
+    scale: u32,
+    negative: bool,
+    sticky: bool,
+    target: Target,
+    strategy: RoundingStrategy,
+) -> Option<Rounded> {
+    let mut drop = match target {
+        Target::Precision(precision) => scale
+            .saturating_sub(MAX_SCALE_U32)
+            .max(count_digits(mantissa).saturating_sub(precision)),
+        Target::Scale(dp) => scale.saturating_sub(dp.min(MAX_SCALE_U32)),
+        // Any more than 29 digits can never fit
+        Target::MaxScale(dp) => scale
+            .saturating_sub(dp.min(MAX_SCALE_U32))
+            .max(count_digits(mantissa).saturating_sub(MAX_PRECISION_U32).min(scale)),
+        Target::Exponent(exponent) => {
+            // Anything beyond the 30th digit rounds identically, so there's no need to go further
+            let exponent = i64::from(exponent.min(30));
+            (i64::from(scale) + exponent)
+                .max(i64::from(scale.saturating_sub(MAX_SCALE_U32)))
+                .max(0) as u32
+        }
+    };
+    debug_assert!(drop > 0 || !sticky);
+
+    loop {
+        let mut data = *mantissa;
+        let discarded = discard_digits(&mut data, drop, sticky);
+        let mut quotient = data;
+        let last_digit = div_by_u32(&mut quotient, 10);
+        if strategy.rounds_away(negative, last_digit, discarded) {
+            add_one(&mut data);
+        }
+
+        let mut new_scale = i64::from(scale) - i64::from(drop);
+        let min_scale = match target {
+            Target::Precision(precision) => {
+                // Rounding up may have carried into an extra digit, e.g. 999 -> 1000
+                if count_digits(&data) > precision {
+                    div_by_u32(&mut data, 10);
+                    new_scale -= 1;
+                }
+                0
+            }
+            Target::Scale(_) | Target::MaxScale(_) | Target::Exponent(_) => 0,
+        };
+
+        let mut clamped = false;
+        if new_scale < min_scale {
+            if !mul_by_pow10(&mut data, (min_scale - new_scale) as u32) {
+                return None;
+            }
+            clamped = new_scale < 0 && !is_all_zero(&data);
+            new_scale = min_scale;
+        }
+
+        if data[3..].iter().all(|w| *w == 0) {
+            if let Target::Scale(dp) | Target::MaxScale(dp) = target {
+                pad_scale(&mut data, &mut new_scale, i64::from(dp.min(MAX_SCALE_U32)));
+            }
+            return Some(Rounded {
+                value: Decimal::from_parts(data[0], data[1], data[2], negative, new_scale as u32),
+                rounded: drop > 0,
+                inexact: discarded != Discarded::Zero,
+                clamped,
+            });
+        }
+
+        // Too many digits to fit within 96 bits. Drop another digit from the exact value (rather
+        // than the rounded one) so that we still only round once.
+        match target {
+            Target::Precision(_) | Target::MaxScale(_) if new_scale > 0 => drop += 1,
+            _ => return None,
+        }
+    }
+}
+
+/// Rounds the exact value `mantissa * 10^-scale` into a `DecWide` using a single rounding step,
+/// keeping at most `max_scale` decimal places and as many digits as fit within 192 bits.
+///
+/// `sticky` indicates that non-zero digits beyond the end of `mantissa` have already been
+/// discarded, in which case at least one more digit must be dropped here.
+///
+/// Returns `None` if the integral part of the value doesn't fit within 192 bits.
+pub(crate) fn round_wide<const N: usize>(
+    mantissa: &[u32; N],
+    scale: u32,
+    negative: bool,
+    sticky: bool,
+    max_scale: u32,
+    strategy: RoundingStrategy,
+) -> Option<DecWide> {
+    debug_assert!(N >= 6);
+    let limits = Limits {
+        max_scale: max_scale.min(MAX_WIDE_SCALE),
+        max_digits: MAX_WIDE_PRECISION + 1,
+        fits: |data: &[u32; N]| data[6..].iter().all(|w| *w == 0),
+    };
+    let (data, scale) = round_to_fit(mantissa, scale, negative, sticky, limits, strategy)?;
+    let mut result = [0u32; 6];
+    result.copy_from_slice(&data[..6]);
+    Some(DecWide {
+        data: result,
+        scale,
+        negative: negative && !is_all_zero(&result),
+    })
+}
+
+/// Describes the coefficients that a rounded value can hold.
+pub(crate) struct Limits<F> {
+    /// The largest scale that may be kept.
+    pub max_scale: u32,
+    /// The number of digits beyond which a coefficient can never fit.
+    pub max_digits: u32,
+    /// Whether a coefficient fits.
+    pub fits: F,
+}
+
+/// Rounds the exact value `mantissa * 10^-scale` using a single rounding step, dropping as few
+/// digits as possible for the coefficient to fit within `limits`. Returns the rounded coefficient
+/// and its scale.
+///
+/// `sticky` indicates that non-zero digits beyond the end of `mantissa` have already been
+/// discarded, in which case at least one more digit must be dropped here.
+///
+/// Returns `None` if the integral part of the value doesn't fit.
+pub(crate) fn round_to_fit<const N: usize>(
+    mantissa: &[u32; N],
+    scale: u32,
+    negative: bool,
+    sticky: bool,
+    limits: Limits<impl Fn(&[u32; N]) -> bool>,
+    strategy: RoundingStrategy,
+) -> Option<([u32; N], u32)> {
+    // Skip straight past any digits that could never be kept
+    let mut drop = scale
+        .saturating_sub(limits.max_scale)
+        .max(count_digits(mantissa).saturating_sub(limits.max_digits));
+    debug_assert!(drop > 0 || !sticky);
+
+    while drop <= scale {
+        let mut data = *mantissa;
+        let discarded = discard_digits(&mut data, drop, sticky);
+        let mut quotient = data;
+        let last_digit = div_by_u32(&mut quotient, 10);
+        if strategy.rounds_away(negative, last_digit, discarded) {
+            add_one(&mut data);
+        }
+
+        if (limits.fits)(&data) {
+            return Some((data, scale - drop));
+        }
+        // Drop another digit from the exact value (rather than the rounded one) so that we
+        // still only round once
+        drop += 1;
+    }
+    None
+}
+
+/// Divides `data` by a non-zero `divisor` of any width using binary long division, returning the
+/// remainder.
+pub(crate) fn div_rem_wide<const N: usize, const M: usize>(data: &mut [u32; N], divisor: &[u32; M]) -> [u32; M] {
+    debug_assert!(!is_all_zero(divisor));
+    let mut remainder = [0u32; M];
+    let mut quotient = [0u32; N];
+    let top_bit = match data.iter().rposition(|w| *w != 0) {
+        Some(word) => word * 32 + (31 - data[word].leading_zeros() as usize),
+        None => return remainder,
+    };
+    for bit in (0..=top_bit).rev() {
+        // Shift the next bit of the dividend into the remainder. The remainder is always less
+        // than the divisor, so the only bit that can be lost is the top one.
+        let mut carry = (data[bit / 32] >> (bit % 32)) & 1;
+        for word in remainder.iter_mut() {
+            let next = *word >> 31;
+            *word = (*word << 1) | carry;
+            carry = next;
+        }
+        if carry != 0 || !less_than(&remainder, divisor) {
+            let mut borrow = 0i64;
+            for (word, &d) in remainder.iter_mut().zip(divisor.iter()) {
+                let diff = i64::from(*word) - i64::from(d) - borrow;
+                *word = diff as u32;
+                borrow = i64::from(diff < 0);
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    *data = quotient;
+    remainder
+}
+
+fn less_than<const M: usize>(a: &[u32; M], b: &[u32; M]) -> bool {
+    for (x, y) in a.iter().zip(b.iter()).rev() {
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
+
+/// Divides `a` by `b`, truncating the quotient to at least `min_scale` decimal places. Returns the
+/// mantissa and scale of the quotient along with whether a non-zero remainder was discarded.
+///
+/// If the division is exact then trailing zeros are removed from the quotient, down to the
+/// natural scale of `a.scale() - b.scale()`.
+///
+/// `b` must be non-zero and `min_scale` must not exceed `Decimal::MAX_SCALE + 1`.
+pub(crate) fn div_truncated(a: &Decimal, b: &Decimal, min_scale: u32) -> ([u32; 10], u32, bool) {
+    debug_assert!(!b.is_zero() && min_scale <= MAX_SCALE_U32 + 1);
+    let (a_scale, b_scale) = (a.scale(), b.scale());
+    let shift = (b_scale + min_scale).saturating_sub(a_scale);
+
+    // The dividend is at most 96 bits scaled by 10^57, which comfortably fits within 320 bits.
+    let m = a.mantissa_array3();
+    let mut quotient = [m[0], m[1], m[2], 0, 0, 0, 0, 0, 0, 0];
+    mul_by_pow10(&mut quotient, shift);
+
+    let d = b.mantissa_array3();
+    let divisor = u128::from(d[0]) | (u128::from(d[1]) << 32) | (u128::from(d[2]) << 64);
+    let remainder = div_by_u96(&mut quotient, divisor);
+
+    let mut scale = a_scale + shift - b_scale;
+    if remainder != 0 {
+        return (quotient, scale, true);
+    }
+    let natural_scale = a_scale.saturating_sub(b_scale);
+    while scale > natural_scale {
+        let mut working = quotient;
+        if div_by_u32(&mut working, 10) != 0 {
+            break;
+        }
+        quotient = working;
+        scale -= 1;
+    }
+    (quotient, scale, false)
+}
+
+/// An exact running total of decimal terms. The scale of the total is fixed up front so that
+/// terms only ever need to be scaled up, and the total is held as a magnitude and sign.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ExactSum<const N: usize> {
+    data: [u32; N],
+    scale: u32,
+    negative: bool,
+}
+
+impl<const N: usize> ExactSum<N> {
+    /// A zero total with the given scale, which must be at least that of every term added.
+    pub const fn new(scale: u32) -> Self {
+        ExactSum {
+            data: [0; N],
+            scale,
+            negative: false,
+        }
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Adds the exact value `mantissa * 10^-scale` to the total, returning `None` if the total
+    /// no longer fits within `N` words.
+    pub fn add<const M: usize>(&mut self, mantissa: &[u32; M], scale: u32, negative: bool) -> Option<()> {
+        debug_assert!(M <= N && scale <= self.scale);
+        let mut term = [0u32; N];
+        term[..M].copy_from_slice(mantissa);
+        if !mul_by_pow10(&mut term, self.scale - scale) {
+            return None;
+        }
+
+        if is_all_zero(&self.data) {
+            self.data = term;
+            self.negative = negative;
+        } else if self.negative == negative {
+            let mut carry = 0u64;
+            for (word, &t) in self.data.iter_mut().zip(term.iter()) {
+                carry += u64::from(*word) + u64::from(t);
+                *word = carry as u32;
+                carry >>= 32;
+            }
+            if carry != 0 {
+                return None;
+            }
+        } else if less_than(&self.data, &term) {
+            sub_by_internal(&mut term, &self.data);
+            self.data = term;
+            self.negative = negative;
+        } else {
+            sub_by_internal(&mut self.data, &term);
+        }
+
+        // Cancelling terms leave a positive zero, mirroring `Decimal` addition
+        if is_all_zero(&self.data) {
+            self.negative = false;
+        }
+        Some(())
+    }
+
+    /// Rounds the total into a `Decimal` using a single rounding step.
+    pub fn round(&self, target: Target, strategy: RoundingStrategy) -> Option<Rounded> {
+        round_exact(&self.data, self.scale, self.negative, false, target, strategy)
+    }
+}
+
+/// Divides `data` by `10^digits`, describing the digits that were discarded. `sticky` indicates
+/// that non-zero digits were previously discarded beyond the end of `data`.
+pub(crate) fn discard_digits(data: &mut [u32], digits: u32, sticky: bool) -> Discarded {
+    if digits == 0 {
+        return Discarded::Zero;
+    }
+
+    // Drop everything but the most significant of the discarded digits, remembering whether
+    // anything non-zero was lost along the way.
+    let mut sticky = sticky;
+    let mut remaining = digits;
+    while remaining > 1 {
+        let step = (remaining - 1).min(MAX_I32_SCALE as u32);
+        sticky |= div_by_u32(data, POWERS_10[step as usize]) != 0;
+        remaining -= step;
+    }
+
+    match div_by_u32(data, 10) {
+        0 if !sticky => Discarded::Zero,
+        0..=4 => Discarded::BelowHalf,
+        5 if !sticky => Discarded::Half,
+        _ => Discarded::AboveHalf,
+    }
+}
+
+/// Appends trailing zeros to the 96 bit coefficient within `data` until `scale` reaches `target`,
+/// stopping early if the coefficient would no longer fit.
+fn pad_scale<const N: usize>(data: &mut [u32; N], scale: &mut i64, target: i64) {
+    while *scale < target {
+        let mut working = *data;
+        if mul_by_u32(&mut working, 10) != 0 || working[3..].iter().any(|w| *w != 0) {
+            break;
+        }
+        *data = working;
+        *scale += 1;
+    }
+}
+
+/// Divides `data` by a non-zero divisor of at most 96 bits, returning the remainder.
+pub(crate) fn div_by_u96<const N: usize>(data: &mut [u32; N], divisor: u128) -> u128 {
+    debug_assert!(divisor != 0 && divisor >> 96 == 0);
+    let mut remainder = 0u128;
+    for word in data.iter_mut().rev() {
+        // The remainder is always less than the 96 bit divisor so this can't overflow
+        let dividend = (remainder << 32) | u128::from(*word);
+        *word = (dividend / divisor) as u32;
+        remainder = dividend % divisor;
+    }
+    remainder
+}
+
+/// Multiplies `data` by a factor of at most 96 bits, returning `false` if the result overflows.
+pub(crate) fn mul_by_u96<const N: usize>(data: &mut [u32; N], factor: u128) -> bool {
+    debug_assert!(factor >> 96 == 0);
+    let factor = [factor as u32, (factor >> 32) as u32, (factor >> 64) as u32];
+    let mut product = [0u32; N];
+    for (i, &word) in data.iter().enumerate() {
+        if word == 0 {
+            continue;
+        }
+        let mut carry = 0u64;
+        for (j, &f) in factor.iter().enumerate() {
+            let Some(slot) = product.get_mut(i + j) else {
+                if f != 0 || carry != 0 {
+                    return false;
+                }
+                continue;
+            };
+            carry += u64::from(word) * u64::from(f) + u64::from(*slot);
+            *slot = carry as u32;
+            carry >>= 32;
+        }
+        let mut k = i + factor.len();
+        while carry != 0 {
+            let Some(slot) = product.get_mut(k) else {
+                return false;
+            };
+            carry += u64::from(*slot);
+            *slot = carry as u32;
+            carry >>= 32;
+            k += 1;
+        }
+    }
+    *data = product;
+    true
+}
+
+/// Multiplies `data` by `10^power`, returning `false` if the result overflows.
+pub(crate) fn mul_by_pow10<const N: usize>(data: &mut [u32; N], power: u32) -> bool {
+    let mut remaining = power;
+    while remaining > 0 {
+        let step = remaining.min(MAX_I32_SCALE as u32);
+        if mul_by_u32(data, POWERS_10[step as usize]) != 0 {
+            return false;
+        }
+        remaining -= step;
+    }
+    true
+}
+
+/// Counts the number of decimal digits in `data`, returning zero for a zero value.
+pub(crate) fn count_digits<const N: usize>(data: &[u32; N]) -> u32 {
+    let mut working = *data;
+    let mut digits = 0;
+    while working[1..].iter().any(|w| *w != 0) {
+        div_by_u32(&mut working, POWERS_10[MAX_I32_SCALE as usize]);
+        digits += MAX_I32_SCALE as u32;
+    }
+    digits + working[0].checked_ilog10().map_or(0, |d| d + 1)
+}
+
 /// Exponentiation by squaring using adaptive precision.
 ///
 /// For small exponents (fewer than 10 squarings, i.e. exp < 1024), uses
 /// standard 96-bit Decimal arithmetic - fast and sufficient precision (~18+
 /// correct digits). For large exponents, uses 192-bit DecWide intermediates
 /// to prevent precision loss from compounding over many squarings.
+#[cfg(feature = "maths")]
 pub(crate) fn powu_wide(base: &Decimal, exp: u64) -> Option<Decimal> {
     if exp == 0 {
         return Some(Decimal::ONE);
@@ -623,6 +1138,7 @@ pub(crate) fn powu_wide(base: &Decimal, exp: u64) -> Option<Decimal> {
 }
 
 /// Fast path: exponentiation by squaring using 96-bit Decimal.
+#[cfg(feature = "maths")]
 fn powu_narrow(base: &Decimal, exp: u64) -> Option<Decimal> {
     let mut product = Decimal::ONE;
     let mut mask = exp;
@@ -643,6 +1159,7 @@ fn powu_narrow(base: &Decimal, exp: u64) -> Option<Decimal> {
 }
 
 /// Precise path: exponentiation by squaring using 192-bit DecWide.
+#[cfg(feature = "maths")]
 fn powu_192(base: &Decimal, exp: u64) -> Option<Decimal> {
     let mut product = DecWide::from_decimal(&Decimal::ONE);
     let mut mask = exp;
@@ -668,6 +1185,7 @@ fn powu_192(base: &Decimal, exp: u64) -> Option<Decimal> {
 /// Uses argument reduction: exp(x) = exp(n) * exp(r) where n = floor(x), r = x - n.
 /// - exp(n) = e^n via powu squaring in DecWide
 /// - exp(r) via Taylor series entirely in DecWide
+#[cfg(feature = "maths")]
 pub(crate) fn exp_wide(value: &Decimal) -> Option<Decimal> {
     if value.is_zero() {
         return Some(Decimal::ONE);
@@ -689,7 +1207,7 @@ pub(crate) fn exp_wide(value: &Decimal) -> Option<Decimal> {
     } else {
         let one_wide = DecWide::from_decimal(&Decimal::ONE);
         let mut result = one_wide.checked_add(&r_wide)?;
-        let mut term = r_wide.clone();
+        let mut term = r_wide;
 
         for i in 2..100u32 {
             term = r_wide.checked_mul(&term.checked_div_u32(i)?)?;
@@ -745,6 +1263,7 @@ pub(crate) fn exp_wide(value: &Decimal) -> Option<Decimal> {
 /// Uses range reduction (multiply/divide by e), then the atanh series:
 /// ln(x) = 2 * atanh((x-1)/(x+1)) where atanh(z) = z + z³/3 + z⁵/5 + ...
 /// This converges much faster than the standard ln(1+t) series.
+#[cfg(feature = "maths")]
 pub(crate) fn ln_wide(value: &Decimal) -> Option<Decimal> {
     if value.is_sign_negative() || value.is_zero() {
         return None;
@@ -784,7 +1303,7 @@ pub(crate) fn ln_wide(value: &Decimal) -> Option<Decimal> {
     let z2 = z.checked_mul(&z)?;
 
     // atanh(z) = z + z³/3 + z⁵/5 + z⁷/7 + ...
-    let mut result = z.clone();
+    let mut result = z;
     let mut term = z;
 
     for n in 1..100u32 {
@@ -810,6 +1329,7 @@ pub(crate) fn ln_wide(value: &Decimal) -> Option<Decimal> {
 }
 
 /// Compute sin(x) using 192-bit intermediate precision.
+#[cfg(feature = "maths")]
 pub(crate) fn sin_wide(value: &Decimal) -> Option<Decimal> {
     if value.is_zero() {
         return Some(Decimal::ZERO);
@@ -833,7 +1353,7 @@ pub(crate) fn sin_wide(value: &Decimal) -> Option<Decimal> {
 
     // sin(x) = x - x³/3! + x⁵/5! - ...
     // term_{n+1} = -term_n * x² / ((2n+2)(2n+3))
-    let mut result = x_wide.clone();
+    let mut result = x_wide;
     let mut term = x_wide;
 
     for n in 0..50u32 {
@@ -853,6 +1373,7 @@ pub(crate) fn sin_wide(value: &Decimal) -> Option<Decimal> {
 }
 
 /// Compute cos(x) using 192-bit intermediate precision.
+#[cfg(feature = "maths")]
 pub(crate) fn cos_wide(value: &Decimal) -> Option<Decimal> {
     if value.is_zero() {
         return Some(Decimal::ONE);
@@ -0,0 +1,171 @@
+use crate::RoundingStrategy;
+
+/// Options describing the syntax accepted by [`Decimal::parse_with`](crate::Decimal::parse_with).
+///
+/// The default options accept the same syntax as `FromStr`: a `.` decimal separator, an
+/// optional leading `+` or `-`, and `_` separators anywhere after the first digit. Values with
+/// more digits than can be represented are rounded at the midpoint away from zero.
+///
+/// Separators must be ASCII characters. Grouping separators are skipped wherever they appear
+/// after the first digit, the size of each group is not validated.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Decimal, ParseOptions};
+/// # use rust_decimal_macros::dec;
+/// #
+/// let english = ParseOptions::new().with_grouping_separator(',');
+/// assert_eq!(Decimal::parse_with("1,234.56", &english), Ok(dec!(1234.56)));
+///
+/// let german = ParseOptions::new().with_decimal_separator(',').with_grouping_separator('.');
+/// assert_eq!(Decimal::parse_with("1.234,56", &german), Ok(dec!(1234.56)));
+///
+/// let ledger = ParseOptions::new().with_trailing_minus(true).with_trim_whitespace(true);
+/// assert_eq!(Decimal::parse_with(" 1234.56- ", &ledger), Ok(dec!(-1234.56)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) decimal_separator: u8,
+    // A bit set of the ASCII grouping separators
+    pub(crate) grouping_separators: u128,
+    pub(crate) exponent: bool,
+    pub(crate) plus_sign: bool,
+    pub(crate) underscores: bool,
+    pub(crate) trailing_minus: bool,
+    pub(crate) trim_whitespace: bool,
+    pub(crate) rounding: Option<RoundingStrategy>,
+}
+
+impl Default for ParseOptions {
+    /// Options accepting the same syntax as `FromStr`.
+    fn default() -> ParseOptions {
+        ParseOptions::new()
+    }
+}
+
+impl ParseOptions {
+    /// Creates options accepting the same syntax as `FromStr`.
+    #[must_use]
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            decimal_separator: b'.',
+            grouping_separators: 0,
+            exponent: false,
+            plus_sign: true,
+            underscores: true,
+            trailing_minus: false,
+            trim_whitespace: false,
+            rounding: Some(RoundingStrategy::MidpointAwayFromZero),
+        }
+    }
+
+    /// Returns a copy of these options using the given decimal separator. If the separator is
+    /// also a grouping separator then it is treated as the decimal separator.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the separator is not an ASCII character, or is a digit, a sign or
+    /// an exponent marker.
+    #[must_use]
+    pub const fn with_decimal_separator(mut self, separator: char) -> ParseOptions {
+        self.decimal_separator = separator_byte(separator);
+        self
+    }
+
+    /// Returns a copy of these options that also accepts the given grouping separator, e.g. `,`
+    /// in `1,234.56`. This can be called multiple times to accept several separators.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the separator is not an ASCII character, or is a digit, a sign or
+    /// an exponent marker.
+    #[must_use]
+    pub const fn with_grouping_separator(mut self, separator: char) -> ParseOptions {
+        self.grouping_separators |= 1 << separator_byte(separator);
+        self
+    }
+
+    /// Returns a copy of these options that accepts an exponent such as `1.5e-3`.
+    #[must_use]
+    pub const fn with_exponent(mut self, allow: bool) -> ParseOptions {
+        self.exponent = allow;
+        self
+    }
+
+    /// Returns a copy of these options that accepts or rejects a leading `+`.
+    #[must_use]
+    pub const fn with_plus_sign(mut self, allow: bool) -> ParseOptions {
+        self.plus_sign = allow;
+        self
+    }
+
+    /// Returns a copy of these options that accepts or rejects `_` separators.
+    #[must_use]
+    pub const fn with_underscores(mut self, allow: bool) -> ParseOptions {
+        self.underscores = allow;
+        self
+    }
+
+    /// Returns a copy of these options that accepts a trailing `-` in place of a leading sign,
+    /// e.g. `1234.56-`.
+    #[must_use]
+    pub const fn with_trailing_minus(mut self, allow: bool) -> ParseOptions {
+        self.trailing_minus = allow;
+        self
+    }
+
+    /// Returns a copy of these options that ignores leading and trailing whitespace.
+    #[must_use]
+    pub const fn with_trim_whitespace(mut self, trim: bool) -> ParseOptions {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    /// Returns a copy of these options that rounds values with more digits than can be
+    /// represented using the given strategy, or returns [`Error::Underflow`](crate::Error::Underflow)
+    /// for such values when `None`.
+    #[must_use]
+    pub const fn with_rounding(mut self, rounding: Option<RoundingStrategy>) -> ParseOptions {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Whether these options accept exactly the `FromStr` syntax, in which case the `FromStr`
+    /// parser can be used directly.
+    pub(crate) const fn matches_from_str(&self) -> bool {
+        self.decimal_separator == b'.'
+            && self.grouping_separators == 0
+            && self.plus_sign
+            && self.underscores
+            && !self.exponent
+            && !self.trailing_minus
+    }
+
+    /// Whether `bytes` only uses the parts of the `FromStr` syntax that these options accept as
+    /// well, in which case the `FromStr` parser reads it the same way.
+    pub(crate) fn reads_like_from_str(&self, bytes: &[u8]) -> bool {
+        if self.decimal_separator != b'.' {
+            return false;
+        }
+        let rest = match bytes {
+            [b'-', rest @ ..] => rest,
+            [b'+', rest @ ..] if self.plus_sign => rest,
+            bytes => bytes,
+        };
+        rest.iter()
+            .all(|&b| b.is_ascii_digit() || b == b'.' || (b == b'_' && self.underscores))
+    }
+
+    pub(crate) const fn is_grouping_separator(&self, byte: u8) -> bool {
+        byte < 128 && self.grouping_separators & (1 << byte) != 0
+    }
+}
+
+const fn separator_byte(separator: char) -> u8 {
+    match separator {
+        '0'..='9' | '+' | '-' | 'e' | 'E' => panic!("Separator must not be a digit, sign or exponent marker"),
+        _ if separator.is_ascii() => separator as u8,
+        _ => panic!("Separator must be an ASCII character"),
+    }
+}
@@ -22,6 +22,25 @@ impl fmt::Display for InvalidDecimal {
 
 impl error::Error for InvalidDecimal {}
 
+// These are from numeric.c in the PostgreSQL source code
+pub(in crate::postgres) const NUMERIC_NAN: u16 = 0xC000;
+pub(in crate::postgres) const NUMERIC_PINF: u16 = 0xD000;
+pub(in crate::postgres) const NUMERIC_NINF: u16 = 0xF000;
+pub(in crate::postgres) const NUMERIC_SPECIAL: u16 = 0xC000;
+
+/// Returns the sign field of a binary `NUMERIC` if it denotes one of the special values.
+pub(in crate::postgres) fn special_sign(raw: &[u8]) -> Option<u16> {
+    // The sign follows the 16 bit group count and weight
+    let sign = u16::from_be_bytes(raw.get(4..6)?.try_into().ok()?);
+    (sign & NUMERIC_SPECIAL == NUMERIC_SPECIAL).then_some(sign)
+}
+
+/// Returns the binary `NUMERIC` representation of a special value, which has no digit groups.
+pub(in crate::postgres) fn special_header(sign: u16) -> [u8; 8] {
+    let [hi, lo] = sign.to_be_bytes();
+    [0, 0, 0, 0, hi, lo, 0, 0]
+}
+
 pub(in crate::postgres) struct PostgresDecimal<D> {
     pub neg: bool,
     pub weight: i16,
@@ -1,13 +1,14 @@
-use crate::Decimal;
 use crate::postgres::common::*;
+use crate::{Decimal, ExtDecimal, FixedDecimal};
 use diesel::{
     deserialize::{self, FromSql},
     pg::Pg,
     pg::data_types::PgNumeric,
-    serialize::{self, Output, ToSql},
+    serialize::{self, IsNull, Output, ToSql},
     sql_types::Numeric,
 };
 use std::error;
+use std::io::Write;
 
 impl<'a> TryFrom<&'a PgNumeric> for Decimal {
     type Error = Box<dyn error::Error + Send + Sync>;
@@ -85,6 +86,51 @@ impl FromSql<Numeric, Pg> for Decimal {
     }
 }
 
+#[cfg(feature = "diesel")]
+impl<const SCALE: u32> ToSql<Numeric, Pg> for FixedDecimal<SCALE> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let numeric = PgNumeric::from(Decimal::from(*self));
+        ToSql::<Numeric, Pg>::to_sql(&numeric, &mut out.reborrow())
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl<const SCALE: u32> FromSql<Numeric, Pg> for FixedDecimal<SCALE> {
+    fn from_sql(numeric: diesel::pg::PgValue) -> deserialize::Result<Self> {
+        let value = <Decimal as FromSql<Numeric, Pg>>::from_sql(numeric)?;
+        Ok(FixedDecimal::try_from(value)?)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl ToSql<Numeric, Pg> for ExtDecimal {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let sign = match self {
+            ExtDecimal::Finite(value) => return ToSql::<Numeric, Pg>::to_sql(value, out),
+            ExtDecimal::NaN => NUMERIC_NAN,
+            ExtDecimal::PosInf => NUMERIC_PINF,
+            ExtDecimal::NegInf => NUMERIC_NINF,
+        };
+        // PgNumeric has no representation of infinity, so the header is written directly
+        out.write_all(&special_header(sign))?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel")]
+impl FromSql<Numeric, Pg> for ExtDecimal {
+    fn from_sql(numeric: diesel::pg::PgValue) -> deserialize::Result<Self> {
+        match special_sign(numeric.as_bytes()) {
+            Some(NUMERIC_NAN) => Ok(ExtDecimal::NaN),
+            Some(NUMERIC_PINF) => Ok(ExtDecimal::PosInf),
+            Some(NUMERIC_NINF) => Ok(ExtDecimal::NegInf),
+            _ => Ok(ExtDecimal::Finite(<Decimal as FromSql<Numeric, Pg>>::from_sql(
+                numeric,
+            )?)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
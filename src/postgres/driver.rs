@@ -1,16 +1,10 @@
-use crate::Decimal;
 use crate::error::Error;
 use crate::postgres::common::*;
+use crate::{Decimal, ExtDecimal, FixedDecimal};
 use bytes::{BufMut, BytesMut};
 use postgres_types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
 use std::io::{Cursor, Read};
 
-// These are from numeric.c in the PostgreSQL source code
-const NUMERIC_NAN: u16 = 0xC000;
-const NUMERIC_PINF: u16 = 0xD000;
-const NUMERIC_NINF: u16 = 0xF000;
-const NUMERIC_SPECIAL: u16 = 0xC000;
-
 fn read_two_bytes(cursor: &mut Cursor<&[u8]>) -> std::io::Result<[u8; 2]> {
     let mut result = [0; 2];
     cursor.read_exact(&mut result)?;
@@ -157,6 +151,73 @@ impl ToSql for Decimal {
     to_sql_checked!();
 }
 
+impl<'a, const SCALE: u32> FromSql<'a> for FixedDecimal<SCALE> {
+    fn from_sql(
+        ty: &Type,
+        raw: &[u8],
+    ) -> Result<FixedDecimal<SCALE>, Box<dyn std::error::Error + 'static + Sync + Send>> {
+        Ok(FixedDecimal::try_from(Decimal::from_sql(ty, raw)?)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::NUMERIC)
+    }
+}
+
+impl<const SCALE: u32> ToSql for FixedDecimal<SCALE> {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + 'static + Sync + Send>> {
+        Decimal::from(*self).to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::NUMERIC)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for ExtDecimal {
+    fn from_sql(ty: &Type, raw: &[u8]) -> Result<ExtDecimal, Box<dyn std::error::Error + 'static + Sync + Send>> {
+        match special_sign(raw) {
+            Some(NUMERIC_NAN) => Ok(ExtDecimal::NaN),
+            Some(NUMERIC_PINF) => Ok(ExtDecimal::PosInf),
+            Some(NUMERIC_NINF) => Ok(ExtDecimal::NegInf),
+            _ => Ok(ExtDecimal::Finite(Decimal::from_sql(ty, raw)?)),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::NUMERIC)
+    }
+}
+
+impl ToSql for ExtDecimal {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + 'static + Sync + Send>> {
+        let sign = match self {
+            ExtDecimal::Finite(value) => return value.to_sql(ty, out),
+            ExtDecimal::NaN => NUMERIC_NAN,
+            ExtDecimal::PosInf => NUMERIC_PINF,
+            ExtDecimal::NegInf => NUMERIC_NINF,
+        };
+        out.extend_from_slice(&special_header(sign));
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::NUMERIC)
+    }
+
+    to_sql_checked!();
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -465,4 +526,53 @@ mod test {
             crate::error::Error::ExceedsMaximumPossibleValue.to_string()
         );
     }
+
+    #[test]
+    fn special_values_round_trip() {
+        let tests = [
+            (ExtDecimal::NaN, [0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00]),
+            (ExtDecimal::PosInf, [0x00, 0x00, 0x00, 0x00, 0xD0, 0x00, 0x00, 0x00]),
+            (ExtDecimal::NegInf, [0x00, 0x00, 0x00, 0x00, 0xF0, 0x00, 0x00, 0x00]),
+        ];
+        for (value, raw) in tests {
+            let mut out = BytesMut::new();
+            value.to_sql(&Type::NUMERIC, &mut out).unwrap();
+            assert_eq!(&out[..], &raw[..], "{value}");
+            let result = ExtDecimal::from_sql(&Type::NUMERIC, &raw).unwrap();
+            assert_eq!(result.to_string(), value.to_string());
+        }
+
+        let value = ExtDecimal::from(Decimal::from_str("-3950.123456").unwrap());
+        let mut out = BytesMut::new();
+        value.to_sql(&Type::NUMERIC, &mut out).unwrap();
+        assert_eq!(ExtDecimal::from_sql(&Type::NUMERIC, &out).unwrap(), value);
+
+        let error = Decimal::from_sql(&Type::NUMERIC, &tests[1].1).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            crate::error::Error::ConversionTo("Infinity").to_string()
+        );
+    }
+
+    #[test]
+    fn read_special_numeric_type() {
+        let mut client = match Client::connect(&get_postgres_url(), NoTls) {
+            Ok(x) => x,
+            Err(err) => panic!("{:#?}", err),
+        };
+        for sent in ["NaN", "Infinity", "-Infinity", "-1.5"] {
+            let result: ExtDecimal = match client.query(&*format!("SELECT '{}'::NUMERIC", sent), &[]) {
+                Ok(x) => x.first().unwrap().get(0),
+                Err(err) => panic!("error - {:#?}", err),
+            };
+            assert_eq!(sent, result.to_string());
+
+            let value = ExtDecimal::from_str(sent).unwrap();
+            let result: String = match client.query("SELECT $1::NUMERIC::TEXT", &[&value]) {
+                Ok(x) => x.first().unwrap().get(0),
+                Err(err) => panic!("error - {:#?}", err),
+            };
+            assert_eq!(sent, result);
+        }
+    }
 }
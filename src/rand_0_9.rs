@@ -1,4 +1,4 @@
-use crate::Decimal;
+use crate::{Decimal, StochasticRounding};
 use rand_0_9::{
     Rng,
     distr::{
@@ -22,6 +22,15 @@ impl Distribution<Decimal> for StandardUniform {
     }
 }
 
+impl Distribution<Decimal> for StochasticRounding {
+    fn sample<R>(&self, rng: &mut R) -> Decimal
+    where
+        R: Rng + ?Sized,
+    {
+        self.sample_with(|denominator| rng.random_range(0..denominator))
+    }
+}
+
 impl SampleUniform for Decimal {
     type Sampler = DecimalSampler;
 }
@@ -0,0 +1,449 @@
+use crate::constants::MAX_SCALE_U32;
+use crate::decimal::out_of_range;
+use crate::decimal192::impl_binop;
+use crate::ops::array::is_all_zero;
+use crate::ops::wide::{Target, div_rem_wide, round_exact};
+use crate::{Decimal, Error, RoundingStrategy};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_traits::{One, Zero};
+
+/// `DecimalRatio` is an exact rational number, held as a numerator and a positive denominator
+/// reduced to lowest terms.
+///
+/// It allows values such as `1/3` or `7/12` to be carried through a calculation without loss,
+/// rounding only once at the end with [`Self::to_decimal`]. Every [`Decimal`] can be represented
+/// exactly, via [`Decimal::to_ratio`].
+///
+/// Arithmetic is exact. The checked operations return `None`, and the operators panic, if the
+/// numerator or denominator of the result would exceed the range of an `i128`.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Decimal, DecimalRatio, RoundingStrategy};
+/// #
+/// let third = DecimalRatio::new(1, 3);
+/// let total = third + third + third;
+/// assert_eq!(total, DecimalRatio::ONE);
+///
+/// let share = Decimal::from(100).to_ratio() * DecimalRatio::new(7, 12);
+/// assert_eq!(share.to_string(), "175/3");
+/// assert_eq!(share.to_decimal(2, RoundingStrategy::MidpointNearestEven), Some(Decimal::new(5833, 2)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalRatio {
+    numer: i128,
+    denom: i128,
+}
+
+impl DecimalRatio {
+    /// A constant representing 0.
+    pub const ZERO: DecimalRatio = DecimalRatio { numer: 0, denom: 1 };
+    /// A constant representing 1.
+    pub const ONE: DecimalRatio = DecimalRatio { numer: 1, denom: 1 };
+
+    /// Creates the ratio `numer / denom`, reduced to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `denom` is zero, or the reduced ratio cannot be represented.
+    #[must_use]
+    pub fn new(numer: i128, denom: i128) -> DecimalRatio {
+        match Self::try_new(numer, denom) {
+            Err(e) => panic!("{e}"),
+            Ok(ratio) => ratio,
+        }
+    }
+
+    /// Checked version of [`Self::new`]. Will return an error instead of panicking at run-time.
+    pub fn try_new(numer: i128, denom: i128) -> crate::Result<DecimalRatio> {
+        if denom == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        let negative = (numer < 0) != (denom < 0);
+        DecimalRatio::from_parts(numer.unsigned_abs(), denom.unsigned_abs(), negative).ok_or(out_of_range(negative))
+    }
+
+    /// Reduces the magnitudes `numer / denom` to lowest terms, returning `None` if either no
+    /// longer fits within an `i128`. `denom` must be non-zero.
+    fn from_parts(numer: u128, denom: u128, negative: bool) -> Option<DecimalRatio> {
+        let divisor = gcd(numer, denom);
+        let (numer, denom) = (numer / divisor, denom / divisor);
+        let numer = i128::try_from(numer).ok()?;
+        Some(DecimalRatio {
+            numer: if negative { -numer } else { numer },
+            denom: i128::try_from(denom).ok()?,
+        })
+    }
+
+    /// Returns the numerator, which carries the sign of the ratio.
+    #[inline]
+    #[must_use]
+    pub const fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    /// Returns the denominator, which is always positive.
+    #[inline]
+    #[must_use]
+    pub const fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    /// Returns `true` if the ratio is equivalent to zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    /// Returns `true` if the ratio is negative.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(&self) -> bool {
+        self.numer < 0
+    }
+
+    /// Returns `true` if the ratio is a whole number.
+    #[inline]
+    #[must_use]
+    pub const fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    /// Computes the absolute value of `self`.
+    #[must_use]
+    pub const fn abs(&self) -> DecimalRatio {
+        DecimalRatio {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
+    }
+
+    /// Returns the reciprocal `1 / self`, or `None` if `self` is zero.
+    #[must_use]
+    pub fn recip(&self) -> Option<DecimalRatio> {
+        match self.numer.cmp(&0) {
+            Ordering::Equal => None,
+            Ordering::Greater => Some(DecimalRatio {
+                numer: self.denom,
+                denom: self.numer,
+            }),
+            Ordering::Less => Some(DecimalRatio {
+                numer: -self.denom,
+                denom: -self.numer,
+            }),
+        }
+    }
+
+    /// Rounds the ratio to `dp` decimal places using the given strategy, rounding only once.
+    /// Returns `None` if the result exceeds the range of a `Decimal`.
+    ///
+    /// The result has exactly `dp` decimal places, with `dp` limited to [`Decimal::MAX_SCALE`],
+    /// unless the value cannot be held at that scale, in which case it is rounded to the largest
+    /// scale that fits instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rust_decimal::{Decimal, DecimalRatio, RoundingStrategy};
+    /// # use core::str::FromStr;
+    /// #
+    /// let two_thirds = DecimalRatio::new(-2, 3);
+    /// assert_eq!(
+    ///     two_thirds.to_decimal(4, RoundingStrategy::ToZero),
+    ///     Some(Decimal::from_str("-0.6666").unwrap())
+    /// );
+    /// assert_eq!(DecimalRatio::new(1, 2).to_decimal(2, RoundingStrategy::ToZero), Some(Decimal::new(50, 2)));
+    /// ```
+    #[must_use]
+    pub fn to_decimal(&self, dp: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+        let dp = dp.min(MAX_SCALE_U32);
+        // Truncating to one more place than needed leaves a digit to round away whenever the
+        // remainder is non-zero. The scaled numerator is below 2^127 * 10^29 < 2^224.
+        let mut quotient = mul_wide(self.numer.unsigned_abs(), 10u128.pow(dp + 1));
+        let remainder = div_rem_wide(&mut quotient, &words(self.denom as u128));
+        let sticky = !is_all_zero(&remainder);
+        let rounded = round_exact(
+            &quotient,
+            dp + 1,
+            self.is_sign_negative(),
+            sticky,
+            Target::MaxScale(dp),
+            strategy,
+        )?;
+        Some(rounded.value)
+    }
+
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_add(self, other: DecimalRatio) -> Option<DecimalRatio> {
+        add_impl(&self, &other)
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_sub(self, other: DecimalRatio) -> Option<DecimalRatio> {
+        add_impl(&self, &-other)
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul(self, other: DecimalRatio) -> Option<DecimalRatio> {
+        mul_impl(&self, &other)
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other` is zero or overflow
+    /// occurred.
+    #[inline]
+    #[must_use]
+    pub fn checked_div(self, other: DecimalRatio) -> Option<DecimalRatio> {
+        div_impl(&self, &other)
+    }
+}
+
+impl Default for DecimalRatio {
+    /// Returns the default value for a `DecimalRatio` (equivalent to `DecimalRatio::ZERO`).
+    fn default() -> Self {
+        DecimalRatio::ZERO
+    }
+}
+
+impl Zero for DecimalRatio {
+    fn zero() -> DecimalRatio {
+        DecimalRatio::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        DecimalRatio::is_zero(self)
+    }
+}
+
+impl One for DecimalRatio {
+    fn one() -> DecimalRatio {
+        DecimalRatio::ONE
+    }
+}
+
+impl From<Decimal> for DecimalRatio {
+    /// Converts a `Decimal` into its exact ratio, reduced to lowest terms.
+    #[inline]
+    fn from(value: Decimal) -> DecimalRatio {
+        value.to_ratio()
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for DecimalRatio {
+                #[inline]
+                fn from(value: $t) -> DecimalRatio {
+                    DecimalRatio { numer: i128::from(value), denom: 1 }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl fmt::Display for DecimalRatio {
+    /// Formats the ratio as `numer/denom`, or just the numerator for whole numbers.
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl PartialOrd for DecimalRatio {
+    #[inline]
+    fn partial_cmp(&self, other: &DecimalRatio) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DecimalRatio {
+    fn cmp(&self, other: &DecimalRatio) -> Ordering {
+        let sign = self.numer.signum().cmp(&other.numer.signum());
+        if sign != Ordering::Equal || self.numer == 0 {
+            return sign;
+        }
+        // Cross multiplying the magnitudes needs up to 254 bits
+        let a = mul_wide(self.numer.unsigned_abs(), other.denom as u128);
+        let b = mul_wide(other.numer.unsigned_abs(), self.denom as u128);
+        let magnitude = cmp_wide(&a, &b);
+        if self.numer < 0 { magnitude.reverse() } else { magnitude }
+    }
+}
+
+impl Neg for DecimalRatio {
+    type Output = DecimalRatio;
+
+    fn neg(self) -> DecimalRatio {
+        -&self
+    }
+}
+
+impl Neg for &DecimalRatio {
+    type Output = DecimalRatio;
+
+    fn neg(self) -> DecimalRatio {
+        // The numerator is never i128::MIN, so this can't overflow
+        DecimalRatio {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl_binop!(impl Add for DecimalRatio, add, AddAssign, add_assign, add_impl, "Addition overflowed");
+impl_binop!(impl Sub for DecimalRatio, sub, SubAssign, sub_assign, sub_impl, "Subtraction overflowed");
+impl_binop!(impl Mul for DecimalRatio, mul, MulAssign, mul_assign, mul_impl, "Multiplication overflowed");
+impl_binop!(impl Div for DecimalRatio, div, DivAssign, div_assign, div_impl, "Division by zero" | "Division overflowed");
+
+fn add_impl(a: &DecimalRatio, b: &DecimalRatio) -> Option<DecimalRatio> {
+    // Scaling by the smallest common denominator keeps intermediate values as small as possible
+    let divisor = gcd(a.denom as u128, b.denom as u128) as i128;
+    let (a_factor, b_factor) = (b.denom / divisor, a.denom / divisor);
+    let numer = a
+        .numer
+        .checked_mul(a_factor)?
+        .checked_add(b.numer.checked_mul(b_factor)?)?;
+    let denom = a.denom.checked_mul(a_factor)?;
+    DecimalRatio::from_parts(numer.unsigned_abs(), denom as u128, numer < 0)
+}
+
+fn sub_impl(a: &DecimalRatio, b: &DecimalRatio) -> Option<DecimalRatio> {
+    add_impl(a, &-b)
+}
+
+fn mul_impl(a: &DecimalRatio, b: &DecimalRatio) -> Option<DecimalRatio> {
+    // Cancelling common factors first means the products are already in lowest terms
+    let (a_numer, b_numer) = (a.numer.unsigned_abs(), b.numer.unsigned_abs());
+    let (a_denom, b_denom) = (a.denom as u128, b.denom as u128);
+    let g1 = gcd(a_numer, b_denom);
+    let g2 = gcd(b_numer, a_denom);
+    let numer = (a_numer / g1).checked_mul(b_numer / g2)?;
+    let denom = (a_denom / g2).checked_mul(b_denom / g1)?;
+    DecimalRatio::from_parts(numer, denom, a.is_sign_negative() != b.is_sign_negative())
+}
+
+fn div_impl(a: &DecimalRatio, b: &DecimalRatio) -> Option<DecimalRatio> {
+    mul_impl(a, &b.recip()?)
+}
+
+/// Returns the exact ratio of a `Decimal`, reduced to lowest terms.
+pub(crate) fn to_ratio(value: &Decimal) -> DecimalRatio {
+    // A 96 bit mantissa over at most 10^28 always fits
+    let denom = 10u128.pow(value.scale());
+    DecimalRatio::from_parts(value.mantissa().unsigned_abs(), denom, value.is_sign_negative())
+        .expect("Decimal ratios always fit")
+}
+
+/// Finds the closest ratio to `value` whose denominator doesn't exceed `max_denominator`, using
+/// the convergents and semiconvergents of its continued fraction expansion.
+pub(crate) fn approximate_ratio(value: &Decimal, max_denominator: i128) -> DecimalRatio {
+    let exact = to_ratio(value);
+    let max_denominator = max_denominator.max(1) as u128;
+    let (n, d) = (exact.numer.unsigned_abs(), exact.denom as u128);
+    if d <= max_denominator {
+        return exact;
+    }
+
+    // Walk the convergents p1/q1 until the next denominator would be too large
+    let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+    let (mut num, mut den) = (n, d);
+    loop {
+        let a = num / den;
+        let q2 = q0 + a * q1;
+        if q2 > max_denominator {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+        (num, den) = (den, num - a * den);
+    }
+
+    // The best semiconvergent, which may be closer than the last convergent
+    let k = (max_denominator - q0) / q1;
+    let (p2, q2) = (p0 + k * p1, q0 + k * q1);
+
+    // Compare |p1/q1 - n/d| with |p2/q2 - n/d| by cross multiplying. Both distances are less than
+    // d, so only the final products need to be wide.
+    let distance = |p: u128, q: u128| {
+        let a = mul_wide(p, d);
+        let b = mul_wide(n, q);
+        let diff = if cmp_wide(&a, &b) == Ordering::Less {
+            sub_wide(&b, &a)
+        } else {
+            sub_wide(&a, &b)
+        };
+        u128::from(diff[0]) | u128::from(diff[1]) << 32 | u128::from(diff[2]) << 64 | u128::from(diff[3]) << 96
+    };
+    let convergent = mul_wide(distance(p1, q1), q2);
+    let semiconvergent = mul_wide(distance(p2, q2), q1);
+    let (p, q) = if cmp_wide(&convergent, &semiconvergent) != Ordering::Greater {
+        (p1, q1)
+    } else {
+        (p2, q2)
+    };
+    DecimalRatio::from_parts(p, q, exact.is_sign_negative()).expect("approximations never exceed the exact ratio")
+}
+
+/// Returns the greatest common divisor of `a` and `b`, treating `gcd(0, 0)` as 1.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+fn words(value: u128) -> [u32; 4] {
+    [
+        value as u32,
+        (value >> 32) as u32,
+        (value >> 64) as u32,
+        (value >> 96) as u32,
+    ]
+}
+
+/// Computes the full 256 bit product of two 128 bit values.
+fn mul_wide(a: u128, b: u128) -> [u32; 8] {
+    let (a, b) = (words(a), words(b));
+    let mut product = [0u32; 8];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            carry += u64::from(x) * u64::from(y) + u64::from(product[i + j]);
+            product[i + j] = carry as u32;
+            carry >>= 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    product
+}
+
+fn cmp_wide(a: &[u32; 8], b: &[u32; 8]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Computes `a - b`, where `a` must not be less than `b`.
+fn sub_wide(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let mut result = *a;
+    let mut borrow = 0i64;
+    for (word, &y) in result.iter_mut().zip(b.iter()) {
+        let diff = i64::from(*word) - i64::from(y) - borrow;
+        *word = diff as u32;
+        borrow = i64::from(diff < 0);
+    }
+    result
+}
@@ -0,0 +1,157 @@
+use crate::Decimal;
+use core::{
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+/// Saturating arithmetic through the standard operators.
+///
+/// Operations on a `Saturating<Decimal>` clamp the result to `Decimal::MAX` or `Decimal::MIN`
+/// rather than panicking on overflow, in the same way as [`Decimal::saturating_add`] and
+/// friends. This mirrors `core::num::Saturating` for the primitive integer types.
+///
+/// Division or remainder by zero has no sensible boundary to saturate to, so it still panics.
+///
+/// # Example
+///
+/// ```
+/// # use rust_decimal::{Decimal, Saturating};
+/// # use rust_decimal_macros::dec;
+/// #
+/// let exposure = Saturating(Decimal::MAX) * Saturating(dec!(2)) + Saturating(dec!(1));
+/// assert_eq!(exposure, Saturating(Decimal::MAX));
+///
+/// let mut total = Saturating(dec!(1.5));
+/// total *= Saturating(Decimal::MIN);
+/// assert_eq!(total.0, Decimal::MIN);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+impl From<Decimal> for Saturating<Decimal> {
+    #[inline]
+    fn from(value: Decimal) -> Saturating<Decimal> {
+        Saturating(value)
+    }
+}
+
+impl fmt::Display for Saturating<Decimal> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Neg for Saturating<Decimal> {
+    type Output = Saturating<Decimal>;
+
+    #[inline]
+    fn neg(self) -> Saturating<Decimal> {
+        // The range of `Decimal` is symmetrical so negation can't overflow
+        Saturating(-self.0)
+    }
+}
+
+impl Neg for &Saturating<Decimal> {
+    type Output = Saturating<Decimal>;
+
+    #[inline]
+    fn neg(self) -> Saturating<Decimal> {
+        Saturating(-self.0)
+    }
+}
+
+macro_rules! impl_saturating_binop {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl $imp<&Saturating<Decimal>> for &Saturating<Decimal> {
+            type Output = Saturating<Decimal>;
+
+            #[inline]
+            fn $method(self, other: &Saturating<Decimal>) -> Saturating<Decimal> {
+                let ($a, $b) = (self.0, other.0);
+                Saturating($body)
+            }
+        }
+
+        impl $imp<Saturating<Decimal>> for &Saturating<Decimal> {
+            type Output = Saturating<Decimal>;
+
+            #[inline]
+            fn $method(self, other: Saturating<Decimal>) -> Saturating<Decimal> {
+                self.$method(&other)
+            }
+        }
+
+        impl $imp<&Saturating<Decimal>> for Saturating<Decimal> {
+            type Output = Saturating<Decimal>;
+
+            #[inline]
+            fn $method(self, other: &Saturating<Decimal>) -> Saturating<Decimal> {
+                (&self).$method(other)
+            }
+        }
+
+        impl $imp<Saturating<Decimal>> for Saturating<Decimal> {
+            type Output = Saturating<Decimal>;
+
+            #[inline]
+            fn $method(self, other: Saturating<Decimal>) -> Saturating<Decimal> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $imp<Decimal> for Saturating<Decimal> {
+            type Output = Saturating<Decimal>;
+
+            #[inline]
+            fn $method(self, other: Decimal) -> Saturating<Decimal> {
+                (&self).$method(&Saturating(other))
+            }
+        }
+
+        impl $imp<&Decimal> for Saturating<Decimal> {
+            type Output = Saturating<Decimal>;
+
+            #[inline]
+            fn $method(self, other: &Decimal) -> Saturating<Decimal> {
+                (&self).$method(&Saturating(*other))
+            }
+        }
+
+        impl<T> $assign_imp<T> for Saturating<Decimal>
+        where
+            Saturating<Decimal>: $imp<T, Output = Saturating<Decimal>>,
+        {
+            #[inline]
+            fn $assign_method(&mut self, other: T) {
+                *self = (*self).$method(other);
+            }
+        }
+    };
+}
+
+impl_saturating_binop!(Add, add, AddAssign, add_assign, |a, b| a.saturating_add(b));
+impl_saturating_binop!(Sub, sub, SubAssign, sub_assign, |a, b| a.saturating_sub(b));
+impl_saturating_binop!(Mul, mul, MulAssign, mul_assign, |a, b| a.saturating_mul(b));
+impl_saturating_binop!(Div, div, DivAssign, div_assign, |a, b| a.saturating_div(b));
+// The magnitude of a remainder never exceeds that of the dividend, so there is nothing to clamp
+impl_saturating_binop!(Rem, rem, RemAssign, rem_assign, |a, b| a % b);
+
+impl<T> Sum<T> for Saturating<Decimal>
+where
+    Saturating<Decimal>: Add<T, Output = Saturating<Decimal>>,
+{
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Saturating(Decimal::ZERO), |sum, i| sum + i)
+    }
+}
+
+impl<T> Product<T> for Saturating<Decimal>
+where
+    Saturating<Decimal>: Mul<T, Output = Saturating<Decimal>>,
+{
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Saturating(Decimal::ONE), |product, i| product * i)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::Decimal;
 use alloc::string::ToString;
+#[cfg(feature = "money")]
+use core::marker::PhantomData;
 use core::{fmt, str::FromStr};
 use num_traits::FromPrimitive;
 use serde::{self, de::Unexpected};
@@ -561,6 +563,198 @@ impl serde::Serialize for Decimal {
     }
 }
 
+impl<const SCALE: u32> serde::Serialize for crate::FixedDecimal<SCALE> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&Decimal::from(*self), serializer)
+    }
+}
+
+impl<'de, const SCALE: u32> serde::Deserialize<'de> for crate::FixedDecimal<SCALE> {
+    fn deserialize<D>(deserializer: D) -> Result<crate::FixedDecimal<SCALE>, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let value = <Decimal as serde::Deserialize>::deserialize(deserializer)?;
+        crate::FixedDecimal::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes special values using the PostgreSQL spelling, or as the equivalent float when
+/// decimals are serialized as floats.
+impl serde::Serialize for crate::ExtDecimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            crate::ExtDecimal::Finite(value) => serde::Serialize::serialize(value, serializer),
+            #[cfg(not(feature = "serde-float"))]
+            special => serializer.collect_str(special),
+            #[cfg(feature = "serde-float")]
+            crate::ExtDecimal::NaN => serializer.serialize_f64(f64::NAN),
+            #[cfg(feature = "serde-float")]
+            crate::ExtDecimal::PosInf => serializer.serialize_f64(f64::INFINITY),
+            #[cfg(feature = "serde-float")]
+            crate::ExtDecimal::NegInf => serializer.serialize_f64(f64::NEG_INFINITY),
+        }
+    }
+}
+
+#[cfg(not(feature = "serde-str"))]
+impl<'de> serde::Deserialize<'de> for crate::ExtDecimal {
+    fn deserialize<D>(deserializer: D) -> Result<crate::ExtDecimal, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ExtDecimalVisitor)
+    }
+}
+
+#[cfg(all(feature = "serde-str", not(feature = "serde-float")))]
+impl<'de> serde::Deserialize<'de> for crate::ExtDecimal {
+    fn deserialize<D>(deserializer: D) -> Result<crate::ExtDecimal, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ExtDecimalVisitor)
+    }
+}
+
+#[cfg(all(feature = "serde-str", feature = "serde-float"))]
+impl<'de> serde::Deserialize<'de> for crate::ExtDecimal {
+    fn deserialize<D>(deserializer: D) -> Result<crate::ExtDecimal, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(ExtDecimalVisitor)
+    }
+}
+
+/// Accepts anything a `Decimal` does, along with the special values as strings or floats.
+struct ExtDecimalVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ExtDecimalVisitor {
+    type Value = crate::ExtDecimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a Decimal type representing a fixed-point number, NaN or Infinity"
+        )
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<crate::ExtDecimal, E>
+    where
+        E: serde::de::Error,
+    {
+        DecimalVisitor.visit_i64(value).map(crate::ExtDecimal::Finite)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<crate::ExtDecimal, E>
+    where
+        E: serde::de::Error,
+    {
+        DecimalVisitor.visit_u64(value).map(crate::ExtDecimal::Finite)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<crate::ExtDecimal, E>
+    where
+        E: serde::de::Error,
+    {
+        match value {
+            _ if value.is_nan() => Ok(crate::ExtDecimal::NaN),
+            f64::INFINITY => Ok(crate::ExtDecimal::PosInf),
+            f64::NEG_INFINITY => Ok(crate::ExtDecimal::NegInf),
+            _ => DecimalVisitor.visit_f64(value).map(crate::ExtDecimal::Finite),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<crate::ExtDecimal, E>
+    where
+        E: serde::de::Error,
+    {
+        match crate::ExtDecimal::from_str(value) {
+            Ok(special) if !special.is_finite() => Ok(special),
+            _ => DecimalVisitor.visit_str(value).map(crate::ExtDecimal::Finite),
+        }
+    }
+
+    #[cfg(feature = "serde-with-arbitrary-precision")]
+    fn visit_map<A>(self, map: A) -> Result<crate::ExtDecimal, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        DecimalVisitor.visit_map(map).map(crate::ExtDecimal::Finite)
+    }
+}
+
+/// Serializes an amount along with its currency code, such as `"USD 12.34"`.
+#[cfg(feature = "money")]
+impl serde::Serialize for crate::Money {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "money")]
+impl<'de> serde::Deserialize<'de> for crate::Money {
+    fn deserialize<D>(deserializer: D) -> Result<crate::Money, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(MoneyStrVisitor(
+            "an amount with its currency, such as \"USD 12.34\"",
+            PhantomData,
+        ))
+    }
+}
+
+#[cfg(feature = "money")]
+impl serde::Serialize for crate::Currency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[cfg(feature = "money")]
+impl<'de> serde::Deserialize<'de> for crate::Currency {
+    fn deserialize<D>(deserializer: D) -> Result<crate::Currency, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(MoneyStrVisitor("an ISO 4217 currency code", PhantomData))
+    }
+}
+
+/// Parses a `Money` or `Currency` from its string representation.
+#[cfg(feature = "money")]
+struct MoneyStrVisitor<T>(&'static str, PhantomData<T>);
+
+#[cfg(feature = "money")]
+impl<T: FromStr<Err = crate::Error>> serde::de::Visitor<'_> for MoneyStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.0)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<T, E>
+    where
+        E: serde::de::Error,
+    {
+        T::from_str(value).map_err(E::custom)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -633,6 +827,52 @@ mod test {
         assert_eq!("{\"amount\":\"-0\"}", serialized);
     }
 
+    #[test]
+    #[cfg(not(any(feature = "serde-str", feature = "serde-float")))]
+    fn serialize_and_deserialize_ext_decimal() {
+        let tests = [
+            ("\"NaN\"", "NaN"),
+            ("\"Infinity\"", "Infinity"),
+            ("\"-Infinity\"", "-Infinity"),
+            ("\"-1.50\"", "-1.50"),
+        ];
+        for (json, display) in tests {
+            let value: crate::ExtDecimal = serde_json::from_str(json).unwrap();
+            assert_eq!(value.to_string(), display, "{json}");
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        }
+
+        let value: crate::ExtDecimal = serde_json::from_str("12.5").unwrap();
+        assert_eq!(value, crate::ExtDecimal::Finite(Decimal::new(125, 1)));
+        let value: crate::ExtDecimal = serde_json::from_str("\"-inf\"").unwrap();
+        assert_eq!(value, crate::ExtDecimal::NegInf);
+        assert!(serde_json::from_str::<crate::ExtDecimal>("\"Infinite\"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "money")]
+    fn serialize_and_deserialize_money() {
+        let value: crate::Money = serde_json::from_str("\"12.30 USD\"").unwrap();
+        assert_eq!(value, crate::Money::new(Decimal::new(123, 1), crate::Currency::USD));
+        assert_eq!(serde_json::to_string(&value).unwrap(), "\"USD 12.30\"");
+        assert!(serde_json::from_str::<crate::Money>("\"12.30 XYZ\"").is_err());
+
+        let currency: crate::Currency = serde_json::from_str("\"JPY\"").unwrap();
+        assert_eq!(currency, crate::Currency::JPY);
+        assert_eq!(serde_json::to_string(&currency).unwrap(), "\"JPY\"");
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde-float"))]
+    fn serialize_and_deserialize_fixed_decimal() {
+        let value = crate::FixedDecimal::<2>::from_mantissa(1230);
+        let serialized = serde_json::to_string(&value).unwrap();
+        assert_eq!("\"12.30\"", serialized);
+        let deserialized: crate::FixedDecimal<2> = serde_json::from_str("\"12.345\"").unwrap();
+        assert_eq!(deserialized, crate::FixedDecimal::from_mantissa(1234));
+        assert!(serde_json::from_str::<crate::FixedDecimal<28>>("\"79228162514264337593543950335\"").is_err());
+    }
+
     #[test]
     #[cfg(feature = "serde-float")]
     fn serialize_decimal() {
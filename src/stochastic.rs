@@ -0,0 +1,96 @@
+use crate::{Decimal, RoundingStrategy};
+
+/// A distribution that rounds a decimal stochastically, for use with the `rand` crate.
+///
+/// The value is rounded away from zero with a probability equal to the fraction of a unit in the
+/// last place that would be discarded, and toward zero otherwise. For example, `1.237` rounded to
+/// two decimal places becomes `1.24` 70% of the time and `1.23` 30% of the time. The expected
+/// value of the result is therefore the original value, which avoids the bias that deterministic
+/// rounding introduces when many rounded values are aggregated.
+///
+/// # Example
+///
+/// ```
+/// # use rand_0_9 as rand;
+/// # use rand::Rng;
+/// # use rust_decimal::StochasticRounding;
+/// # use rust_decimal_macros::dec;
+/// #
+/// let mut rng = rand::rng();
+/// let rounded: rust_decimal::Decimal = rng.sample(StochasticRounding::round_dp(dec!(1.237), 2));
+/// assert!(rounded == dec!(1.23) || rounded == dec!(1.24));
+///
+/// let rounded = rng.sample(StochasticRounding::round_sf(dec!(-305.459), 2).unwrap());
+/// assert!(rounded == dec!(-300) || rounded == dec!(-310));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StochasticRounding {
+    lower: Decimal,
+    upper: Decimal,
+    // The discarded digits as a fraction of a unit in the last place
+    numerator: u128,
+    denominator: u128,
+}
+
+impl StochasticRounding {
+    /// Creates a distribution that rounds `value` to `dp` decimal places. As with
+    /// [`Decimal::round_dp_with_strategy`], values that already have `dp` or fewer decimal
+    /// places are always returned unchanged.
+    #[must_use]
+    pub fn round_dp(value: Decimal, dp: u32) -> StochasticRounding {
+        StochasticRounding::new(
+            value,
+            value.scale().saturating_sub(dp),
+            value.round_dp_with_strategy(dp, RoundingStrategy::ToZero),
+            value.round_dp_with_strategy(dp, RoundingStrategy::AwayFromZero),
+        )
+    }
+
+    /// Creates a distribution that rounds `value` to `digits` significant digits, in the same
+    /// manner as [`Decimal::round_sf_with_strategy`].
+    ///
+    /// Returns `None` if rounding away from zero would produce a value that cannot be
+    /// represented, even if the sampled result would have been rounded toward zero.
+    #[must_use]
+    pub fn round_sf(value: Decimal, digits: u32) -> Option<StochasticRounding> {
+        let mut mantissa = value.mantissa().unsigned_abs();
+        let mut mantissa_sf = 0u32;
+        while mantissa != 0 {
+            mantissa /= 10;
+            mantissa_sf += 1;
+        }
+        // Rounding to zero digits always produces zero, so nothing is left to chance
+        let discarded = if digits == 0 {
+            0
+        } else {
+            mantissa_sf.saturating_sub(digits)
+        };
+        Some(StochasticRounding::new(
+            value,
+            discarded,
+            value.round_sf_with_strategy(digits, RoundingStrategy::ToZero)?,
+            value.round_sf_with_strategy(digits, RoundingStrategy::AwayFromZero)?,
+        ))
+    }
+
+    fn new(value: Decimal, discarded: u32, lower: Decimal, upper: Decimal) -> StochasticRounding {
+        // At most 29 digits can be discarded, so the denominator always fits
+        let denominator = 10u128.pow(discarded);
+        StochasticRounding {
+            lower,
+            upper,
+            numerator: value.mantissa().unsigned_abs() % denominator,
+            denominator,
+        }
+    }
+
+    /// Chooses the rounded value given a uniformly distributed random number in the range
+    /// `0..denominator`, which is only drawn when the outcome isn't certain.
+    pub(crate) fn sample_with(&self, random_below: impl FnOnce(u128) -> u128) -> Decimal {
+        if self.numerator != 0 && random_below(self.denominator) < self.numerator {
+            self.upper
+        } else {
+            self.lower
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use crate::{
-    Decimal,
-    constants::{BYTES_TO_OVERFLOW_U64, MAX_SCALE, MAX_STR_BUFFER_SIZE, OVERFLOW_U96, WILL_OVERFLOW_U64},
+    Decimal, ParseOptions, RoundingStrategy,
+    constants::{
+        BYTES_TO_OVERFLOW_U64, MAX_PRECISION_U32, MAX_SCALE, MAX_STR_BUFFER_SIZE, OVERFLOW_U96, WILL_OVERFLOW_U64,
+    },
     error::{Error, tail_error},
     ops::array::{add_by_internal_flattened, add_one_internal, div_by_u32, is_all_zero, mul_by_u32},
+    ops::wide::{Target, mul_by_pow10, round_exact},
 };
 
 use arrayvec::{ArrayString, ArrayVec};
@@ -179,6 +182,68 @@ pub(crate) fn parse_str_radix_10_exact(str: &str) -> Result<Decimal, Error> {
     }
 }
 
+pub(crate) fn parse_str_radix_10_with_strategy(str: &str, strategy: RoundingStrategy) -> Result<Decimal, Error> {
+    match parse_str_radix_10_exact(str) {
+        Err(Error::Underflow) => {}
+        result => return result,
+    }
+
+    // The value has too many digits to be represented exactly. The fast path has already
+    // validated everything up to this point, so collect the digits into a 128 bit buffer (enough
+    // for 38 digits) and let `round_exact` deal with the rest in a single rounding step.
+    let (negative, bytes) = match str.as_bytes() {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        bytes => (false, bytes),
+    };
+    let mut data = 0u128;
+    let mut digits = 0;
+    let mut scale = 0u32;
+    let mut point = false;
+    let mut sticky = false;
+    for &b in bytes {
+        match b {
+            b'0'..=b'9' => {
+                let digit = u128::from(b - b'0');
+                if digits < 38 {
+                    data = data * 10 + digit;
+                    if data != 0 {
+                        digits += 1;
+                    }
+                } else if point {
+                    sticky |= digit != 0;
+                    continue;
+                } else {
+                    return tail_error(crate::Error::ExceedsMaximumPossibleValue);
+                }
+                if point {
+                    scale += 1;
+                }
+            }
+            b'.' if !point => point = true,
+            b'_' => {}
+            b => return tail_invalid_digit(b),
+        }
+    }
+
+    let mantissa = [
+        data as u32,
+        (data >> 32) as u32,
+        (data >> 64) as u32,
+        (data >> 96) as u32,
+    ];
+    round_exact(
+        &mantissa,
+        scale,
+        negative,
+        sticky,
+        Target::Precision(MAX_PRECISION_U32),
+        strategy,
+    )
+    .map(|rounded| rounded.value)
+    .ok_or(Error::ExceedsMaximumPossibleValue)
+}
+
 #[inline]
 fn parse_str_radix_10_dispatch<const BIG: bool, const ROUND: bool>(bytes: &[u8]) -> Result<Decimal, Error> {
     match bytes {
@@ -466,6 +531,281 @@ fn handle_data<const NEG: bool, const HAS: bool>(data: u128, scale: u8) -> Resul
     }
 }
 
+/// Parses a base 10 string, including an optional exponent, in a `const` context.
+///
+/// This is a straightforward two pass parser rather than the dispatch chain used by
+/// `parse_str_radix_10`: the first pass validates the input and counts the digits, the second
+/// accumulates as many digits as fit and rounds the remainder at the midpoint away from zero.
+pub(crate) const fn parse_str_const(str: &str) -> Result<Decimal, Error> {
+    let bytes = str.as_bytes();
+    let len = bytes.len();
+    if len == 0 {
+        return Err(Error::EmptyData);
+    }
+
+    let (negative, start) = match bytes[0] {
+        b'-' => (true, 1),
+        b'+' => (false, 1),
+        _ => (false, 0),
+    };
+
+    // First pass: validate the mantissa, counting all digits and those after the point.
+    let mut digits: i64 = 0;
+    let mut fraction_digits: i64 = 0;
+    let mut point = false;
+    let mut i = start;
+    while i < len {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                digits += 1;
+                if point {
+                    fraction_digits += 1;
+                }
+            }
+            b'.' if point => return Err(Error::DuplicatedDecimalPoint),
+            b'.' => point = true,
+            b'_' if digits > 0 => {}
+            b'_' => return Err(Error::InvalidLeadingChar),
+            b'e' | b'E' if digits > 0 => break,
+            _ => return Err(Error::InvalidCharacter),
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        return Err(Error::NoDigits);
+    }
+    let mantissa_end = i;
+
+    // The exponent is saturated since anything this large either overflows or rounds to zero.
+    let mut exponent: i64 = 0;
+    if i < len {
+        i += 1;
+        let exponent_negative = i < len && bytes[i] == b'-';
+        if i < len && (bytes[i] == b'-' || bytes[i] == b'+') {
+            i += 1;
+        }
+        let mut has_exponent = false;
+        while i < len {
+            match bytes[i] {
+                b'0'..=b'9' => {
+                    has_exponent = true;
+                    if exponent < 1_000_000 {
+                        exponent = exponent * 10 + (bytes[i] - b'0') as i64;
+                    }
+                }
+                b'_' if has_exponent => {}
+                _ => return Err(Error::FailedToParseScientificFromString),
+            }
+            i += 1;
+        }
+        if !has_exponent {
+            return Err(Error::FailedToParseScientificFromString);
+        }
+        if exponent_negative {
+            exponent = -exponent;
+        }
+    }
+
+    // Second pass: the full digit string has a scale of `fraction_digits - exponent`. Keep
+    // digits while they fit within the mantissa and the maximum scale, the first digit that
+    // doesn't is used to round.
+    let full_scale = fraction_digits - exponent;
+    let mut data: u128 = 0;
+    let mut kept: i64 = 0;
+    let mut round_digit: u128 = 0;
+    let mut i = start;
+    while i < mantissa_end {
+        let b = bytes[i];
+        i += 1;
+        if !b.is_ascii_digit() {
+            continue;
+        }
+        let digit = (b - b'0') as u128;
+        let next = data * 10 + digit;
+        if full_scale - (digits - kept - 1) > MAX_SCALE as i64 || next >= OVERFLOW_U96 {
+            round_digit = digit;
+            break;
+        }
+        data = next;
+        kept += 1;
+    }
+
+    let mut scale = full_scale - (digits - kept);
+    if scale > MAX_SCALE as i64 {
+        // Every digit lies beyond the maximum scale, so the value rounds to zero.
+        data = 0;
+        scale = MAX_SCALE as i64;
+    } else if scale < 0 {
+        if kept < digits {
+            return Err(Error::ExceedsMaximumPossibleValue);
+        }
+        if data == 0 {
+            scale = 0;
+        }
+        // A non-zero value overflows within 29 iterations
+        while scale < 0 {
+            data *= 10;
+            if data >= OVERFLOW_U96 {
+                return Err(Error::ExceedsMaximumPossibleValue);
+            }
+            scale += 1;
+        }
+    } else if round_digit >= 5 {
+        data += 1;
+        if data >= OVERFLOW_U96 {
+            if scale == 0 {
+                return Err(Error::ExceedsMaximumPossibleValue);
+            }
+            data = (data + 4) / 10;
+            scale -= 1;
+        }
+    }
+
+    Ok(Decimal::from_parts(
+        data as u32,
+        (data >> 32) as u32,
+        (data >> 64) as u32,
+        negative,
+        scale as u32,
+    ))
+}
+
+/// Parses a base 10 string accepting the syntax described by `options`.
+///
+/// The const generic dispatch is used whenever the options accept exactly the `FromStr` syntax,
+/// or the string only uses the parts of it that the options accept too, so that the common
+/// format stays on the fast path. Anything else, such as an exponent or a grouping separator,
+/// goes through the general parser.
+pub(crate) fn parse_str_radix_10_with_options(str: &str, options: &ParseOptions) -> Result<Decimal, Error> {
+    let str = if options.trim_whitespace { str.trim() } else { str };
+    if options.matches_from_str() || options.reads_like_from_str(str.as_bytes()) {
+        return match options.rounding {
+            Some(RoundingStrategy::MidpointAwayFromZero) => parse_str_radix_10(str),
+            Some(strategy) => parse_str_radix_10_with_strategy(str, strategy),
+            None => parse_str_radix_10_exact(str),
+        };
+    }
+    parse_str_radix_10_general(str.as_bytes(), options)
+}
+
+#[inline(never)]
+fn parse_str_radix_10_general(bytes: &[u8], options: &ParseOptions) -> Result<Decimal, Error> {
+    let (negative, bytes) = match bytes {
+        [] => return tail_error(crate::Error::EmptyData),
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] if options.plus_sign => (false, rest),
+        [rest @ .., b'-'] if options.trailing_minus => (true, rest),
+        bytes => (false, bytes),
+    };
+
+    // Collect up to 38 significant digits (enough to fill 128 bits), remembering whether any
+    // non-zero digits beyond that were discarded. `scale` is negative when integral digits are
+    // discarded.
+    let mut data = 0u128;
+    let mut digits = 0;
+    let mut scale = 0i64;
+    let mut point = false;
+    let mut has = false;
+    let mut sticky = false;
+    let mut exponent_marker = false;
+    let mut rest = bytes.iter();
+    for &b in rest.by_ref() {
+        match b {
+            b'0'..=b'9' => {
+                has = true;
+                let digit = u128::from(b - b'0');
+                if digits < 38 {
+                    data = data * 10 + digit;
+                    if data != 0 {
+                        digits += 1;
+                    }
+                    if point {
+                        scale += 1;
+                    }
+                } else {
+                    sticky |= digit != 0;
+                    if !point {
+                        scale -= 1;
+                    }
+                }
+            }
+            _ if b == options.decimal_separator => {
+                if point {
+                    return tail_error(crate::Error::DuplicatedDecimalPoint);
+                }
+                point = true;
+            }
+            _ if (b == b'_' && options.underscores) || options.is_grouping_separator(b) => {
+                if !has {
+                    return tail_error(crate::Error::InvalidLeadingChar);
+                }
+            }
+            b'e' | b'E' if has && options.exponent => {
+                exponent_marker = true;
+                break;
+            }
+            _ => return tail_error(crate::Error::InvalidCharacter),
+        }
+    }
+    if !has {
+        return tail_no_has();
+    }
+
+    // Anything beyond a million either overflows or rounds away entirely
+    let mut exponent = 0i64;
+    let mut exponent_negative = false;
+    let mut exponent_digits = false;
+    for (i, &b) in rest.enumerate() {
+        match b {
+            b'-' | b'+' if i == 0 => exponent_negative = b == b'-',
+            b'0'..=b'9' => {
+                exponent_digits = true;
+                exponent = (exponent * 10 + i64::from(b - b'0')).min(1_000_000);
+            }
+            b'_' if exponent_digits && options.underscores => {}
+            _ => return tail_error(crate::Error::FailedToParseScientificFromString),
+        }
+    }
+    if exponent_negative {
+        exponent = -exponent;
+    }
+    if exponent_marker && !exponent_digits {
+        return tail_error(crate::Error::FailedToParseScientificFromString);
+    }
+    scale -= exponent;
+
+    let mut mantissa = [
+        data as u32,
+        (data >> 32) as u32,
+        (data >> 64) as u32,
+        (data >> 96) as u32,
+    ];
+    if scale < 0 {
+        // Only integral digits remain, which must all have been kept for the value to fit
+        if data != 0 && (sticky || scale < -38 || !mul_by_pow10(&mut mantissa, scale.unsigned_abs() as u32)) {
+            return tail_error(crate::Error::ExceedsMaximumPossibleValue);
+        }
+        scale = 0;
+    }
+    // Beyond this all 38 digits lie past the rounding digit, so a larger scale rounds identically
+    let scale = scale.min(i64::from(MAX_SCALE) + 40) as u32;
+
+    let strategy = options.rounding.unwrap_or(RoundingStrategy::ToZero);
+    let rounded = round_exact(
+        &mantissa,
+        scale,
+        negative,
+        sticky,
+        Target::Precision(MAX_PRECISION_U32),
+        strategy,
+    )
+    .ok_or(Error::ExceedsMaximumPossibleValue)?;
+    if options.rounding.is_none() && rounded.rounded {
+        return tail_error(crate::Error::Underflow);
+    }
+    Ok(rounded.value)
+}
+
 pub(crate) fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, Error> {
     if str.is_empty() {
         return Err(crate::Error::EmptyData);
@@ -814,6 +814,69 @@ fn it_can_mulassign() {
     assert_eq!("0.0000000125", a.to_string());
 }
 
+#[test]
+fn it_can_multiply_with_scale() {
+    let tests = &[
+        ("3.125", "1.21", 2, RoundingStrategy::MidpointAwayFromZero, Some("3.78")),
+        ("3.125", "1.21", 2, RoundingStrategy::ToZero, Some("3.78")),
+        ("1.25", "0.5", 2, RoundingStrategy::MidpointNearestEven, Some("0.62")),
+        ("1.25", "0.5", 2, RoundingStrategy::MidpointAwayFromZero, Some("0.63")),
+        ("-1.25", "0.5", 2, RoundingStrategy::ToNegativeInfinity, Some("-0.63")),
+        ("1.5", "2", 2, RoundingStrategy::ToZero, Some("3.00")),
+        ("0.001", "0.001", 2, RoundingStrategy::AwayFromZero, Some("0.01")),
+        (
+            "0.0000000000000001",
+            "0.0000000000000015",
+            30,
+            RoundingStrategy::MidpointAwayFromZero,
+            Some("0.0000000000000000000000000000"),
+        ),
+        (
+            "0.000000000000011",
+            "0.000000000000015",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("0.0000000000000000000000000002"),
+        ),
+        (
+            "79228162514264337593543950335",
+            "1",
+            2,
+            RoundingStrategy::ToZero,
+            Some("79228162514264337593543950335"),
+        ),
+        // The rounded product can't be held at 28 decimal places
+        (
+            "1.2345678901234567890123456789",
+            "12345.6789",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            None,
+        ),
+        (
+            "1.2345678901234567890123456789",
+            "12345.6789",
+            24,
+            RoundingStrategy::ToZero,
+            Some("15241.578751714678875171467887"),
+        ),
+        ("39614081257132168796771975168", "2", 0, RoundingStrategy::ToZero, None),
+    ];
+    for &(a, b, dp, strategy, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = a.checked_mul_with_scale(b, dp, strategy).map(|d| d.to_string());
+        assert_eq!(
+            expected.map(String::from),
+            result,
+            "{a} * {b} to {dp} dp with {strategy:?}"
+        );
+        if let Some(expected) = expected {
+            assert_eq!(expected, a.mul_with_scale(b, dp, strategy).to_string());
+        }
+    }
+}
+
 // Division
 
 #[test]
@@ -890,6 +953,80 @@ fn it_can_divassign() {
     assert_eq!("125000000", a.to_string());
 }
 
+#[test]
+fn it_can_divide_with_scale() {
+    let tests = &[
+        ("2", "3", 2, RoundingStrategy::MidpointNearestEven, Some("0.67")),
+        ("2", "3", 2, RoundingStrategy::ToZero, Some("0.66")),
+        ("-2", "3", 2, RoundingStrategy::ToNegativeInfinity, Some("-0.67")),
+        ("-2", "3", 2, RoundingStrategy::ToPositiveInfinity, Some("-0.66")),
+        ("1", "8", 2, RoundingStrategy::MidpointNearestEven, Some("0.12")),
+        ("1", "8", 2, RoundingStrategy::MidpointAwayFromZero, Some("0.13")),
+        ("1", "8", 2, RoundingStrategy::MidpointTowardZero, Some("0.12")),
+        ("10", "4", 4, RoundingStrategy::MidpointNearestEven, Some("2.5000")),
+        ("1", "3", 0, RoundingStrategy::AwayFromZero, Some("1")),
+        (
+            "1",
+            "3",
+            30,
+            RoundingStrategy::MidpointNearestEven,
+            Some("0.3333333333333333333333333333"),
+        ),
+        // The digits beyond the 28th decimal place are taken into account when rounding once
+        (
+            "4.4499999999999999999999999997",
+            "10",
+            2,
+            RoundingStrategy::MidpointAwayFromZero,
+            Some("0.44"),
+        ),
+        (
+            "79228162514264337593543950335",
+            "1",
+            2,
+            RoundingStrategy::ToZero,
+            Some("79228162514264337593543950335"),
+        ),
+        // The rounded quotient can't be held at 28 decimal places
+        ("12345678", "7", 28, RoundingStrategy::MidpointNearestEven, None),
+        (
+            "-12345678",
+            "7",
+            22,
+            RoundingStrategy::ToNegativeInfinity,
+            Some("-1763668.2857142857142857142858"),
+        ),
+        ("79228162514264337593543950335", "2", 1, RoundingStrategy::ToZero, None),
+        (
+            "79228162514264337593543950335",
+            "0.5",
+            0,
+            RoundingStrategy::ToZero,
+            None,
+        ),
+        ("1", "0", 2, RoundingStrategy::ToZero, None),
+    ];
+    for &(a, b, dp, strategy, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = a.checked_div_with_scale(b, dp, strategy).map(|d| d.to_string());
+        assert_eq!(
+            expected.map(String::from),
+            result,
+            "{a} / {b} to {dp} dp with {strategy:?}"
+        );
+        if let Some(expected) = expected {
+            assert_eq!(expected, a.div_with_scale(b, dp, strategy).to_string());
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_dividing_by_zero_with_scale() {
+    let _ = Decimal::ONE.div_with_scale(Decimal::ZERO, 2, RoundingStrategy::ToZero);
+}
+
 // Modulus and Remainder are not the same thing!
 // https://math.stackexchange.com/q/801962/82277
 
@@ -945,6 +1082,96 @@ fn it_can_remassign() {
     assert_eq!("1", a.to_string());
 }
 
+#[test]
+fn it_can_divide_to_integers() {
+    // a, b, div_rem, div_euclid, rem_euclid, div_floor, div_ceil
+    let tests = &[
+        ("7", "2", ("3", "1"), "3", "1", "3", "4"),
+        ("-7", "2", ("-3", "-1"), "-4", "1", "-4", "-3"),
+        ("7", "-2", ("-3", "1"), "-3", "1", "-4", "-3"),
+        ("-7", "-2", ("3", "-1"), "4", "1", "3", "4"),
+        ("7.5", "2", ("3", "1.5"), "3", "1.5", "3", "4"),
+        ("-7.5", "2", ("-3", "-1.5"), "-4", "0.5", "-4", "-3"),
+        ("6", "3", ("2", "0"), "2", "0", "2", "2"),
+        ("-6", "3", ("-2", "0"), "-2", "0", "-2", "-2"),
+        ("0.5", "3", ("0", "0.5"), "0", "0.5", "0", "1"),
+        ("-0.5", "3", ("0", "-0.5"), "-1", "2.5", "-1", "0"),
+        ("42.2", "11.9", ("3", "6.5"), "3", "6.5", "3", "4"),
+        (
+            "1",
+            "0.0000000000000000000000000003",
+            ("3333333333333333333333333333", "0.0000000000000000000000000001"),
+            "3333333333333333333333333333",
+            "0.0000000000000000000000000001",
+            "3333333333333333333333333333",
+            "3333333333333333333333333334",
+        ),
+        (
+            "79228162514264337593543950335",
+            "1",
+            ("79228162514264337593543950335", "0"),
+            "79228162514264337593543950335",
+            "0",
+            "79228162514264337593543950335",
+            "79228162514264337593543950335",
+        ),
+    ];
+    for &(a, b, (quot, rem), div_euclid, rem_euclid, div_floor, div_ceil) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let (q, r) = a.div_rem(b);
+        assert_eq!(
+            (quot, rem),
+            (q.to_string().as_str(), r.to_string().as_str()),
+            "{a}.div_rem({b})"
+        );
+        assert_eq!(div_euclid, a.div_euclid(b).to_string(), "{a}.div_euclid({b})");
+        assert_eq!(rem_euclid, a.rem_euclid(b).to_string(), "{a}.rem_euclid({b})");
+        assert_eq!(div_floor, a.div_floor(b).to_string(), "{a}.div_floor({b})");
+        assert_eq!(div_ceil, a.div_ceil(b).to_string(), "{a}.div_ceil({b})");
+        // The Euclidean identity must hold exactly
+        assert_eq!(a, a.div_euclid(b) * b + a.rem_euclid(b), "{a} = q * {b} + r");
+    }
+
+    // The quotient is always the floor for a positive divisor and the ceiling for a negative one
+    let a = Decimal::from_str("-0.0000000000000000000000000001").unwrap();
+    assert_eq!(a.div_euclid(Decimal::TEN), Decimal::NEGATIVE_ONE);
+    assert_eq!(a.div_euclid(Decimal::TEN), a.div_floor(Decimal::TEN));
+    assert_eq!(a.div_euclid(-Decimal::TEN), Decimal::ONE);
+    assert_eq!(a.div_euclid(-Decimal::TEN), a.div_ceil(-Decimal::TEN));
+    // The exact remainder of |b| - 1e-28 rounds to |b| itself
+    for b in [Decimal::from_str("1e28").unwrap(), Decimal::from_str("-1e28").unwrap()] {
+        assert_eq!(a.rem_euclid(b), b.abs(), "{a}.rem_euclid({b})");
+        assert_eq!(a.div_euclid(b), -b.signum(), "{a}.div_euclid({b})");
+    }
+}
+
+#[test]
+fn it_checks_integer_division() {
+    use num_traits::{CheckedEuclid, Euclid};
+
+    assert_eq!(Decimal::ONE.checked_div_rem(Decimal::ZERO), None);
+    assert_eq!(Decimal::ONE.checked_div_euclid(Decimal::ZERO), None);
+    assert_eq!(Decimal::ONE.checked_rem_euclid(Decimal::ZERO), None);
+    assert_eq!(Decimal::ONE.checked_div_floor(Decimal::ZERO), None);
+    assert_eq!(Decimal::ONE.checked_div_ceil(Decimal::ZERO), None);
+    assert_eq!(Decimal::MAX.checked_div_floor(Decimal::new(1, 1)), None);
+    assert_eq!(Decimal::MIN.checked_div_ceil(Decimal::new(5, 1)), None);
+
+    let a = Decimal::new(-75, 1);
+    let b = Decimal::TWO;
+    assert_eq!(Euclid::div_euclid(&a, &b), Decimal::from(-4));
+    assert_eq!(Euclid::rem_euclid(&a, &b), Decimal::new(5, 1));
+    assert_eq!(CheckedEuclid::checked_div_euclid(&a, &b), Some(Decimal::from(-4)));
+    assert_eq!(CheckedEuclid::checked_rem_euclid(&a, &Decimal::ZERO), None);
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_dividing_to_integer_by_zero() {
+    let _ = Decimal::ONE.div_floor(Decimal::ZERO);
+}
+
 #[test]
 fn it_eqs_decimals() {
     fn eq(a: &str, b: &str, c: bool) {
@@ -2152,6 +2379,153 @@ fn it_ceils_decimals() {
     }
 }
 
+#[test]
+fn it_can_round_to_power_of_ten() {
+    let tests = &[
+        ("123456.78", 3, RoundingStrategy::MidpointNearestEven, Some("123000")),
+        ("123456.78", 3, RoundingStrategy::AwayFromZero, Some("124000")),
+        ("123456.78", 3, RoundingStrategy::ToPositiveInfinity, Some("124000")),
+        ("-123456.78", 3, RoundingStrategy::ToPositiveInfinity, Some("-123000")),
+        ("-123456.78", 3, RoundingStrategy::ToNegativeInfinity, Some("-124000")),
+        ("2500", 3, RoundingStrategy::MidpointNearestEven, Some("2000")),
+        ("3500", 3, RoundingStrategy::MidpointNearestEven, Some("4000")),
+        ("2500", 3, RoundingStrategy::MidpointAwayFromZero, Some("3000")),
+        ("2500", 3, RoundingStrategy::MidpointTowardZero, Some("2000")),
+        ("2500.0001", 3, RoundingStrategy::MidpointTowardZero, Some("3000")),
+        ("499", 3, RoundingStrategy::MidpointNearestEven, Some("0")),
+        ("999.9", 3, RoundingStrategy::MidpointNearestEven, Some("1000")),
+        ("123456.78", 0, RoundingStrategy::MidpointNearestEven, Some("123457")),
+        ("123456.78", -1, RoundingStrategy::MidpointNearestEven, Some("123456.8")),
+        (
+            "123456.78",
+            -5,
+            RoundingStrategy::MidpointNearestEven,
+            Some("123456.78"),
+        ),
+        ("123456.78", i32::MIN, RoundingStrategy::AwayFromZero, Some("123456.78")),
+        ("123456.78", 40, RoundingStrategy::MidpointNearestEven, Some("0")),
+        ("123456.78", i32::MAX, RoundingStrategy::ToZero, Some("0")),
+        ("123456.78", i32::MAX, RoundingStrategy::AwayFromZero, None),
+        (
+            "79228162514264337593543950335",
+            27,
+            RoundingStrategy::MidpointNearestEven,
+            Some("79000000000000000000000000000"),
+        ),
+        (
+            "79228162514264337593543950335",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            None,
+        ),
+        ("79228162514264337593543950335", 1, RoundingStrategy::AwayFromZero, None),
+        (
+            "79228162514264337593543950335",
+            29,
+            RoundingStrategy::MidpointNearestEven,
+            None,
+        ),
+        (
+            "49228162514264337593543950335",
+            29,
+            RoundingStrategy::MidpointNearestEven,
+            Some("0"),
+        ),
+    ];
+    for &(value, exp, strategy, expected) in tests {
+        let value = Decimal::from_str(value).unwrap();
+        let rounded = value.checked_round_to_power_of_ten(exp, strategy);
+        assert_eq!(
+            expected,
+            rounded.map(|d| d.to_string()).as_deref(),
+            "{value} to 10^{exp} with {strategy:?}"
+        );
+        if exp < 0 && exp > -28 {
+            assert_eq!(rounded, Some(value.round_dp_with_strategy(-exp as u32, strategy)));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Rounding to a power of ten overflowed")]
+fn it_panics_when_rounding_to_power_of_ten_overflows() {
+    let _ = Decimal::MAX.round_to_power_of_ten(2, RoundingStrategy::AwayFromZero);
+}
+
+#[test]
+fn it_can_round_to_increment() {
+    let tests = &[
+        ("1.23", "0.05", RoundingStrategy::MidpointNearestEven, Some("1.25")),
+        ("1.22", "0.05", RoundingStrategy::MidpointNearestEven, Some("1.20")),
+        ("1.225", "0.05", RoundingStrategy::MidpointNearestEven, Some("1.20")),
+        ("1.275", "0.05", RoundingStrategy::MidpointNearestEven, Some("1.30")),
+        ("1.225", "0.05", RoundingStrategy::MidpointAwayFromZero, Some("1.25")),
+        ("1.225", "0.05", RoundingStrategy::MidpointTowardZero, Some("1.20")),
+        ("-1.225", "0.05", RoundingStrategy::MidpointAwayFromZero, Some("-1.25")),
+        ("-1.21", "0.05", RoundingStrategy::ToNegativeInfinity, Some("-1.25")),
+        ("-1.24", "0.05", RoundingStrategy::ToPositiveInfinity, Some("-1.20")),
+        ("1.21", "0.05", RoundingStrategy::AwayFromZero, Some("1.25")),
+        ("1.2", "-0.05", RoundingStrategy::AwayFromZero, Some("1.20")),
+        ("10.13", "0.25", RoundingStrategy::MidpointNearestEven, Some("10.25")),
+        (
+            "100.00012",
+            "0.0005",
+            RoundingStrategy::MidpointNearestEven,
+            Some("100.0000"),
+        ),
+        ("1250", "100", RoundingStrategy::MidpointNearestEven, Some("1200")),
+        ("1350", "100", RoundingStrategy::MidpointNearestEven, Some("1400")),
+        ("7", "3", RoundingStrategy::MidpointNearestEven, Some("6")),
+        ("0", "0.05", RoundingStrategy::AwayFromZero, Some("0.00")),
+        ("1", "0", RoundingStrategy::MidpointNearestEven, None),
+        (
+            "79228162514264337593543950335",
+            "10",
+            RoundingStrategy::AwayFromZero,
+            None,
+        ),
+        (
+            "79228162514264337593543950335",
+            "10",
+            RoundingStrategy::ToZero,
+            Some("79228162514264337593543950330"),
+        ),
+        // The nearest multiple 6999999999999999999999999999.96 can't be held at a scale of 2
+        (
+            "7000000000000000000000000000",
+            "0.33",
+            RoundingStrategy::MidpointNearestEven,
+            None,
+        ),
+    ];
+    for &(value, increment, strategy, expected) in tests {
+        let value = Decimal::from_str(value).unwrap();
+        let increment = Decimal::from_str(increment).unwrap();
+        let rounded = value.checked_round_to_increment(increment, strategy);
+        assert_eq!(
+            expected,
+            rounded.map(|d| d.to_string()).as_deref(),
+            "{value} to increment {increment} with {strategy:?}"
+        );
+        if let Some(expected) = expected {
+            assert_eq!(expected, value.round_to_increment(increment, strategy).to_string());
+        }
+        if strategy == RoundingStrategy::ToZero {
+            assert_eq!(rounded, value.checked_trunc_to_increment(increment));
+        }
+    }
+    assert_eq!(
+        Decimal::new(-1049, 2).trunc_to_increment(Decimal::new(25, 2)),
+        Decimal::new(-1025, 2)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Rounding to an increment of zero")]
+fn it_panics_when_rounding_to_a_zero_increment() {
+    let _ = Decimal::ONE.round_to_increment(Decimal::ZERO, RoundingStrategy::ToZero);
+}
+
 #[test]
 fn it_finds_max_of_two() {
     let tests = &[("1", "1", "1"), ("2", "1", "2"), ("1", "2", "2")];
@@ -2407,6 +2781,72 @@ fn it_can_round_complex_numbers_using_round_half_down() {
     assert_eq!("0.16", part.to_string(), "RoundHalfDown");
 }
 
+#[test]
+fn it_can_round_using_additional_strategies() {
+    let tests = &[
+        ("6.5", 0, RoundingStrategy::MidpointTowardNegativeInfinity, "6"),
+        ("-6.5", 0, RoundingStrategy::MidpointTowardNegativeInfinity, "-7"),
+        ("6.51", 0, RoundingStrategy::MidpointTowardNegativeInfinity, "7"),
+        ("-6.49", 0, RoundingStrategy::MidpointTowardNegativeInfinity, "-6"),
+        ("1.2345", 3, RoundingStrategy::MidpointTowardNegativeInfinity, "1.234"),
+        ("6.5", 0, RoundingStrategy::MidpointTowardPositiveInfinity, "7"),
+        ("-6.5", 0, RoundingStrategy::MidpointTowardPositiveInfinity, "-6"),
+        ("-6.51", 0, RoundingStrategy::MidpointTowardPositiveInfinity, "-7"),
+        ("6.49", 0, RoundingStrategy::MidpointTowardPositiveInfinity, "6"),
+        ("-1.2345", 3, RoundingStrategy::MidpointTowardPositiveInfinity, "-1.234"),
+        ("6.2", 0, RoundingStrategy::ToOdd, "7"),
+        ("7.8", 0, RoundingStrategy::ToOdd, "7"),
+        ("6.0", 0, RoundingStrategy::ToOdd, "6"),
+        ("-6.2", 0, RoundingStrategy::ToOdd, "-7"),
+        ("0.0001", 2, RoundingStrategy::ToOdd, "0.01"),
+        ("1.2", 3, RoundingStrategy::ToOdd, "1.2"),
+        ("6.2", 0, RoundingStrategy::ZeroFiveUp, "6"),
+        ("5.2", 0, RoundingStrategy::ZeroFiveUp, "6"),
+        ("10.2", 0, RoundingStrategy::ZeroFiveUp, "11"),
+        ("5.0", 0, RoundingStrategy::ZeroFiveUp, "5"),
+        ("-5.2", 0, RoundingStrategy::ZeroFiveUp, "-6"),
+        ("0.001", 2, RoundingStrategy::ZeroFiveUp, "0.01"),
+        ("1.4999", 1, RoundingStrategy::ZeroFiveUp, "1.4"),
+        (
+            "7.9228162514264337593543950355",
+            27,
+            RoundingStrategy::ZeroFiveUp,
+            "7.922816251426433759354395036",
+        ),
+    ];
+    for &(input, dp, strategy, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        let b = a.round_dp_with_strategy(dp, strategy);
+        assert_eq!(
+            expected,
+            b.to_string(),
+            "{input}.round_dp_with_strategy({dp}, {strategy:?})"
+        );
+    }
+}
+
+#[test]
+fn it_avoids_double_rounding_when_rounding_to_odd_first() {
+    // Rounding to nearest directly gives the same result as first rounding to odd with two extra
+    // digits, or using 05UP with one extra digit
+    for input in ["1.2450001", "1.2449999", "1.245", "-2.3550000001", "2.355"] {
+        let value = Decimal::from_str(input).unwrap();
+        let via_odd = value
+            .round_dp_with_strategy(4, RoundingStrategy::ToOdd)
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven);
+        assert_eq!(value.round_dp(2), via_odd, "{input}");
+
+        let via_05up = value
+            .round_dp_with_strategy(3, RoundingStrategy::ZeroFiveUp)
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        assert_eq!(
+            value.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero),
+            via_05up,
+            "{input}"
+        );
+    }
+}
+
 #[test]
 fn it_can_round_to_2dp_using_explicit_function() {
     let a = Decimal::from_str("6.12345").unwrap();
@@ -2643,6 +3083,112 @@ fn it_can_round_significant_figures_with_strategy() {
     }
 }
 
+#[test]
+fn it_can_round_significant_figures_with_additional_strategies() {
+    let tests = &[
+        (
+            "12350",
+            3u32,
+            RoundingStrategy::MidpointTowardNegativeInfinity,
+            Some("12300"),
+        ),
+        (
+            "-12350",
+            3u32,
+            RoundingStrategy::MidpointTowardNegativeInfinity,
+            Some("-12400"),
+        ),
+        (
+            "1.235",
+            3u32,
+            RoundingStrategy::MidpointTowardPositiveInfinity,
+            Some("1.24"),
+        ),
+        (
+            "-1.235",
+            3u32,
+            RoundingStrategy::MidpointTowardPositiveInfinity,
+            Some("-1.23"),
+        ),
+        ("12201", 3u32, RoundingStrategy::ToOdd, Some("12300")),
+        ("0.012301", 3u32, RoundingStrategy::ToOdd, Some("0.0123")),
+        ("12501", 3u32, RoundingStrategy::ZeroFiveUp, Some("12600")),
+        ("1.2401", 3u32, RoundingStrategy::ZeroFiveUp, Some("1.24")),
+        (
+            "79228162514264337593543950335",
+            1u32,
+            RoundingStrategy::MidpointTowardPositiveInfinity,
+            None,
+        ),
+        (
+            "79228162514264337593543950335",
+            1u32,
+            RoundingStrategy::ZeroFiveUp,
+            Some("70000000000000000000000000000"),
+        ),
+    ];
+    for &(input, sf, strategy, expected) in tests {
+        let input = Decimal::from_str(input).unwrap();
+        let result = input.round_sf_with_strategy(sf, strategy).map(|d| d.to_string());
+        assert_eq!(
+            expected.map(str::to_string),
+            result,
+            "{input}.round_sf_with_strategy({sf}, {strategy:?})"
+        );
+    }
+}
+
+#[test]
+fn it_can_round_stochastically() {
+    use rand_0_9::Rng;
+    use rust_decimal::StochasticRounding;
+
+    let mut rng = rand_0_9::rng();
+    let tests = &[
+        ("1.25", 1, "1.2", "1.3"),
+        ("-1.25", 1, "-1.2", "-1.3"),
+        ("1.237", 2, "1.23", "1.24"),
+        ("0.0000000000000000000000000001", 0, "0", "1"),
+    ];
+    for &(input, dp, lower, upper) in tests {
+        let value = Decimal::from_str(input).unwrap();
+        let distribution = StochasticRounding::round_dp(value, dp);
+        let (lower, upper) = (Decimal::from_str(lower).unwrap(), Decimal::from_str(upper).unwrap());
+        let mut sum = Decimal::ZERO;
+        for _ in 0..1000 {
+            let rounded: Decimal = rng.sample(distribution);
+            assert!(rounded == lower || rounded == upper, "{input} rounded to {rounded}");
+            sum += rounded;
+        }
+        // The expected value is the original value, with plenty of slack for randomness
+        let mean = sum / Decimal::from(1000);
+        let unit = (upper - lower).abs();
+        assert!(
+            (mean - value).abs() < unit / Decimal::from(5),
+            "{input} had a mean of {mean}"
+        );
+    }
+
+    // Exact values are always returned unchanged
+    let distribution = StochasticRounding::round_dp(Decimal::from_str("1.20").unwrap(), 1);
+    for _ in 0..100 {
+        assert_eq!("1.2", rng.sample::<Decimal, _>(distribution).to_string());
+    }
+    let distribution = StochasticRounding::round_dp(Decimal::from_str("1.2").unwrap(), 3);
+    assert_eq!("1.2", rng.sample::<Decimal, _>(distribution).to_string());
+
+    let distribution = StochasticRounding::round_sf(Decimal::from_str("-305.459").unwrap(), 2).unwrap();
+    for _ in 0..100 {
+        let rounded = rng.sample::<Decimal, _>(distribution).to_string();
+        assert!(rounded == "-300" || rounded == "-310", "Rounded to {rounded}");
+    }
+    let distribution = StochasticRounding::round_sf(Decimal::from_str("305.459").unwrap(), 7).unwrap();
+    assert_eq!("305.4590", rng.sample::<Decimal, _>(distribution).to_string());
+    let distribution = StochasticRounding::round_sf(Decimal::from_str("305.459").unwrap(), 0).unwrap();
+    assert_eq!("0", rng.sample::<Decimal, _>(distribution).to_string());
+    assert_eq!(StochasticRounding::round_sf(Decimal::MAX, 1), None);
+}
+
 #[test]
 fn it_can_trunc() {
     let tests = &[
@@ -2851,6 +3397,144 @@ fn it_converts_to_f64() {
     }
 }
 
+#[test]
+fn it_converts_to_the_nearest_float() {
+    let tests = &[
+        // Values where rounding the integral and fractional parts separately gives the wrong result
+        ("0.3", 0.3f64, 0.3f32),
+        ("9007199254740993", 9007199254740992f64, 9007199000000000f32),
+        ("9007199254740995", 9007199254740996f64, 9007199000000000f32),
+        (
+            "9007199254740993.000000000001",
+            9007199254740994f64,
+            9007199000000000f32,
+        ),
+        ("1.0000000596046447753906250001", 1.0000000596046448f64, 1.0000001f32),
+        ("1.000000059604644775390625", 1.0000000596046448f64, 1f32),
+        ("16777217", 16777217f64, 16777216f32),
+        ("16777219", 16777219f64, 16777220f32),
+        ("0.0000000000000000000000000001", 1e-28f64, 1e-28f32),
+        (
+            "79228162514264337593543950335",
+            7.922816251426434e28f64,
+            7.9228163e28f32,
+        ),
+        ("-7.9228162514264337593543950335", -7.9228162514264335f64, -7.9228163f32),
+    ];
+    for &(input, expected_f64, expected_f32) in tests {
+        let value = Decimal::from_str(input).unwrap();
+        assert_eq!(Some(expected_f64), value.to_f64(), "{input}.to_f64()");
+        assert_eq!(Some(expected_f32), value.to_f32(), "{input}.to_f32()");
+    }
+
+    // The result should always match parsing the string representation
+    use rand_0_9::Rng;
+    let mut rng = rand_0_9::rng();
+    for _ in 0..10_000 {
+        let value: Decimal = rng.random();
+        let string = value.to_string();
+        assert_eq!(
+            Some(f64::from_str(&string).unwrap()),
+            value.to_f64(),
+            "{string}.to_f64()"
+        );
+        assert_eq!(
+            Some(f32::from_str(&string).unwrap()),
+            value.to_f32(),
+            "{string}.to_f32()"
+        );
+    }
+}
+
+#[test]
+fn it_converts_to_float_with_strategy() {
+    let tests = &[
+        ("0.1", RoundingStrategy::ToZero, 0.09999999999999999f64, 0.099999994f32),
+        ("0.1", RoundingStrategy::AwayFromZero, 0.1f64, 0.1f32),
+        ("-0.1", RoundingStrategy::ToNegativeInfinity, -0.1f64, -0.1f32),
+        (
+            "-0.1",
+            RoundingStrategy::ToPositiveInfinity,
+            -0.09999999999999999f64,
+            -0.099999994f32,
+        ),
+        (
+            "16777217",
+            RoundingStrategy::MidpointNearestEven,
+            16777217f64,
+            16777216f32,
+        ),
+        (
+            "16777217",
+            RoundingStrategy::MidpointAwayFromZero,
+            16777217f64,
+            16777218f32,
+        ),
+        (
+            "-16777217",
+            RoundingStrategy::MidpointTowardNegativeInfinity,
+            -16777217f64,
+            -16777218f32,
+        ),
+        (
+            "-16777217",
+            RoundingStrategy::MidpointTowardPositiveInfinity,
+            -16777217f64,
+            -16777216f32,
+        ),
+        (
+            "16777219",
+            RoundingStrategy::MidpointTowardZero,
+            16777219f64,
+            16777218f32,
+        ),
+        ("16777218.5", RoundingStrategy::ToOdd, 16777218.5f64, 16777218f32),
+        ("16777218.5", RoundingStrategy::ZeroFiveUp, 16777218.5f64, 16777218f32),
+        ("16777220.5", RoundingStrategy::ToOdd, 16777220.5f64, 16777222f32),
+        ("0", RoundingStrategy::AwayFromZero, 0f64, 0f32),
+    ];
+    for &(input, strategy, expected_f64, expected_f32) in tests {
+        let value = Decimal::from_str(input).unwrap();
+        let actual = value.to_f64_with_strategy(strategy);
+        assert_eq!(
+            expected_f64.to_bits(),
+            actual.to_bits(),
+            "{input} to f64 with {strategy:?}: {actual}"
+        );
+        let actual = value.to_f32_with_strategy(strategy);
+        assert_eq!(
+            expected_f32.to_bits(),
+            actual.to_bits(),
+            "{input} to f32 with {strategy:?}: {actual}"
+        );
+    }
+
+    // The sign of zero is retained
+    let mut negative_zero = Decimal::ZERO;
+    negative_zero.set_sign_negative(true);
+    assert_eq!((-0f64).to_bits(), negative_zero.to_f64().unwrap().to_bits());
+    assert_eq!((-0f32).to_bits(), negative_zero.to_f32().unwrap().to_bits());
+
+    // Directed rounding always brackets the nearest float
+    use rand_0_9::Rng;
+    let mut rng = rand_0_9::rng();
+    for _ in 0..10_000 {
+        let value: Decimal = rng.random();
+        let nearest = value.to_f64().unwrap();
+        let down = value.to_f64_with_strategy(RoundingStrategy::ToNegativeInfinity);
+        let up = value.to_f64_with_strategy(RoundingStrategy::ToPositiveInfinity);
+        assert!(down <= nearest && nearest <= up, "{value}: {down} <= {nearest} <= {up}");
+        assert!(
+            nearest == down || nearest == up,
+            "{value}: {nearest} is one of {down} or {up}"
+        );
+        assert!(
+            up.to_bits().abs_diff(down.to_bits()) <= 1,
+            "{value}: {down} and {up} are adjacent"
+        );
+    }
+}
+
 #[test]
 fn it_converts_to_f64_try() {
     let tests = &[
@@ -3161,22 +3845,180 @@ fn it_converts_from_f64_retaining_bits() {
 }
 
 #[test]
-fn it_converts_to_integers() {
-    assert_eq!(i64::try_from(Decimal::ONE), Ok(1));
-    assert_eq!(i64::try_from(Decimal::MAX), Err(Error::ConversionTo("i64")));
-    assert_eq!(u128::try_from(Decimal::ONE_HUNDRED), Ok(100));
-}
-
-#[test]
-fn it_handles_simple_underflow() {
-    // Issue #71
-    let rate = Decimal::new(19, 2); // 0.19
-    let one = Decimal::new(1, 0); // 1
-    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
-    let result = one * part;
-    assert_eq!("0.1596638655462184873949579832", result.to_string());
-
-    // 169 * 0.1596638655462184873949579832 = 26.983193277310924
+fn it_converts_from_f64_with_scale() {
+    let tests = [
+        (
+            0.1_f64,
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "0.1000000000000000055511151231",
+        ),
+        (0.1_f64, 2, RoundingStrategy::AwayFromZero, "0.11"),
+        (0.1_f64, 1, RoundingStrategy::ToZero, "0.1"),
+        (2.675_f64, 2, RoundingStrategy::MidpointAwayFromZero, "2.67"),
+        (-2.675_f64, 2, RoundingStrategy::ToNegativeInfinity, "-2.68"),
+        (0.125_f64, 2, RoundingStrategy::MidpointNearestEven, "0.12"),
+        (0.125_f64, 2, RoundingStrategy::MidpointTowardPositiveInfinity, "0.13"),
+        (-0.5_f64, 0, RoundingStrategy::MidpointNearestEven, "0"),
+        (
+            5e-324_f64,
+            28,
+            RoundingStrategy::AwayFromZero,
+            "0.0000000000000000000000000001",
+        ),
+        (
+            5e-324_f64,
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "0.0000000000000000000000000000",
+        ),
+        (
+            4.9e-29_f64,
+            28,
+            RoundingStrategy::MidpointAwayFromZero,
+            "0.0000000000000000000000000000",
+        ),
+        (123456789.987654321_f64, 3, RoundingStrategy::ToZero, "123456789.987"),
+        (0.3_f64, 0, RoundingStrategy::ZeroFiveUp, "1"),
+        (5.3_f64, 0, RoundingStrategy::ZeroFiveUp, "6"),
+        (1.5_f64, 6, RoundingStrategy::ToZero, "1.500000"),
+        (
+            7.9228162514264e28_f64,
+            0,
+            RoundingStrategy::ToZero,
+            "79228162514264003342009106432",
+        ),
+        (1e20_f64, 10, RoundingStrategy::ToZero, "100000000000000000000.00000000"),
+        // The value can't be held at 28 decimal places, so the largest scale that fits is used
+        (
+            123456789.123_f64,
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "123456789.12299999594688415527",
+        ),
+        (
+            -123456789.123_f64,
+            28,
+            RoundingStrategy::ToZero,
+            "-123456789.12299999594688415527",
+        ),
+        (1e28_f64, 28, RoundingStrategy::ToZero, "9999999999999999583119736832"),
+    ];
+    for &(input, dp, strategy, expected) in &tests {
+        assert_eq!(
+            Ok(expected.to_string()),
+            Decimal::from_f64_with_scale(input, dp, strategy).map(|d| d.to_string()),
+            "from_f64_with_scale({input}, {dp}, {strategy:?})"
+        );
+    }
+
+    let strategy = RoundingStrategy::MidpointNearestEven;
+    assert_eq!(
+        Decimal::from_f32_with_scale(0.1, 12, strategy),
+        Ok(Decimal::from_str("0.100000001490").unwrap())
+    );
+    assert_eq!(Decimal::from_f64_with_scale(f64::NAN, 2, strategy), Err(Error::NaN));
+    assert_eq!(Decimal::from_f32_with_scale(f32::NAN, 2, strategy), Err(Error::NaN));
+    assert_eq!(
+        Decimal::from_f64_with_scale(f64::INFINITY, 2, strategy),
+        Err(Error::Infinite)
+    );
+    assert_eq!(
+        Decimal::from_f64_with_scale(f64::NEG_INFINITY, 2, strategy),
+        Err(Error::Infinite)
+    );
+    assert_eq!(
+        Decimal::from_f64_with_scale(1e29, 0, strategy),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal::from_f64_with_scale(-f64::MAX, 0, strategy),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+    assert_eq!(
+        Decimal::from_f64_with_scale(79228162514264337593543950335.0, 0, strategy),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal::from_f64_with_scale(1.0, 29, strategy),
+        Err(Error::ScaleExceedsMaximumPrecision(29))
+    );
+}
+
+#[test]
+fn it_converts_from_floats_using_the_shortest_representation() {
+    let tests = [
+        (0.1_f64, "0.1"),
+        (0.1_f64 + 0.2_f64, "0.30000000000000004"),
+        (1.0_f64 / 3.0_f64, "0.3333333333333333"),
+        (-2.0_f64 / 3.0_f64 * 1e-10, "-0.00000000006666666666666667"),
+        (123456789.0_f64, "123456789"),
+        (1e28_f64, "10000000000000000000000000000"),
+        (1e-28_f64, "0.0000000000000000000000000001"),
+        (0.0_f64, "0"),
+        (-0.0_f64, "-0"),
+    ];
+    for &(input, expected) in &tests {
+        assert_eq!(
+            Ok(expected.to_string()),
+            Decimal::from_f64_shortest(input).map(|d| d.to_string()),
+            "from_f64_shortest({input})"
+        );
+    }
+
+    let tests = [
+        (0.1_f32, "0.1"),
+        (16777217_f32, "16777216"),
+        (3.4028235e28_f32, "34028235000000000000000000000"),
+    ];
+    for &(input, expected) in &tests {
+        assert_eq!(
+            Ok(expected.to_string()),
+            Decimal::from_f32_shortest(input).map(|d| d.to_string()),
+            "from_f32_shortest({input})"
+        );
+    }
+
+    assert!(Decimal::from_f64_shortest(-0.0).unwrap().is_sign_negative());
+    assert_eq!(Decimal::from_f64_shortest(f64::NAN), Err(Error::NaN));
+    assert_eq!(Decimal::from_f32_shortest(f32::NEG_INFINITY), Err(Error::Infinite));
+    assert_eq!(Decimal::from_f64_shortest(1.5e-29), Err(Error::Underflow));
+    assert_eq!(
+        Decimal::from_f64_shortest(1e29),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal::from_f32_shortest(-1e30),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+
+    // The shortest representation always converts back to the original float
+    use rand_0_9::Rng;
+    let mut rng = rand_0_9::rng();
+    for _ in 0..10_000 {
+        let input = rng.random_range(-1e20_f64..1e20_f64) * 10_f64.powi(rng.random_range(-8..8));
+        let decimal = Decimal::from_f64_shortest(input).unwrap();
+        assert_eq!(Some(input), decimal.to_f64(), "from_f64_shortest({input}) = {decimal}");
+    }
+}
+
+#[test]
+fn it_converts_to_integers() {
+    assert_eq!(i64::try_from(Decimal::ONE), Ok(1));
+    assert_eq!(i64::try_from(Decimal::MAX), Err(Error::ConversionTo("i64")));
+    assert_eq!(u128::try_from(Decimal::ONE_HUNDRED), Ok(100));
+}
+
+#[test]
+fn it_handles_simple_underflow() {
+    // Issue #71
+    let rate = Decimal::new(19, 2); // 0.19
+    let one = Decimal::new(1, 0); // 1
+    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
+    let result = one * part;
+    assert_eq!("0.1596638655462184873949579832", result.to_string());
+
+    // 169 * 0.1596638655462184873949579832 = 26.983193277310924
     let result = part * Decimal::new(169, 0);
     assert_eq!("26.983193277310924369747899161", result.to_string());
     let result = Decimal::new(169, 0) * part;
@@ -3256,6 +4098,130 @@ fn it_can_parse_exact_highly_significant_numbers() {
     }
 }
 
+#[test]
+fn it_can_parse_highly_significant_numbers_with_strategy() {
+    let tests = &[
+        (
+            "11.1111111111111111111111111115",
+            RoundingStrategy::MidpointNearestEven,
+            "11.111111111111111111111111112",
+        ),
+        (
+            "11.1111111111111111111111111125",
+            RoundingStrategy::MidpointNearestEven,
+            "11.111111111111111111111111112",
+        ),
+        (
+            "11.11111111111111111111111111250001",
+            RoundingStrategy::MidpointNearestEven,
+            "11.111111111111111111111111113",
+        ),
+        (
+            "11.11111111111111111111111111199",
+            RoundingStrategy::ToZero,
+            "11.111111111111111111111111111",
+        ),
+        (
+            "-11.11111111111111111111111111101",
+            RoundingStrategy::ToNegativeInfinity,
+            "-11.111111111111111111111111112",
+        ),
+        (
+            "-11.11111111111111111111111111101",
+            RoundingStrategy::ToPositiveInfinity,
+            "-11.111111111111111111111111111",
+        ),
+        (
+            "11.1111111111111111111111111115",
+            RoundingStrategy::MidpointTowardNegativeInfinity,
+            "11.111111111111111111111111111",
+        ),
+        (
+            "-11.1111111111111111111111111115",
+            RoundingStrategy::MidpointTowardNegativeInfinity,
+            "-11.111111111111111111111111112",
+        ),
+        (
+            "-11.1111111111111111111111111115",
+            RoundingStrategy::MidpointTowardPositiveInfinity,
+            "-11.111111111111111111111111111",
+        ),
+        (
+            "11.111111111111111111111111110000000000000000000001",
+            RoundingStrategy::ToOdd,
+            "11.111111111111111111111111111",
+        ),
+        (
+            "11.11111111111111111111111111300",
+            RoundingStrategy::ToOdd,
+            "11.111111111111111111111111113",
+        ),
+        (
+            "11.11111111111111111111111111501",
+            RoundingStrategy::ZeroFiveUp,
+            "11.111111111111111111111111116",
+        ),
+        (
+            "11.11111111111111111111111111401",
+            RoundingStrategy::ZeroFiveUp,
+            "11.111111111111111111111111114",
+        ),
+        (
+            "99.9999999999999999999999999995",
+            RoundingStrategy::MidpointNearestEven,
+            "100.00000000000000000000000000",
+        ),
+        (
+            "1_000.000_000_000_000_000_000_000_000_1",
+            RoundingStrategy::AwayFromZero,
+            "1000.0000000000000000000000001",
+        ),
+        (
+            ".00000000000000000000000000005",
+            RoundingStrategy::MidpointAwayFromZero,
+            "0.0000000000000000000000000001",
+        ),
+        (
+            "0.00000000000000000000000000000000000000000000000000000000001",
+            RoundingStrategy::AwayFromZero,
+            "0.0000000000000000000000000001",
+        ),
+        ("1.5", RoundingStrategy::ToZero, "1.5"),
+    ];
+    for &(value, strategy, expected) in tests {
+        let actual = Decimal::from_str_with_strategy(value, strategy).map(|d| d.to_string());
+        assert_eq!(Ok(expected.to_string()), actual, "{value} parsed with {strategy:?}");
+    }
+
+    // Rounding midpoints away from zero is consistent with `from_str`
+    for value in [
+        "1.234567890123456789012345678949999",
+        "8097370036018690744.2590371159596744091",
+        "-11.1111111111111111111111111195",
+        ".00000000000000000000000000001",
+    ] {
+        assert_eq!(
+            Decimal::from_str(value),
+            Decimal::from_str_with_strategy(value, RoundingStrategy::MidpointAwayFromZero),
+            "{value}"
+        );
+    }
+
+    let tests = &[
+        ("", Error::EmptyData),
+        ("1.00000000000000000000000000001.0", Error::DuplicatedDecimalPoint),
+        ("1.00000000000000000000000000001x", Error::InvalidCharacter),
+        ("79228162514264337593543950335.5", Error::ExceedsMaximumPossibleValue),
+    ];
+    for &(value, ref expected) in tests {
+        assert_eq!(
+            Err(expected.clone()),
+            Decimal::from_str_with_strategy(value, RoundingStrategy::AwayFromZero),
+            "{value}"
+        );
+    }
+}
+
 #[test]
 fn it_can_parse_alternative_formats() {
     let tests = &[
@@ -3271,6 +4237,194 @@ fn it_can_parse_alternative_formats() {
     }
 }
 
+#[test]
+fn it_can_parse_with_options() {
+    use rust_decimal::ParseOptions;
+
+    let default = ParseOptions::new();
+    let english = ParseOptions::new().with_grouping_separator(',');
+    let german = ParseOptions::new()
+        .with_decimal_separator(',')
+        .with_grouping_separator('.')
+        .with_grouping_separator(' ');
+    let scientific = ParseOptions::new().with_exponent(true);
+    let ledger = ParseOptions::new().with_trailing_minus(true).with_trim_whitespace(true);
+    let strict = ParseOptions::new().with_plus_sign(false).with_underscores(false);
+    let exact = ParseOptions::new().with_rounding(None);
+    let truncate = english.with_rounding(Some(RoundingStrategy::ToZero));
+    let tests = [
+        ("1234.56", default, Ok("1234.56")),
+        ("1,234.56", default, Err(Error::InvalidCharacter)),
+        ("1,234.56", english, Ok("1234.56")),
+        ("-1,234,567", english, Ok("-1234567")),
+        (",123", english, Err(Error::InvalidLeadingChar)),
+        ("1,234.5,6", english, Ok("1234.56")),
+        ("1.234,56", german, Ok("1234.56")),
+        ("1 234 567,891", german, Ok("1234567.891")),
+        ("1,2,3", german, Err(Error::DuplicatedDecimalPoint)),
+        ("1.5e3", default, Err(Error::InvalidCharacter)),
+        ("1.5e3", scientific, Ok("1500")),
+        ("-1_500E-3", scientific, Ok("-1.500")),
+        ("2.5e+2", scientific, Ok("250")),
+        ("1e-28", scientific, Ok("0.0000000000000000000000000001")),
+        ("5e-29", scientific, Ok("0.0000000000000000000000000001")),
+        ("1e-1000000000", scientific, Ok("0.0000000000000000000000000000")),
+        ("0e1000000000", scientific, Ok("0")),
+        (
+            "7.9228162514264337593543950335e28",
+            scientific,
+            Ok("79228162514264337593543950335"),
+        ),
+        ("1e29", scientific, Err(Error::ExceedsMaximumPossibleValue)),
+        ("1e", scientific, Err(Error::FailedToParseScientificFromString)),
+        ("1e-", scientific, Err(Error::FailedToParseScientificFromString)),
+        ("1e2.5", scientific, Err(Error::FailedToParseScientificFromString)),
+        ("e2", scientific, Err(Error::InvalidCharacter)),
+        // Mantissas longer than 29 digits must still honour the exponent
+        (
+            "1234567890123456789012345678901e-3",
+            scientific,
+            Ok("1234567890123456789012345678.9"),
+        ),
+        (
+            "0.6270024090000100566700009075001e28",
+            scientific,
+            Ok("6270024090000100566700009075.0"),
+        ),
+        (
+            "0.6270024090000100566700009075001e36",
+            scientific,
+            Err(Error::ExceedsMaximumPossibleValue),
+        ),
+        (" 1234.56- ", ledger, Ok("-1234.56")),
+        ("1234.56-", ledger, Ok("-1234.56")),
+        ("\t-12\n", ledger, Ok("-12")),
+        ("-12-", ledger, Err(Error::InvalidCharacter)),
+        (
+            "0.1234567890123456789012345678901-",
+            ledger,
+            Ok("-0.1234567890123456789012345679"),
+        ),
+        (" 12", default, Err(Error::InvalidCharacter)),
+        ("   ", ledger, Err(Error::EmptyData)),
+        ("-", ledger, Err(Error::NoDigits)),
+        ("+1", default, Ok("1")),
+        ("+1", strict, Err(Error::InvalidCharacter)),
+        ("-1", strict, Ok("-1")),
+        ("1_000", strict, Err(Error::InvalidCharacter)),
+        ("0.00000000000000000000000000001", exact, Err(Error::Underflow)),
+        (
+            "0.0000000000000000000000000001",
+            exact,
+            Ok("0.0000000000000000000000000001"),
+        ),
+        ("1,234.56789", truncate, Ok("1234.56789")),
+        (
+            "1,111,111,111,111,111,111.111111111119",
+            truncate,
+            Ok("1111111111111111111.1111111111"),
+        ),
+        (
+            "1,111,111,111,111,111,111.111111111119",
+            english,
+            Ok("1111111111111111111.1111111111"),
+        ),
+        (
+            "1,111,111,111,111,111,111.111111111159",
+            english,
+            Ok("1111111111111111111.1111111112"),
+        ),
+        (
+            "123,456,789,012,345,678,901,234,567,890,123,456,789e-20",
+            english.with_exponent(true),
+            Ok("1234567890123456789.0123456789"),
+        ),
+        (
+            "79,228,162,514,264,337,593,543,950,336",
+            english,
+            Err(Error::ExceedsMaximumPossibleValue),
+        ),
+    ];
+    for (value, options, expected) in tests {
+        let actual = Decimal::parse_with(value, &options).map(|d| d.to_string());
+        assert_eq!(actual, expected.map(String::from), "{value:?} parsed with {options:?}");
+    }
+}
+
+#[test]
+fn it_parses_with_default_options_consistently_with_from_str() {
+    use rust_decimal::ParseOptions;
+
+    // Strings using a grouping separator always go through the general parser, while strings
+    // without one are read by the `FromStr` parser even when the options allow more
+    let default = ParseOptions::new();
+    let general = default.with_grouping_separator('\'');
+    let scientific = default.with_exponent(true);
+    let strategy = RoundingStrategy::MidpointNearestEven;
+    for value in [
+        "0",
+        "-0",
+        "+1.5",
+        ".5",
+        "5.",
+        "1_000.000_1",
+        "123456789.123456789",
+        "79228162514264337593543950335",
+        "-79228162514264337593543950335",
+        "79228162514264337593543950336",
+        "79228162514264337593543950335.5",
+        "7.92281625142643375935439503355",
+        "1.234567890123456789012345678949999",
+        "0.00000000000000000000000000005",
+        "0.000000000000000000000000000025",
+        "0.00000000000000000000000000000000000000000000000000000000001",
+        "8097370036018690744.2590371159596744091",
+        "",
+        "-",
+        ".",
+        "_1",
+        "1..5",
+        "1.5.",
+        "1-",
+        "x",
+        "1.2x",
+    ] {
+        let grouped = match value.find(|c: char| c.is_ascii_digit()) {
+            Some(i) => format!("{}'{}", &value[..=i], &value[i + 1..]),
+            None => value.to_string(),
+        };
+
+        let from_str = Decimal::from_str(value);
+        assert_eq!(Decimal::parse_with(value, &default), from_str, "{value:?}");
+        assert_eq!(Decimal::parse_with(value, &scientific), from_str, "{value:?}");
+        assert_eq!(Decimal::parse_with(&grouped, &general), from_str, "{grouped:?}");
+
+        let exact = Decimal::from_str_exact(value);
+        assert_eq!(
+            Decimal::parse_with(value, &default.with_rounding(None)),
+            exact,
+            "{value:?}"
+        );
+        assert_eq!(
+            Decimal::parse_with(&grouped, &general.with_rounding(None)),
+            exact,
+            "{grouped:?}"
+        );
+
+        let rounded = Decimal::from_str_with_strategy(value, strategy);
+        let options = default.with_rounding(Some(strategy));
+        assert_eq!(Decimal::parse_with(value, &options), rounded, "{value:?}");
+        let options = general.with_rounding(Some(strategy));
+        assert_eq!(Decimal::parse_with(&grouped, &options), rounded, "{grouped:?}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "Separator must be an ASCII character")]
+fn it_panics_when_parse_options_use_a_non_ascii_separator() {
+    let _ = rust_decimal::ParseOptions::new().with_grouping_separator('\u{202f}');
+}
+
 #[test]
 fn it_can_parse_fractional_numbers_with_underscore_separators() {
     let a = Decimal::from_str("0.1_23_456").unwrap();
@@ -3428,6 +4582,7 @@ fn it_errors_parsing_large_scientific_notation_rounded() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_unwrap)]
 fn it_can_parse_different_radix() {
     let tests = &[
         // Input, Radix, Success, to_string()
@@ -3777,9 +4932,2620 @@ fn test_is_integer() {
     }
 }
 
+// Arithmetic context
+
+#[test]
+fn it_can_apply_context_arithmetic() {
+    use rust_decimal::Context;
+
+    type Op = fn(&mut Context, Decimal, Decimal) -> rust_decimal::Result<Decimal>;
+    let tests: &[(&str, Op, &str, &str, u32, RoundingStrategy, &str)] = &[
+        (
+            "add",
+            Context::add,
+            "1.10",
+            "2.20",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "3.30",
+        ),
+        (
+            "add",
+            Context::add,
+            "0.00",
+            "1",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "1.00",
+        ),
+        (
+            "add",
+            Context::add,
+            "123.45",
+            "0.005",
+            5,
+            RoundingStrategy::MidpointNearestEven,
+            "123.46",
+        ),
+        (
+            "add",
+            Context::add,
+            "123.45",
+            "0.005",
+            5,
+            RoundingStrategy::ToZero,
+            "123.45",
+        ),
+        (
+            "add",
+            Context::add,
+            "-123.45",
+            "-0.005",
+            5,
+            RoundingStrategy::ToNegativeInfinity,
+            "-123.46",
+        ),
+        (
+            "add",
+            Context::add,
+            "79228162514264337593543950335",
+            "-0.5",
+            29,
+            RoundingStrategy::MidpointNearestEven,
+            "79228162514264337593543950334",
+        ),
+        (
+            "add",
+            Context::add,
+            "1",
+            "0.0000000000000000000000000005",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "1.000000000000000000000000000",
+        ),
+        (
+            "sub",
+            Context::sub,
+            "10",
+            "0.001",
+            3,
+            RoundingStrategy::MidpointNearestEven,
+            "10.0",
+        ),
+        (
+            "sub",
+            Context::sub,
+            "10",
+            "0.001",
+            3,
+            RoundingStrategy::ToPositiveInfinity,
+            "10.0",
+        ),
+        (
+            "sub",
+            Context::sub,
+            "10",
+            "0.001",
+            3,
+            RoundingStrategy::ToNegativeInfinity,
+            "9.99",
+        ),
+        (
+            "mul",
+            Context::mul,
+            "1.25",
+            "2",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "2.50",
+        ),
+        (
+            "mul",
+            Context::mul,
+            "1.25",
+            "1.25",
+            2,
+            RoundingStrategy::MidpointNearestEven,
+            "1.6",
+        ),
+        (
+            "mul",
+            Context::mul,
+            "1.25",
+            "1.25",
+            2,
+            RoundingStrategy::AwayFromZero,
+            "1.6",
+        ),
+        ("mul", Context::mul, "1.25", "1.25", 2, RoundingStrategy::ToZero, "1.5"),
+        (
+            "mul",
+            Context::mul,
+            "12345",
+            "10",
+            3,
+            RoundingStrategy::MidpointNearestEven,
+            "123000",
+        ),
+        (
+            "mul",
+            Context::mul,
+            "0.0000000000000001",
+            "0.0000000000000015",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "0.0000000000000000000000000000",
+        ),
+        (
+            "mul",
+            Context::mul,
+            "0.0000000000000001",
+            "0.0000000000000015",
+            28,
+            RoundingStrategy::MidpointAwayFromZero,
+            "0.0000000000000000000000000000",
+        ),
+        (
+            "div",
+            Context::div,
+            "1",
+            "4",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "0.25",
+        ),
+        (
+            "div",
+            Context::div,
+            "1.00",
+            "4",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "0.25",
+        ),
+        (
+            "div",
+            Context::div,
+            "2",
+            "3",
+            5,
+            RoundingStrategy::MidpointNearestEven,
+            "0.66667",
+        ),
+        ("div", Context::div, "2", "3", 5, RoundingStrategy::ToZero, "0.66666"),
+        (
+            "div",
+            Context::div,
+            "-2",
+            "3",
+            5,
+            RoundingStrategy::ToPositiveInfinity,
+            "-0.66666",
+        ),
+        (
+            "div",
+            Context::div,
+            "-2",
+            "3",
+            5,
+            RoundingStrategy::ToNegativeInfinity,
+            "-0.66667",
+        ),
+        (
+            "div",
+            Context::div,
+            "1",
+            "3",
+            29,
+            RoundingStrategy::MidpointNearestEven,
+            "0.3333333333333333333333333333",
+        ),
+        (
+            "div",
+            Context::div,
+            "2",
+            "3",
+            29,
+            RoundingStrategy::MidpointNearestEven,
+            "0.6666666666666666666666666667",
+        ),
+        (
+            "div",
+            Context::div,
+            "79228162514264337593543950335",
+            "3",
+            29,
+            RoundingStrategy::MidpointNearestEven,
+            "26409387504754779197847983445",
+        ),
+        (
+            "div",
+            Context::div,
+            "1",
+            "0.0000000000000000000000000001",
+            29,
+            RoundingStrategy::MidpointNearestEven,
+            "10000000000000000000000000000",
+        ),
+        (
+            "div",
+            Context::div,
+            "1000",
+            "8",
+            2,
+            RoundingStrategy::MidpointNearestEven,
+            "120",
+        ),
+        (
+            "div",
+            Context::div,
+            "1000",
+            "8",
+            2,
+            RoundingStrategy::MidpointAwayFromZero,
+            "130",
+        ),
+        (
+            "rem",
+            Context::rem,
+            "10",
+            "3",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "1",
+        ),
+        (
+            "rem",
+            Context::rem,
+            "-10.5",
+            "3",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "-1.5",
+        ),
+        (
+            "rem",
+            Context::rem,
+            "12345.678",
+            "100000",
+            4,
+            RoundingStrategy::ToZero,
+            "12340",
+        ),
+    ];
+    for &(name, op, a, b, precision, rounding, expected) in tests {
+        let mut ctx = Context::new(precision, rounding);
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = op(&mut ctx, a, b).unwrap();
+        assert_eq!(
+            expected,
+            result.to_string(),
+            "{name}({a}, {b}) with precision {precision} and {rounding:?}"
+        );
+    }
+}
+
+#[test]
+fn it_records_context_status_flags() {
+    use rust_decimal::{Context, Signal, Signals};
+
+    let tests: &[(&str, &str, &str, u32, &[Signal])] = &[
+        ("add", "1.10", "2.20", 28, &[]),
+        ("add", "1.10", "2.20", 2, &[Signal::Rounded]),
+        ("add", "1.10", "2.21", 2, &[Signal::Rounded, Signal::Inexact]),
+        (
+            "mul",
+            "12345",
+            "10",
+            3,
+            &[Signal::Rounded, Signal::Inexact, Signal::Clamped],
+        ),
+        ("mul", "12000", "10", 3, &[Signal::Rounded, Signal::Clamped]),
+        (
+            "mul",
+            "0.0000000000000001",
+            "0.00000000000001",
+            28,
+            &[Signal::Rounded, Signal::Inexact],
+        ),
+        ("mul", "0.0000000000000010", "0.0000000000010", 28, &[Signal::Rounded]),
+        ("div", "1", "4", 28, &[]),
+        ("div", "1", "3", 28, &[Signal::Rounded, Signal::Inexact]),
+        ("div", "1", "0", 28, &[Signal::DivisionByZero]),
+        ("div", "79228162514264337593543950335", "0.1", 28, &[Signal::Overflow]),
+        ("add", "79228162514264337593543950335", "1", 28, &[Signal::Overflow]),
+        ("rem", "1", "0", 28, &[Signal::DivisionByZero]),
+    ];
+    for &(op, a, b, precision, expected) in tests {
+        let mut ctx = Context::new(precision, RoundingStrategy::MidpointNearestEven);
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let _ = match op {
+            "add" => ctx.add(a, b),
+            "mul" => ctx.mul(a, b),
+            "div" => ctx.div(a, b),
+            "rem" => ctx.rem(a, b),
+            _ => unreachable!(),
+        };
+        let expected = expected.iter().fold(Signals::new(), |acc, signal| acc | *signal);
+        assert_eq!(expected, ctx.status(), "{op}({a}, {b}) with precision {precision}");
+    }
+}
+
+#[test]
+fn it_accumulates_context_status_flags() {
+    use rust_decimal::{Context, Signal};
+
+    let mut ctx = Context::default();
+    assert_eq!(ctx.precision(), Context::MAX_PRECISION);
+    assert_eq!(ctx.rounding(), RoundingStrategy::MidpointNearestEven);
+
+    let third = ctx.div(Decimal::ONE, Decimal::from(3)).unwrap();
+    assert!(ctx.status().contains(Signal::Inexact));
+    // Flags are sticky until explicitly cleared
+    let _ = ctx.add(Decimal::ONE, Decimal::ONE).unwrap();
+    assert!(ctx.status().contains(Signal::Inexact));
+    assert_eq!(ctx.mul(third, Decimal::ZERO).unwrap(), Decimal::ZERO);
+    assert!(!ctx.status().contains(Signal::Overflow));
+    assert!(ctx.mul(Decimal::MAX, Decimal::TWO).is_err());
+    assert!(ctx.status().contains(Signal::Overflow));
+
+    ctx.clear_status();
+    assert!(ctx.status().is_empty());
+}
+
+#[test]
+fn it_returns_errors_for_trapped_context_signals() {
+    use rust_decimal::{Context, Signal};
+
+    let mut ctx = Context::new(4, RoundingStrategy::MidpointNearestEven).with_traps(Signal::Inexact.into());
+    assert_eq!(ctx.add(Decimal::ONE, Decimal::new(1, 3)), Ok(Decimal::new(1001, 3)));
+    assert_eq!(
+        ctx.add(Decimal::ONE, Decimal::new(1, 4)),
+        Err(Error::Trapped(Signal::Inexact))
+    );
+    assert!(ctx.status().contains(Signal::Inexact));
+    // Digits that are discarded without losing any information only raise Rounded
+    assert_eq!(ctx.round(Decimal::new(1_00000, 5)), Ok(Decimal::new(1000, 3)));
+
+    ctx.set_traps(Signal::Rounded | Signal::Clamped);
+    assert_eq!(
+        ctx.round(Decimal::new(1_00000, 5)),
+        Err(Error::Trapped(Signal::Rounded))
+    );
+
+    // Overflow and division by zero always error since they can't be represented
+    let mut ctx = Context::default();
+    assert_eq!(
+        ctx.mul(Decimal::MAX, Decimal::TWO),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        ctx.mul(Decimal::MIN, Decimal::TWO),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+    assert_eq!(ctx.div(Decimal::ONE, Decimal::ZERO), Err(Error::DivisionByZero));
+}
+
+#[test]
+fn it_clamps_context_precision() {
+    use rust_decimal::Context;
+
+    assert_eq!(Context::new(0, RoundingStrategy::ToZero).precision(), 1);
+    assert_eq!(
+        Context::new(100, RoundingStrategy::ToZero).precision(),
+        Context::MAX_PRECISION
+    );
+    let mut ctx = Context::new(10, RoundingStrategy::ToZero);
+    ctx.set_precision(50);
+    assert_eq!(ctx.precision(), Context::MAX_PRECISION);
+}
+
+// Allocation
+
+#[test]
+fn it_can_allocate_by_ratios() {
+    use rust_decimal::AllocationStrategy;
+
+    type TestCase<'a> = (&'a str, &'a [&'a str], u32, AllocationStrategy, Option<&'a [&'a str]>);
+    let tests: &[TestCase] = &[
+        (
+            "100",
+            &["1", "1", "1"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            Some(&["33.34", "33.33", "33.33"]),
+        ),
+        (
+            "100",
+            &["1", "1", "1"],
+            2,
+            AllocationStrategy::LargestRemainderFromLast,
+            Some(&["33.33", "33.33", "33.34"]),
+        ),
+        (
+            "100",
+            &["1", "1", "1"],
+            2,
+            AllocationStrategy::FromLast,
+            Some(&["33.33", "33.33", "33.34"]),
+        ),
+        (
+            "100",
+            &["1", "1", "1"],
+            0,
+            AllocationStrategy::LargestRemainder,
+            Some(&["34", "33", "33"]),
+        ),
+        (
+            "-100",
+            &["1", "1", "1"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            Some(&["-33.34", "-33.33", "-33.33"]),
+        ),
+        (
+            "0.05",
+            &["0.3", "0.7"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            Some(&["0.02", "0.03"]),
+        ),
+        (
+            "0.10",
+            &["0.12", "0.88"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            Some(&["0.01", "0.09"]),
+        ),
+        (
+            "0.10",
+            &["0.12", "0.88"],
+            2,
+            AllocationStrategy::FromFirst,
+            Some(&["0.02", "0.08"]),
+        ),
+        (
+            "10",
+            &["1", "2", "3", "4"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            Some(&["1.00", "2.00", "3.00", "4.00"]),
+        ),
+        (
+            "10",
+            &["0.2", "3", "0", "1.8"],
+            1,
+            AllocationStrategy::LargestRemainder,
+            Some(&["0.4", "6.0", "0.0", "3.6"]),
+        ),
+        (
+            "1",
+            &["1", "1", "1", "1", "1", "1"],
+            1,
+            AllocationStrategy::LargestRemainder,
+            Some(&["0.2", "0.2", "0.2", "0.2", "0.1", "0.1"]),
+        ),
+        (
+            "1",
+            &["1", "1", "1", "1", "1", "1"],
+            1,
+            AllocationStrategy::FromLast,
+            Some(&["0.1", "0.1", "0.2", "0.2", "0.2", "0.2"]),
+        ),
+        (
+            "0",
+            &["1", "2"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            Some(&["0.00", "0.00"]),
+        ),
+        (
+            "79228162514264337593543950335",
+            &["1", "1", "1"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            Some(&[
+                "26409387504754779197847983445",
+                "26409387504754779197847983445",
+                "26409387504754779197847983445",
+            ]),
+        ),
+        (
+            "79228162514264337593543950335",
+            &["1", "1"],
+            2,
+            AllocationStrategy::LargestRemainder,
+            None,
+        ),
+        ("1.005", &["1", "1"], 2, AllocationStrategy::LargestRemainder, None),
+        ("1", &[], 2, AllocationStrategy::LargestRemainder, None),
+        ("1", &["0", "0"], 2, AllocationStrategy::LargestRemainder, None),
+        ("1", &["1", "-1"], 2, AllocationStrategy::LargestRemainder, None),
+    ];
+    for &(amount, ratios, dp, strategy, expected) in tests {
+        let amount = Decimal::from_str(amount).unwrap();
+        let ratios: Vec<Decimal> = ratios.iter().map(|r| Decimal::from_str(r).unwrap()).collect();
+        let parts = amount.allocate_with_strategy(&ratios, dp, strategy);
+        let actual = parts
+            .as_ref()
+            .map(|parts| parts.iter().map(|p| p.to_string()).collect::<Vec<_>>());
+        let expected = expected.map(|e| e.iter().map(|p| p.to_string()).collect::<Vec<_>>());
+        assert_eq!(expected, actual, "{amount} by {ratios:?} to {dp} dp with {strategy:?}");
+        if let Some(parts) = parts {
+            assert_eq!(amount, parts.iter().sum::<Decimal>());
+        }
+    }
+}
+
+#[test]
+fn it_can_split_evenly() {
+    let amount = Decimal::from_str("100.00").unwrap();
+    let parts = amount.split_evenly(7, 2);
+    assert_eq!(
+        ["14.29", "14.29", "14.29", "14.29", "14.28", "14.28", "14.28"],
+        parts.iter().map(|p| p.to_string()).collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(amount, parts.iter().sum::<Decimal>());
+    assert_eq!(vec![Decimal::ONE], Decimal::ONE.split_evenly(1, 0));
+}
+
+#[test]
+#[should_panic(expected = "Unable to allocate")]
+fn it_panics_when_splitting_into_zero_parts() {
+    let _ = Decimal::ONE.split_evenly(0, 2);
+}
+
+// Checked arithmetic
+
+#[test]
+fn it_can_chain_checked_operations() {
+    use rust_decimal::Checked;
+
+    let a = Decimal::from_str("12.50").unwrap();
+    let b = Decimal::from_str("0.25").unwrap();
+    let result = Checked::new(a) * Decimal::from(3) + b - Checked::new(b) / Decimal::TWO;
+    assert_eq!(result.into_result(), Ok(Decimal::from_str("37.625").unwrap()));
+    assert_eq!(
+        (Decimal::TEN % Checked::new(Decimal::from(3))).into_result(),
+        Ok(Decimal::ONE)
+    );
+    assert_eq!((-Checked::new(a)).into_result(), Ok(-a));
+
+    let mut value = Checked::new(a);
+    value += b;
+    value *= &Decimal::TWO;
+    value -= Checked::new(Decimal::ONE);
+    value /= Decimal::TWO;
+    assert_eq!(value.into_result(), Ok(Decimal::from_str("12.25").unwrap()));
+}
+
+#[test]
+fn it_propagates_the_first_checked_error() {
+    use rust_decimal::Checked;
+
+    let tests: &[(Checked<Decimal>, Error)] = &[
+        (
+            Checked::new(Decimal::MAX) + Decimal::ONE,
+            Error::ExceedsMaximumPossibleValue,
+        ),
+        (
+            Checked::new(Decimal::MIN) - Decimal::ONE,
+            Error::LessThanMinimumPossibleValue,
+        ),
+        (
+            Checked::new(Decimal::MAX) * Decimal::NEGATIVE_ONE * Decimal::TWO,
+            Error::LessThanMinimumPossibleValue,
+        ),
+        (
+            Checked::new(Decimal::MIN) * Decimal::MIN,
+            Error::ExceedsMaximumPossibleValue,
+        ),
+        (Checked::new(Decimal::ONE) / Decimal::ZERO, Error::DivisionByZero),
+        (Checked::new(Decimal::ONE) % Decimal::ZERO, Error::DivisionByZero),
+        // The first error is retained
+        (
+            (Checked::new(Decimal::ONE) / Decimal::ZERO) + Decimal::MAX * Checked::new(Decimal::TWO),
+            Error::DivisionByZero,
+        ),
+        (
+            Decimal::ONE + (Checked::new(Decimal::MAX) * Decimal::TWO) / Decimal::ZERO,
+            Error::ExceedsMaximumPossibleValue,
+        ),
+    ];
+    for (i, (checked, expected)) in tests.iter().enumerate() {
+        assert_eq!(checked.clone().into_result(), Err(expected.clone()), "Test {i}");
+    }
+}
+
+#[test]
+fn it_can_sum_and_multiply_checked_iterators() {
+    use rust_decimal::Checked;
+
+    let values = [Decimal::ONE, Decimal::TWO, Decimal::TEN];
+    assert_eq!(
+        values.iter().sum::<Checked<Decimal>>().into_result(),
+        Ok(Decimal::from(13))
+    );
+    assert_eq!(
+        values.into_iter().product::<Checked<Decimal>>().into_result(),
+        Ok(Decimal::from(20))
+    );
+
+    let values = [Decimal::MAX, Decimal::ONE, Decimal::MIN];
+    assert_eq!(
+        values.into_iter().sum::<Checked<Decimal>>().into_result(),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+}
+
+// Saturating arithmetic
+
+#[test]
+fn it_can_saturate_operators() {
+    use rust_decimal::Saturating;
+
+    let one = Saturating(Decimal::ONE);
+    let two = Saturating(Decimal::TWO);
+    let max = Saturating(Decimal::MAX);
+    let min = Saturating(Decimal::MIN);
+    assert_eq!(max + one, max);
+    assert_eq!(min - one, min);
+    assert_eq!(max * -two, min);
+    assert_eq!(min * min, max);
+    assert_eq!(max / Decimal::new(1, 1), max);
+    assert_eq!(min / Decimal::new(-1, 1), max);
+    assert_eq!(max % two, one);
+    assert_eq!(-min, max);
+    assert_eq!(one + Decimal::TWO, Saturating(Decimal::from(3)));
+    assert_eq!((max + one) - one, Saturating(Decimal::MAX - Decimal::ONE));
+
+    let mut value = Saturating(Decimal::from(100));
+    value += max;
+    assert_eq!(value, max);
+    value -= &two;
+    value *= Decimal::TWO;
+    assert_eq!(value, max);
+    value /= Decimal::NEGATIVE_ONE;
+    assert_eq!(value, min);
+    value %= Decimal::TEN;
+    assert_eq!(value, Saturating(Decimal::from(-5)));
+}
+
+#[test]
+fn it_can_sum_and_multiply_saturating_iterators() {
+    use rust_decimal::Saturating;
+
+    let values = [Decimal::MAX, Decimal::MAX, Decimal::MIN];
+    let sum: Saturating<Decimal> = values.iter().map(|v| Saturating(*v)).sum();
+    assert_eq!(sum, Saturating(Decimal::ZERO));
+    let product: Saturating<Decimal> = values.into_iter().product();
+    assert_eq!(product, Saturating(Decimal::MIN));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_saturating_division_by_zero() {
+    use rust_decimal::Saturating;
+
+    let _ = Saturating(Decimal::ONE) / Saturating(Decimal::ZERO);
+}
+
+// Fused multiply-add
+
+#[test]
+fn it_can_mul_add_with_a_single_rounding() {
+    use num_traits::{MulAdd, MulAddAssign};
+
+    let tests = [
+        ("1.5", "2", "0.25", Some("3.25")),
+        ("1.10", "2.00", "-0.2", Some("2.0000")),
+        ("0", "5.5", "1.50", Some("1.50")),
+        ("0.00", "5.5", "1", Some("1")),
+        ("-3", "4", "12", Some("0")),
+        (
+            "0.1",
+            "0.1",
+            "0.0000000000000000000000000001",
+            Some("0.0100000000000000000000000001"),
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.5",
+            "0",
+            Some("0.0000000000000000000000000000"),
+        ),
+        (
+            "0.0000000000000000000000000003",
+            "0.5",
+            "0",
+            Some("0.0000000000000000000000000002"),
+        ),
+        (
+            "1.0000000000000000000000000001",
+            "1.0000000000000000000000000001",
+            "-1",
+            Some("0.0000000000000000000000000002"),
+        ),
+        (
+            "3.3333333333333333333333333333",
+            "3",
+            "-10",
+            Some("-0.0000000000000000000000000001"),
+        ),
+        (
+            "100000000000000.000000000001",
+            "100000000000000.000000000001",
+            "-10000000000000000000000000000",
+            Some("200.000000000000000000000001"),
+        ),
+        (
+            "12345678901234.567890123456",
+            "98765432109876.543210987654",
+            "-1219326311370217952237463801",
+            Some("24386444.447340371333485751812"),
+        ),
+        (
+            "79228162514264337593543950335",
+            "1",
+            "-1",
+            Some("79228162514264337593543950334"),
+        ),
+        (
+            "79228162514264337593543950335",
+            "2",
+            "-79228162514264337593543950335",
+            Some("79228162514264337593543950335"),
+        ),
+        ("79228162514264337593543950335", "2", "0", None),
+        ("79228162514264337593543950335", "1", "1", None),
+    ];
+    for &(a, b, c, expected) in &tests {
+        let (a, b, c) = (
+            Decimal::from_str(a).unwrap(),
+            Decimal::from_str(b).unwrap(),
+            Decimal::from_str(c).unwrap(),
+        );
+        let result = a.checked_mul_add(b, c);
+        assert_eq!(result.map(|d| d.to_string()).as_deref(), expected, "{a} * {b} + {c}");
+        if let Some(result) = result {
+            assert_eq!(a.mul_add(b, c), result);
+            assert_eq!(MulAdd::mul_add(a, b, c), result);
+            let mut assigned = a;
+            MulAddAssign::mul_add_assign(&mut assigned, b, c);
+            assert_eq!(assigned, result);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Multiply-add overflowed")]
+fn it_panics_when_mul_add_overflows() {
+    let _ = Decimal::MAX.mul_add(Decimal::TWO, Decimal::ONE);
+}
+
+// Exact summation
+
+#[test]
+fn it_can_sum_slices_exactly() {
+    const MAX: &str = "79228162514264337593543950335";
+    const MIN: &str = "-79228162514264337593543950335";
+    let tests: &[(&[&str], Result<&str, Error>)] = &[
+        (&[], Ok("0")),
+        (&["1.1", "2.22", "3.333"], Ok("6.653")),
+        (&["0.1"; 10], Ok("1.0")),
+        (&["1", "-1"], Ok("0")),
+        (&["0.00", "1.5"], Ok("1.50")),
+        (
+            &["0.3333333333333333333333333333"; 3],
+            Ok("0.9999999999999999999999999999"),
+        ),
+        (
+            &["10000000000000000000000000000", "0.4", "0.4"],
+            Ok("10000000000000000000000000001"),
+        ),
+        (
+            &["10000000000000000000000000000", "0.5"],
+            Ok("10000000000000000000000000000"),
+        ),
+        (
+            &[MAX, "0.0000000000000000000000000001", MIN],
+            Ok("0.0000000000000000000000000001"),
+        ),
+        (&[MAX, MAX, MIN], Ok(MAX)),
+        (&[MAX, "1"], Err(Error::ExceedsMaximumPossibleValue)),
+        (&[MIN, "-1"], Err(Error::LessThanMinimumPossibleValue)),
+    ];
+    for (values, expected) in tests {
+        let values: Vec<Decimal> = values.iter().map(|v| Decimal::from_str(v).unwrap()).collect();
+        let result = Decimal::sum_exact(&values).map(|d| d.to_string());
+        assert_eq!(result, expected.clone().map(String::from), "Sum of {values:?}");
+    }
+
+    // Folding with `+` rounds the running total at each step
+    let values = ["10000000000000000000000000000", "0.4", "0.4"].map(|v| Decimal::from_str(v).unwrap());
+    assert_eq!(
+        values.iter().sum::<Decimal>().to_string(),
+        "10000000000000000000000000000"
+    );
+}
+
+#[test]
+fn it_can_calculate_dot_products_exactly() {
+    const MAX: &str = "79228162514264337593543950335";
+    let tests = [
+        (vec![], Some("0")),
+        (
+            vec![("150", "12.345"), ("20.5", "99.99"), ("-3", "0.5")],
+            Some("3900.045"),
+        ),
+        (vec![("0.00", "3"), ("1.5", "2")], Some("3.0")),
+        (vec![("0.1", "0.1"); 3], Some("0.03")),
+        (
+            vec![("0.0000000000000000000000000001", "0.5"); 2],
+            Some("0.0000000000000000000000000001"),
+        ),
+        (
+            vec![
+                ("100000000000000.000000000001", "100000000000000.000000000001"),
+                ("-10000000000000000000000000000", "1"),
+            ],
+            Some("200.000000000000000000000001"),
+        ),
+        (vec![(MAX, "1"), (MAX, "-1")], Some("0")),
+        (vec![(MAX, "2"), ("1", "-79228162514264337593543950335")], Some(MAX)),
+        (vec![(MAX, "2")], None),
+    ];
+    for (pairs, expected) in &tests {
+        let (a, b): (Vec<Decimal>, Vec<Decimal>) = pairs
+            .iter()
+            .map(|(a, b)| (Decimal::from_str(a).unwrap(), Decimal::from_str(b).unwrap()))
+            .unzip();
+        let result = Decimal::dot(&a, &b).ok().map(|d| d.to_string());
+        assert_eq!(result.as_deref(), *expected, "Dot product of {pairs:?}");
+    }
+
+    let max = Decimal::MAX;
+    assert_eq!(
+        Decimal::dot(&[max], &[Decimal::TWO]),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal::dot(&[max], &[-Decimal::TWO]),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Dot product of slices with different lengths")]
+fn it_panics_when_dot_product_lengths_differ() {
+    let _ = Decimal::dot(&[Decimal::ONE, Decimal::TWO], &[Decimal::ONE]);
+}
+
+// Const evaluation
+
+#[test]
+fn it_can_evaluate_arithmetic_in_const_context() {
+    const BASE: Decimal = Decimal::from_parts(125, 0, 0, false, 2);
+    const RATE: Decimal = Decimal::from_parts(35, 0, 0, false, 4);
+    const FEE: Decimal = BASE.checked_mul(RATE).unwrap();
+    const TIERS: [Decimal; 3] = [
+        FEE,
+        FEE.checked_add(Decimal::ONE).unwrap(),
+        FEE.checked_mul(Decimal::TWO).unwrap().checked_sub(RATE).unwrap(),
+    ];
+    const THIRD: Decimal = Decimal::ONE
+        .checked_div(Decimal::from_parts(3, 0, 0, false, 0))
+        .unwrap();
+    const REMAINDER: Option<Decimal> = Decimal::TEN.checked_rem(Decimal::from_parts(3, 0, 0, false, 0));
+    const OVERFLOW: Option<Decimal> = Decimal::MAX.checked_add(Decimal::ONE);
+    const SATURATED: Decimal = Decimal::MAX.saturating_mul(Decimal::TWO);
+    const ROUNDED: Decimal = THIRD.round_dp_with_strategy(4, RoundingStrategy::AwayFromZero);
+    const LARGEST: Decimal = FEE.max(BASE).min(Decimal::TEN);
+    const ORDER: core::cmp::Ordering = FEE.cmp(&BASE);
+    const NORMALIZED: Decimal = Decimal::from_parts(1500, 0, 0, false, 3).normalize();
+    const RESCALED: Decimal = {
+        let mut value = FEE;
+        value.rescale(2);
+        value
+    };
+
+    // Each constant must match the same calculation performed at run time
+    let (base, rate) = (Decimal::from_str("1.25").unwrap(), Decimal::from_str("0.0035").unwrap());
+    let fee = base * rate;
+    assert_eq!(FEE.to_string(), "0.004375");
+    assert_eq!(TIERS, [fee, fee + Decimal::ONE, fee * Decimal::TWO - rate]);
+    assert_eq!(THIRD, Decimal::ONE / Decimal::from(3));
+    assert_eq!(REMAINDER, Some(Decimal::ONE));
+    assert_eq!(OVERFLOW, None);
+    assert_eq!(SATURATED, Decimal::MAX);
+    assert_eq!(ROUNDED.to_string(), "0.3334");
+    assert_eq!(LARGEST, base);
+    assert_eq!(ORDER, Less);
+    assert_eq!(NORMALIZED.to_string(), "1.5");
+    assert_eq!(RESCALED.to_string(), "0.00");
+}
+
+#[test]
+fn it_can_parse_strings_in_const_context() {
+    const RATE: Decimal = Decimal::from_str_const("0.0035");
+    const BIG: Decimal = Decimal::from_str_const("1_000_000.5e3");
+    const INVALID: Result<Decimal, Error> = Decimal::try_from_str_const("1..5");
+    // A large exponent on zero must not take a step per power of ten
+    const ZERO: Decimal = Decimal::from_str_const("0e999999");
+    assert_eq!(RATE, Decimal::from_str("0.0035").unwrap());
+    assert_eq!(BIG.to_string(), "1000000500");
+    assert_eq!(ZERO.to_string(), "0");
+    assert_eq!(INVALID, Err(Error::DuplicatedDecimalPoint));
+
+    // Without an exponent the result matches `from_str`, including rounding of excess digits
+    let tests = [
+        "0",
+        "-0",
+        "+1.5",
+        "-12.345",
+        ".5",
+        "5.",
+        "1_000_000",
+        "0.00000_00000_00000_00000_00000_001",
+        "0.00000_00000_00000_00000_00000_0015",
+        "0.00000_00000_00000_00000_00000_0014",
+        "3.14159265358979323846264338327950288",
+        "79228162514264337593543950335",
+        "-79228162514264337593543950335",
+        "7.9228162514264337593543950335",
+        "7.92281625142643375935439503355",
+        "0.00000000000000000000000000000000000009",
+    ];
+    for input in tests {
+        assert_eq!(
+            Decimal::try_from_str_const(input),
+            Decimal::from_str(input),
+            "Parsing {input}"
+        );
+    }
+
+    let tests = [
+        ("1e0", Ok("1")),
+        ("1.5E3", Ok("1500")),
+        ("-2.5e-3", Ok("-0.0025")),
+        ("12_345e-2", Ok("123.45")),
+        ("1e+2", Ok("100")),
+        ("0e1000000000", Ok("0")),
+        ("0e999999", Ok("0")),
+        ("0.000e999999", Ok("0")),
+        ("1e-28", Ok("0.0000000000000000000000000001")),
+        ("5e-29", Ok("0.0000000000000000000000000001")),
+        ("4e-29", Ok("0.0000000000000000000000000000")),
+        ("1e-30", Ok("0.0000000000000000000000000000")),
+        (
+            "0.12345678901234567890123456789e2",
+            Ok("12.345678901234567890123456789"),
+        ),
+        ("7.9228162514264337593543950335e28", Ok("79228162514264337593543950335")),
+        (
+            "7.9228162514264337593543950336e28",
+            Err(Error::ExceedsMaximumPossibleValue),
+        ),
+        ("1e29", Err(Error::ExceedsMaximumPossibleValue)),
+        (
+            "79228162514264337593543950335.5",
+            Err(Error::ExceedsMaximumPossibleValue),
+        ),
+        ("", Err(Error::EmptyData)),
+        ("-", Err(Error::NoDigits)),
+        ("_1", Err(Error::InvalidLeadingChar)),
+        ("1.2.3", Err(Error::DuplicatedDecimalPoint)),
+        ("1-2", Err(Error::InvalidCharacter)),
+        ("e5", Err(Error::InvalidCharacter)),
+        ("1e", Err(Error::FailedToParseScientificFromString)),
+        ("1e-", Err(Error::FailedToParseScientificFromString)),
+        ("1e5.0", Err(Error::FailedToParseScientificFromString)),
+    ];
+    for (input, expected) in tests {
+        let result = Decimal::try_from_str_const(input).map(|d| d.to_string());
+        assert_eq!(result, expected.map(String::from), "Parsing {input}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "Failed to parse decimal: invalid character")]
+fn it_panics_when_parsing_invalid_strings_in_const_fn() {
+    let _ = Decimal::from_str_const("1.5x");
+}
+
+// Decimal192
+
+#[test]
+fn it_can_parse_and_format_decimal192() {
+    use rust_decimal::Decimal192;
+
+    let tests = [
+        ("0", "0"),
+        ("-0", "0"),
+        ("-1.500", "-1.500"),
+        ("+1_000.25", "1000.25"),
+        (".5", "0.5"),
+        (
+            "6277101735386680763835789423207666416102355444464034512895",
+            "6277101735386680763835789423207666416102355444464034512895",
+        ),
+        (
+            "0.123456789012345678901234567890123456789012345678901234567",
+            "0.123456789012345678901234567890123456789012345678901234567",
+        ),
+        // Digits beyond the available precision are rounded
+        (
+            "0.1234567890123456789012345678901234567890123456789012345675",
+            "0.123456789012345678901234567890123456789012345678901234568",
+        ),
+        (
+            "0.12345678901234567890123456789012345678901234567890123456650000000000000000000000000000001",
+            "0.123456789012345678901234567890123456789012345678901234567",
+        ),
+        (
+            "627710173538668076383578942320766641610235544446403451289.46",
+            "627710173538668076383578942320766641610235544446403451289.5",
+        ),
+    ];
+    for &(input, expected) in &tests {
+        let value = Decimal192::from_str(input).unwrap();
+        assert_eq!(value.to_string(), expected, "{input}");
+    }
+
+    let errors = [
+        ("", Error::EmptyData),
+        ("-", Error::NoDigits),
+        ("1.2.3", Error::DuplicatedDecimalPoint),
+        ("1e5", Error::InvalidCharacter),
+        (
+            "6277101735386680763835789423207666416102355444464034512896",
+            Error::ExceedsMaximumPossibleValue,
+        ),
+        (
+            "-6277101735386680763835789423207666416102355444464034512895.5",
+            Error::LessThanMinimumPossibleValue,
+        ),
+    ];
+    for (input, expected) in errors {
+        assert_eq!(Decimal192::from_str(input), Err(expected), "{input}");
+    }
+
+    let value = Decimal192::new(-31415, 4);
+    assert_eq!(format!("{value:.2}"), "-3.14");
+    assert_eq!(format!("{value:.6}"), "-3.141500");
+    assert_eq!(format!("{value:>10.1}"), "      -3.1");
+    assert_eq!(format!("{:.0}", Decimal192::new(5, 1)), "0");
+}
+
+#[test]
+fn it_can_perform_decimal192_arithmetic() {
+    use rust_decimal::Decimal192;
+
+    let tests = [
+        ("0.1", '+', "0.2", "0.3"),
+        (
+            "1",
+            '+',
+            "0.000000000000000000000000000000000000000000000000000000001",
+            "1.000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "6277101735386680763835789423207666416102355444464034512895",
+            '+',
+            "-1",
+            "6277101735386680763835789423207666416102355444464034512894",
+        ),
+        ("1.5", '-', "2.25", "-0.75"),
+        ("-1", '-', "-1", "0"),
+        ("1.5", '*', "1.5", "2.25"),
+        (
+            "9999999999999999999999999999",
+            '*',
+            "9999999999999999999999999999",
+            "99999999999999999999999999980000000000000000000000000001",
+        ),
+        (
+            "-0.5",
+            '*',
+            "0.000000000000000000000000000000000000000000000000000000001",
+            "0.000000000000000000000000000000000000000000000000000000000",
+        ),
+        ("1", '/', "3", "THIRD"),
+        (
+            "2",
+            '/',
+            "-3",
+            "-0.666666666666666666666666666666666666666666666666666666667",
+        ),
+        (
+            "100",
+            '/',
+            "3",
+            "33.33333333333333333333333333333333333333333333333333333333",
+        ),
+        ("10", '/', "4", "2.5"),
+        ("1.00", '/', "2", "0.50"),
+        ("1", '/', "0.25", "4"),
+        ("0.00", '/', "7", "0.00"),
+        ("10", '%', "3", "1"),
+        ("-7.5", '%', "2", "-1.5"),
+        ("7", '%', "-0.25", "0.00"),
+    ];
+    for &(a, op, b, expected) in &tests {
+        let x = Decimal192::from_str(a).unwrap();
+        let y = Decimal192::from_str(b).unwrap();
+        let result = match op {
+            '+' => x + y,
+            '-' => x - y,
+            '*' => x * y,
+            '/' => x / y,
+            '%' => x % y,
+            _ => unreachable!(),
+        };
+        let expected = if expected == "THIRD" {
+            "0.333333333333333333333333333333333333333333333333333333333"
+        } else {
+            expected
+        };
+        assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+    }
+
+    let mut value = Decimal192::from(10);
+    value += Decimal192::ONE;
+    value *= &Decimal192::new(15, 1);
+    value -= Decimal192::new(5, 1);
+    value /= Decimal192::from(4u8);
+    value %= Decimal192::from(3i128);
+    assert_eq!(value, Decimal192::from(1));
+    assert_eq!(-value, Decimal192::from(-1));
+
+    let values = [Decimal192::new(15, 1), Decimal192::from(2), Decimal192::new(-5, 1)];
+    assert_eq!(values.iter().sum::<Decimal192>(), Decimal192::from(3));
+    assert_eq!(values.into_iter().product::<Decimal192>(), Decimal192::new(-15, 1));
+}
+
+#[test]
+fn it_can_check_decimal192_overflow() {
+    use rust_decimal::Decimal192;
+
+    assert_eq!(Decimal192::MAX.checked_add(Decimal192::ONE), None);
+    assert_eq!(Decimal192::MIN.checked_sub(Decimal192::ONE), None);
+    assert_eq!(Decimal192::MAX.checked_mul(Decimal192::from(2)), None);
+    assert_eq!(Decimal192::MAX.checked_div(Decimal192::new(5, 1)), None);
+    assert_eq!(Decimal192::ONE.checked_div(Decimal192::ZERO), None);
+    assert_eq!(Decimal192::ONE.checked_rem(Decimal192::ZERO), None);
+    assert_eq!(Decimal192::MAX.checked_add(Decimal192::MIN), Some(Decimal192::ZERO));
+    assert_eq!(Decimal192::MAX.checked_div(Decimal192::MAX), Some(Decimal192::ONE));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_decimal192_division_by_zero() {
+    use rust_decimal::Decimal192;
+
+    let _ = Decimal192::ONE / Decimal192::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "Multiplication overflowed")]
+fn it_panics_when_decimal192_multiplication_overflows() {
+    use rust_decimal::Decimal192;
+
+    let _ = Decimal192::MAX * Decimal192::MAX;
+}
+
+#[test]
+fn it_can_compare_decimal192() {
+    use rust_decimal::Decimal192;
+
+    let tests = [
+        ("1", "1.000", Equal),
+        ("-0", "0.00", Equal),
+        ("-1", "0", Less),
+        ("-1.5", "-1.25", Less),
+        (
+            "0.000000000000000000000000000000000000000000000000000000001",
+            "0",
+            Greater,
+        ),
+        (
+            "6277101735386680763835789423207666416102355444464034512895",
+            "0.1",
+            Greater,
+        ),
+    ];
+    for &(a, b, expected) in &tests {
+        let a = Decimal192::from_str(a).unwrap();
+        let b = Decimal192::from_str(b).unwrap();
+        assert_eq!(a.cmp(&b), expected, "{a} <=> {b}");
+        assert_eq!(b.cmp(&a), expected.reverse(), "{b} <=> {a}");
+    }
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(Decimal192::from_str("1.50").unwrap());
+    assert!(set.contains(&Decimal192::new(15, 1)));
+}
+
+#[test]
+fn it_can_convert_between_decimal192_and_decimal() {
+    use rust_decimal::Decimal192;
+
+    for value in [Decimal::MAX, Decimal::MIN, Decimal::new(-1, 28), Decimal::new(12345, 2)] {
+        let wide = Decimal192::from(value);
+        assert_eq!(wide.to_string(), value.to_string());
+        assert_eq!(wide.to_decimal(), Some(value));
+        assert_eq!(Decimal::try_from(wide), Ok(value));
+    }
+
+    let third = Decimal192::ONE / Decimal192::from(3);
+    assert_eq!(
+        third.to_decimal(),
+        Some(Decimal::from_str("0.3333333333333333333333333333").unwrap())
+    );
+    assert_eq!(
+        third.to_decimal_with_strategy(RoundingStrategy::ToPositiveInfinity),
+        Some(Decimal::from_str("0.3333333333333333333333333334").unwrap())
+    );
+    assert_eq!(
+        (Decimal192::from(Decimal::MAX) + Decimal192::new(5, 1)).to_decimal(),
+        None
+    );
+    assert_eq!(
+        (Decimal192::from(Decimal::MAX) + Decimal192::new(49, 2)).to_decimal(),
+        Some(Decimal::MAX)
+    );
+    assert_eq!(
+        Decimal::try_from(Decimal192::MAX),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal::try_from(Decimal192::MIN),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+
+    let value = Decimal192::from_str("-2.675").unwrap();
+    assert_eq!(value.round_dp(2).to_string(), "-2.68");
+    assert_eq!(
+        value.round_dp_with_strategy(1, RoundingStrategy::ToZero).to_string(),
+        "-2.6"
+    );
+    assert_eq!(value.round_dp(5).to_string(), "-2.675");
+    assert_eq!(value.abs().normalize().to_string(), "2.675");
+}
+
+// Decimal64
+
+#[test]
+fn it_can_parse_and_format_decimal64() {
+    use rust_decimal::Decimal64;
+
+    assert_eq!(std::mem::size_of::<Decimal64>(), 8);
+    let tests = [
+        ("0", "0"),
+        ("-0.00", "0.00"),
+        ("-1.500", "-1.500"),
+        ("+1_000.25", "1000.25"),
+        ("72057594037927935", "72057594037927935"),
+        ("-0.0000000000000000000000000001", "-0.0000000000000000000000000001"),
+        // Digits beyond the available precision are rounded once
+        ("0.1234567890123456789", "0.12345678901234568"),
+        ("0.8888888888888888888", "0.8888888888888889"),
+        ("7.99999999999999999", "8.000000000000000"),
+        ("0.00000000000000000000000000015", "0.0000000000000000000000000002"),
+    ];
+    for &(input, expected) in &tests {
+        let value = Decimal64::from_str(input).unwrap();
+        assert_eq!(value.to_string(), expected, "{input}");
+    }
+
+    let errors = [
+        ("", Error::EmptyData),
+        ("1.2.3", Error::DuplicatedDecimalPoint),
+        ("abc", Error::InvalidCharacter),
+        ("72057594037927936", Error::ExceedsMaximumPossibleValue),
+        ("-72057594037927935.5", Error::LessThanMinimumPossibleValue),
+    ];
+    for (input, expected) in errors {
+        assert_eq!(Decimal64::from_str(input), Err(expected), "{input}");
+    }
+
+    for (value, precision) in [
+        (Decimal64::new(-31415, 4), 2),
+        (Decimal64::new(5, 1), 0),
+        (Decimal64::MAX, 3),
+    ] {
+        assert_eq!(
+            format!("{value:.precision$}"),
+            format!("{:.precision$}", Decimal::from(value))
+        );
+    }
+    assert_eq!(Decimal64::try_new(i64::MAX, 0), Err(Error::ExceedsMaximumPossibleValue));
+    assert_eq!(Decimal64::try_new(1, 29), Err(Error::ScaleExceedsMaximumPrecision(29)));
+}
+
+#[test]
+fn it_can_perform_decimal64_arithmetic() {
+    use rust_decimal::Decimal64;
+
+    let tests = [
+        ("0.1", '+', "0.2", "0.3"),
+        ("72057594037927935", '+', "0.4", "72057594037927935"),
+        ("72057594037927935", '-', "72057594037927935", "0"),
+        ("1.5", '-', "2.25", "-0.75"),
+        ("0.1", '*', "0.1", "0.01"),
+        ("-1.5", '*', "1.5", "-2.25"),
+        ("123456789.123", '*', "123456789.123", "15241578780560891"),
+        ("1", '/', "3", "0.33333333333333333"),
+        ("-2", '/', "3", "-0.66666666666666667"),
+        ("8", '/', "9", "0.8888888888888889"),
+        ("10", '/', "4", "2.5"),
+        ("1.00", '/', "2", "0.50"),
+        ("1", '/', "0.25", "4"),
+        ("10", '%', "3", "1"),
+        ("-7.5", '%', "2", "-1.5"),
+    ];
+    for &(a, op, b, expected) in &tests {
+        let x = Decimal64::from_str(a).unwrap();
+        let y = Decimal64::from_str(b).unwrap();
+        let result = match op {
+            '+' => x + y,
+            '-' => x - y,
+            '*' => x * y,
+            '/' => x / y,
+            '%' => x % y,
+            _ => unreachable!(),
+        };
+        assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+    }
+
+    assert_eq!(Decimal64::MAX.checked_add(Decimal64::new(5, 1)), None);
+    assert_eq!(Decimal64::MIN.checked_sub(Decimal64::ONE), None);
+    assert_eq!(Decimal64::MAX.checked_mul(Decimal64::from(2)), None);
+    assert_eq!(Decimal64::ONE.checked_div(Decimal64::ZERO), None);
+    assert_eq!(Decimal64::ONE.checked_rem(Decimal64::ZERO), None);
+
+    let mut value = Decimal64::from(10);
+    value += Decimal64::ONE;
+    value *= &Decimal64::new(15, 1);
+    value -= Decimal64::new(5, 1);
+    value /= Decimal64::from(4u8);
+    value %= Decimal64::from(3);
+    assert_eq!(value, Decimal64::ONE);
+    assert_eq!(-value, Decimal64::from(-1));
+
+    let values = [Decimal64::new(15, 1), Decimal64::from(2), Decimal64::new(-5, 1)];
+    assert_eq!(values.iter().sum::<Decimal64>(), Decimal64::from(3));
+    assert_eq!(values.into_iter().product::<Decimal64>(), Decimal64::new(-15, 1));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_decimal64_division_by_zero() {
+    use rust_decimal::Decimal64;
+
+    let _ = Decimal64::ONE / Decimal64::ZERO;
+}
+
+#[test]
+fn it_can_compare_decimal64() {
+    use rust_decimal::Decimal64;
+
+    let tests = [
+        ("1", "1.000", Equal),
+        ("-0", "0.00", Equal),
+        ("-1", "0", Less),
+        ("-1.5", "-1.25", Less),
+        ("72057594037927935", "7205759403792793.6", Greater),
+    ];
+    for &(a, b, expected) in &tests {
+        let a = Decimal64::from_str(a).unwrap();
+        let b = Decimal64::from_str(b).unwrap();
+        assert_eq!(a.cmp(&b), expected, "{a} <=> {b}");
+        assert_eq!(b.cmp(&a), expected.reverse(), "{b} <=> {a}");
+    }
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(Decimal64::from_str("1.50").unwrap());
+    assert!(set.contains(&Decimal64::new(15, 1)));
+}
+
+#[test]
+fn it_can_convert_between_decimal64_and_decimal() {
+    use rust_decimal::Decimal64;
+
+    for value in [
+        Decimal64::MAX,
+        Decimal64::MIN,
+        Decimal64::new(-1, 28),
+        Decimal64::new(12345, 2),
+    ] {
+        let wide = Decimal::from(value);
+        assert_eq!(wide.to_string(), value.to_string());
+        assert_eq!(Decimal64::try_from(wide), Ok(value));
+    }
+
+    let value = Decimal::from_str("-2.7182818284590452353602874714").unwrap();
+    let tests = [
+        (RoundingStrategy::MidpointNearestEven, "-2.7182818284590452"),
+        (RoundingStrategy::AwayFromZero, "-2.7182818284590453"),
+        (RoundingStrategy::ToPositiveInfinity, "-2.7182818284590452"),
+        (RoundingStrategy::ToNegativeInfinity, "-2.7182818284590453"),
+    ];
+    for &(strategy, expected) in &tests {
+        let narrowed = Decimal64::from_decimal_with_strategy(value, strategy).unwrap();
+        assert_eq!(narrowed.to_string(), expected, "{strategy:?}");
+    }
+    assert_eq!(
+        Decimal64::from_decimal_with_strategy(Decimal::from(72057594037927936u64), RoundingStrategy::ToZero),
+        None
+    );
+    assert_eq!(
+        Decimal64::try_from(Decimal::MAX),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal64::try_from(Decimal::MIN),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+
+    let value = Decimal64::from_str("-2.675").unwrap();
+    assert_eq!(value.round_dp(2).to_string(), "-2.68");
+    assert_eq!(
+        value.round_dp_with_strategy(1, RoundingStrategy::ToZero).to_string(),
+        "-2.6"
+    );
+    assert_eq!(value.round_dp(5).to_string(), "-2.675");
+    assert_eq!(value.abs().normalize().to_string(), "2.675");
+    assert_eq!(value.mantissa(), -2675);
+    assert_eq!(value.scale(), 3);
+}
+
+// FixedDecimal
+
+#[test]
+fn it_can_perform_fixed_decimal_arithmetic() {
+    use rust_decimal::FixedDecimal;
+
+    type Money = FixedDecimal<2>;
+    let tests = [
+        ("0.1", '+', "0.2", "0.30"),
+        ("1.5", '-', "2.25", "-0.75"),
+        ("1.25", '*', "1.25", "1.56"),
+        ("1.35", '*', "1.1", "1.48"),
+        ("-1.25", '*', "1.25", "-1.56"),
+        ("19.99", '*', "3", "59.97"),
+        ("1", '/', "3", "0.33"),
+        ("2", '/', "3", "0.67"),
+        ("-2", '/', "3", "-0.67"),
+        ("0.05", '/', "2", "0.02"),
+        ("0.15", '/', "2", "0.08"),
+        ("10", '/', "4", "2.50"),
+        ("10", '%', "3", "1.00"),
+        ("-7.5", '%', "2", "-1.50"),
+    ];
+    for &(a, op, b, expected) in &tests {
+        let x = Money::from_str(a).unwrap();
+        let y = Money::from_str(b).unwrap();
+        let result = match op {
+            '+' => x + y,
+            '-' => x - y,
+            '*' => x * y,
+            '/' => x / y,
+            '%' => x % y,
+            _ => unreachable!(),
+        };
+        assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+        assert_eq!(result.scale(), 2, "{a} {op} {b}");
+    }
+
+    let a = Money::from_str("1.25").unwrap();
+    let b = Money::from_str("-1.25").unwrap();
+    let tests = [
+        (RoundingStrategy::MidpointNearestEven, "-1.56", "-1.00"),
+        (RoundingStrategy::MidpointAwayFromZero, "-1.56", "-1.00"),
+        (RoundingStrategy::ToZero, "-1.56", "-1.00"),
+        (RoundingStrategy::AwayFromZero, "-1.57", "-1.00"),
+        (RoundingStrategy::ToPositiveInfinity, "-1.56", "-1.00"),
+        (RoundingStrategy::ToNegativeInfinity, "-1.57", "-1.00"),
+    ];
+    for &(strategy, product, quotient) in &tests {
+        assert_eq!(
+            a.mul_with_strategy(b, strategy).unwrap().to_string(),
+            product,
+            "{strategy:?}"
+        );
+        assert_eq!(
+            a.div_with_strategy(b, strategy).unwrap().to_string(),
+            quotient,
+            "{strategy:?}"
+        );
+    }
+    let third = Money::ONE.div_with_strategy(Money::from_mantissa(300), RoundingStrategy::AwayFromZero);
+    assert_eq!(third, Some(Money::from_mantissa(34)));
+
+    assert_eq!(Money::MAX.checked_add(Money::from_mantissa(1)), None);
+    assert_eq!(Money::MIN.checked_sub(Money::from_mantissa(1)), None);
+    assert_eq!(Money::MAX.checked_mul(Money::from_mantissa(200)), None);
+    assert_eq!(Money::MAX.checked_div(Money::from_mantissa(50)), None);
+    assert_eq!(Money::ONE.checked_div(Money::ZERO), None);
+    assert_eq!(Money::ONE.checked_rem(Money::ZERO), None);
+    assert_eq!(
+        FixedDecimal::<28>::ONE.checked_mul(FixedDecimal::<28>::ONE),
+        Some(FixedDecimal::<28>::ONE)
+    );
+
+    let mut value = Money::from_str("10").unwrap();
+    value += Money::ONE;
+    value *= &Money::from_str("1.5").unwrap();
+    value -= Money::from_str("0.5").unwrap();
+    value /= Money::from_str("4").unwrap();
+    value %= Money::from_str("3").unwrap();
+    assert_eq!(value, Money::ONE);
+    assert_eq!(-value, Money::from_mantissa(-100));
+
+    let values = [
+        Money::from_mantissa(150),
+        Money::from_mantissa(200),
+        Money::from_mantissa(-50),
+    ];
+    assert_eq!(values.iter().sum::<Money>(), Money::from_mantissa(300));
+    assert_eq!(values.into_iter().product::<Money>(), Money::from_mantissa(-150));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_fixed_decimal_division_by_zero() {
+    use rust_decimal::FixedDecimal;
+
+    let _ = FixedDecimal::<2>::ONE / FixedDecimal::<2>::ZERO;
+}
+
+#[test]
+fn it_can_convert_between_fixed_decimal_and_decimal() {
+    use rust_decimal::FixedDecimal;
+
+    let tests = [
+        ("1", "1.00"),
+        ("-1.005", "-1.00"),
+        ("1.015", "1.02"),
+        ("0.001", "0.00"),
+        ("792281625142643375935439503.35", "792281625142643375935439503.35"),
+    ];
+    for &(input, expected) in &tests {
+        let value = FixedDecimal::<2>::try_from(Decimal::from_str(input).unwrap()).unwrap();
+        assert_eq!(value.to_string(), expected, "{input}");
+        assert_eq!(Decimal::from(value).to_string(), expected, "{input}");
+        assert_eq!(FixedDecimal::<2>::from_str(input), Ok(value), "{input}");
+    }
+
+    // Strings are rounded once, rather than first to 28 decimal places and then to `SCALE`
+    assert_eq!(
+        FixedDecimal::<2>::from_str("0.125000000000000000000000000049"),
+        Ok(FixedDecimal::from_mantissa(13))
+    );
+    assert_eq!(
+        FixedDecimal::<2>::from_str("-0.125000000000000000000000000049"),
+        Ok(FixedDecimal::from_mantissa(-13))
+    );
+    assert_eq!(
+        FixedDecimal::<2>::from_str("792281625142643375935439503.355"),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(FixedDecimal::<2>::from_str("1.2.3"), Err(Error::DuplicatedDecimalPoint));
+    assert_eq!(
+        FixedDecimal::<2>::try_from(Decimal::MAX),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        FixedDecimal::<1>::try_from(Decimal::MIN),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+    assert_eq!(
+        FixedDecimal::<0>::from_decimal_with_strategy(Decimal::new(25, 1), RoundingStrategy::MidpointAwayFromZero),
+        Some(FixedDecimal::from_mantissa(3))
+    );
+    assert_eq!(
+        FixedDecimal::<2>::try_from_mantissa(-(1 << 96)),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+
+    let value = FixedDecimal::<4>::from_mantissa(-12345);
+    assert_eq!(value.mantissa(), -12345);
+    assert_eq!(value.scale(), 4);
+    assert_eq!(value.abs().to_string(), "1.2345");
+    assert_eq!(format!("{value:.2}"), "-1.23");
+    assert!(value < FixedDecimal::ZERO);
+}
+
+// Decimal128
+
+#[test]
+fn it_can_parse_and_format_decimal128() {
+    use rust_decimal::Decimal128;
+
+    let tests = [
+        ("0", "0"),
+        ("-0.00", "0.00"),
+        ("-1.500", "-1.500"),
+        ("+1_000.25", "1000.25"),
+        (
+            "99999999999999999999999999999999999999",
+            "99999999999999999999999999999999999999",
+        ),
+        (
+            "-0.00000000000000000000000000000000000001",
+            "-0.00000000000000000000000000000000000001",
+        ),
+        // Digits beyond the available precision are rounded once
+        (
+            "0.123456789012345678901234567890123456789",
+            "0.12345678901234567890123456789012345679",
+        ),
+        (
+            "9.999999999999999999999999999999999999999",
+            "10.000000000000000000000000000000000000",
+        ),
+        (
+            "0.000000000000000000000000000000000000005",
+            "0.00000000000000000000000000000000000000",
+        ),
+    ];
+    for &(input, expected) in &tests {
+        let value = Decimal128::from_str(input).unwrap();
+        assert_eq!(value.to_string(), expected, "{input}");
+    }
+
+    let errors = [
+        ("", Error::EmptyData),
+        ("1.2.3", Error::DuplicatedDecimalPoint),
+        ("abc", Error::InvalidCharacter),
+        (
+            "100000000000000000000000000000000000000",
+            Error::ExceedsMaximumPossibleValue,
+        ),
+        (
+            "-99999999999999999999999999999999999999.5",
+            Error::LessThanMinimumPossibleValue,
+        ),
+    ];
+    for (input, expected) in errors {
+        assert_eq!(Decimal128::from_str(input), Err(expected), "{input}");
+    }
+
+    assert_eq!(
+        Decimal128::try_from_i128_with_scale(10i128.pow(38), 0),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal128::try_from_i128_with_scale(1, 39),
+        Err(Error::ScaleExceedsMaximumPrecision(39))
+    );
+    let value = Decimal128::from_i128_with_scale(-31415, 4);
+    assert_eq!(format!("{value:.2}"), "-3.14");
+    assert_eq!(value.mantissa(), -31415);
+    assert_eq!(value.scale(), 4);
+}
+
+#[test]
+fn it_can_perform_decimal128_arithmetic() {
+    use rust_decimal::Decimal128;
+
+    let tests = [
+        ("0.1", '+', "0.2", "0.3"),
+        (
+            "99999999999999999999999999999999999999",
+            '+',
+            "-1",
+            "99999999999999999999999999999999999998",
+        ),
+        (
+            "1",
+            '+',
+            "0.00000000000000000000000000000000000001",
+            "1.0000000000000000000000000000000000000",
+        ),
+        ("1.5", '-', "2.25", "-0.75"),
+        (
+            "9999999999999999999",
+            '*',
+            "9999999999999999999",
+            "99999999999999999980000000000000000001",
+        ),
+        (
+            "0.00000000000000000000000000000000000001",
+            '*',
+            "-0.1",
+            "0.00000000000000000000000000000000000000",
+        ),
+        ("1", '/', "3", "0.33333333333333333333333333333333333333"),
+        ("-2", '/', "3", "-0.66666666666666666666666666666666666667"),
+        ("10", '/', "4", "2.5"),
+        ("1.00", '/', "2", "0.50"),
+        ("1", '/', "0.25", "4"),
+        ("10", '%', "3", "1"),
+        ("-7.5", '%', "2", "-1.5"),
+    ];
+    for &(a, op, b, expected) in &tests {
+        let x = Decimal128::from_str(a).unwrap();
+        let y = Decimal128::from_str(b).unwrap();
+        let result = match op {
+            '+' => x + y,
+            '-' => x - y,
+            '*' => x * y,
+            '/' => x / y,
+            '%' => x % y,
+            _ => unreachable!(),
+        };
+        assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+    }
+
+    assert_eq!(Decimal128::MAX.checked_add(Decimal128::ONE), None);
+    assert_eq!(Decimal128::MIN.checked_sub(Decimal128::ONE), None);
+    assert_eq!(Decimal128::MAX.checked_mul(Decimal128::from(10)), None);
+    assert_eq!(Decimal128::ONE.checked_div(Decimal128::ZERO), None);
+    assert_eq!(Decimal128::ONE.checked_rem(Decimal128::ZERO), None);
+    assert_eq!(Decimal128::MAX.checked_div(Decimal128::MAX), Some(Decimal128::ONE));
+
+    let mut value = Decimal128::from(10);
+    value += Decimal128::ONE;
+    value *= &Decimal128::from_str("1.5").unwrap();
+    value -= Decimal128::from_str("0.5").unwrap();
+    value /= Decimal128::from(4u8);
+    value %= Decimal128::from(3);
+    assert_eq!(value, Decimal128::ONE);
+    assert_eq!(-value, Decimal128::from(-1));
+    assert!(Decimal128::from_str("-1.5").unwrap() < Decimal128::from_str("-1.25").unwrap());
+
+    let values = [Decimal128::from(2), Decimal128::from(-3)];
+    assert_eq!(values.iter().sum::<Decimal128>(), Decimal128::from(-1));
+    assert_eq!(values.into_iter().product::<Decimal128>(), Decimal128::from(-6));
+}
+
+#[test]
+fn it_can_convert_between_decimal128_and_decimal() {
+    use rust_decimal::Decimal128;
+
+    for value in [Decimal::MAX, Decimal::MIN, Decimal::new(-1, 28), Decimal::new(12345, 2)] {
+        let wide = Decimal128::from(value);
+        assert_eq!(wide.to_string(), value.to_string());
+        assert_eq!(wide.to_decimal(), Some(value));
+        assert_eq!(Decimal::try_from(wide), Ok(value));
+    }
+
+    let value = Decimal128::from_str("0.12345678901234567890123456789012345678").unwrap();
+    assert_eq!(
+        value.to_decimal(),
+        Some(Decimal::from_str("0.1234567890123456789012345679").unwrap())
+    );
+    assert_eq!(
+        value.to_decimal_with_strategy(RoundingStrategy::ToZero),
+        Some(Decimal::from_str("0.1234567890123456789012345678").unwrap())
+    );
+    assert_eq!(
+        Decimal::try_from(Decimal128::MAX),
+        Err(Error::ExceedsMaximumPossibleValue)
+    );
+    assert_eq!(
+        Decimal::try_from(Decimal128::MIN),
+        Err(Error::LessThanMinimumPossibleValue)
+    );
+
+    let value = Decimal128::from_str("-2.675").unwrap();
+    assert_eq!(value.round_dp(2).to_string(), "-2.68");
+    assert_eq!(
+        value.round_dp_with_strategy(1, RoundingStrategy::ToZero).to_string(),
+        "-2.6"
+    );
+    assert_eq!(value.abs().normalize().to_string(), "2.675");
+}
+
+// ExtDecimal
+
+#[test]
+fn it_can_parse_and_format_ext_decimal() {
+    use rust_decimal::ExtDecimal;
+
+    let tests = [
+        ("NaN", "NaN"),
+        ("nan", "NaN"),
+        ("Infinity", "Infinity"),
+        ("+inf", "Infinity"),
+        ("-Infinity", "-Infinity"),
+        ("-INF", "-Infinity"),
+        ("-1.500", "-1.500"),
+        ("0", "0"),
+    ];
+    for &(input, expected) in &tests {
+        let value = ExtDecimal::from_str(input).unwrap();
+        assert_eq!(value.to_string(), expected, "{input}");
+    }
+
+    let errors = [
+        ("", Error::EmptyData),
+        ("-nan", Error::InvalidCharacter),
+        ("Infinite", Error::InvalidCharacter),
+        ("1.2.3", Error::DuplicatedDecimalPoint),
+    ];
+    for (input, expected) in errors {
+        assert_eq!(ExtDecimal::from_str(input), Err(expected), "{input}");
+    }
+
+    assert_eq!(format!("{:>10}", ExtDecimal::NaN), "       NaN");
+    assert_eq!(format!("{:.2}", ExtDecimal::from(Decimal::new(31415, 4))), "3.14");
+    assert_eq!(Decimal::try_from(ExtDecimal::from(Decimal::ONE)), Ok(Decimal::ONE));
+    assert_eq!(
+        Decimal::try_from(ExtDecimal::PosInf),
+        Err(Error::ConversionTo("Decimal"))
+    );
+    assert_eq!(ExtDecimal::NegInf.to_decimal(), None);
+}
+
+#[test]
+fn it_can_perform_ext_decimal_arithmetic() {
+    use rust_decimal::ExtDecimal;
+
+    let tests = [
+        ("1.5", '+', "2.25", "3.75"),
+        ("79228162514264337593543950335", '+', "1", "Infinity"),
+        ("-79228162514264337593543950335", '-', "1", "-Infinity"),
+        ("Infinity", '+', "1", "Infinity"),
+        ("Infinity", '+', "-Infinity", "NaN"),
+        ("Infinity", '-', "Infinity", "NaN"),
+        ("-Infinity", '-', "Infinity", "-Infinity"),
+        ("NaN", '+', "1", "NaN"),
+        ("79228162514264337593543950335", '*', "-2", "-Infinity"),
+        ("-Infinity", '*', "-2", "Infinity"),
+        ("Infinity", '*', "0", "NaN"),
+        ("1", '/', "0", "Infinity"),
+        ("-1", '/', "0", "-Infinity"),
+        ("0", '/', "0", "NaN"),
+        ("1", '/', "-Infinity", "0"),
+        ("-Infinity", '/', "4", "-Infinity"),
+        ("Infinity", '/', "Infinity", "NaN"),
+        ("7", '%', "3", "1"),
+        ("7", '%', "0", "NaN"),
+        ("7", '%', "Infinity", "7"),
+        ("Infinity", '%', "3", "NaN"),
+    ];
+    for &(a, op, b, expected) in &tests {
+        let a = ExtDecimal::from_str(a).unwrap();
+        let b = ExtDecimal::from_str(b).unwrap();
+        let result = match op {
+            '+' => a + b,
+            '-' => a - b,
+            '*' => a * b,
+            '/' => a / b,
+            '%' => a % b,
+            _ => unreachable!(),
+        };
+        assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+    }
+
+    assert_eq!(-ExtDecimal::PosInf, ExtDecimal::NegInf);
+    assert_eq!(ExtDecimal::NegInf.abs(), ExtDecimal::PosInf);
+    assert!(ExtDecimal::NegInf.is_sign_negative());
+    assert!(ExtDecimal::PosInf.is_infinite() && !ExtDecimal::PosInf.is_finite());
+}
+
+#[test]
+fn it_can_compare_ext_decimal() {
+    use core::cmp::Ordering;
+    use rust_decimal::ExtDecimal;
+
+    let one = ExtDecimal::from(Decimal::ONE);
+    assert!(ExtDecimal::NegInf < one);
+    assert!(one < ExtDecimal::PosInf);
+    assert_eq!(ExtDecimal::from_str("1.00").unwrap(), one);
+    assert_eq!(ExtDecimal::PosInf, ExtDecimal::PosInf);
+
+    // NaN is unordered and unequal to everything, including itself
+    assert_ne!(ExtDecimal::NaN, ExtDecimal::NaN);
+    assert_eq!(ExtDecimal::NaN.partial_cmp(&one), None);
+    assert_eq!(ExtDecimal::NaN.partial_cmp(&ExtDecimal::NegInf), None);
+
+    // Whereas the total ordering sorts it last, as PostgreSQL does
+    assert_eq!(ExtDecimal::NaN.total_cmp(&ExtDecimal::PosInf), Ordering::Greater);
+    assert_eq!(ExtDecimal::NegInf.total_cmp(&ExtDecimal::NegInf), Ordering::Equal);
+}
+
+// DecimalRatio
+
+#[test]
+fn it_can_convert_decimal_to_and_from_ratio() {
+    use rust_decimal::DecimalRatio;
+
+    let tests = [
+        ("0", 0, 1),
+        ("-0.00", 0, 1),
+        ("0.75", 3, 4),
+        ("-12.500", -25, 2),
+        ("1000", 1000, 1),
+        (
+            "0.0000000000000000000000000001",
+            1,
+            10_000_000_000_000_000_000_000_000_000,
+        ),
+        (
+            "-79228162514264337593543950335",
+            -79_228_162_514_264_337_593_543_950_335,
+            1,
+        ),
+    ];
+    for &(input, numer, denom) in &tests {
+        let ratio = Decimal::from_str(input).unwrap().to_ratio();
+        assert_eq!((ratio.numer(), ratio.denom()), (numer, denom), "{input}");
+    }
+
+    let tests = [
+        (2, 3, 4, RoundingStrategy::ToZero, Some("0.6666")),
+        (2, 3, 4, RoundingStrategy::MidpointNearestEven, Some("0.6667")),
+        (-2, 3, 0, RoundingStrategy::AwayFromZero, Some("-1")),
+        (1, 8, 2, RoundingStrategy::MidpointNearestEven, Some("0.12")),
+        (1, -8, 2, RoundingStrategy::MidpointAwayFromZero, Some("-0.13")),
+        (1, 2, 3, RoundingStrategy::ToZero, Some("0.500")),
+        (
+            1,
+            3,
+            40,
+            RoundingStrategy::ToZero,
+            Some("0.3333333333333333333333333333"),
+        ),
+        (1, 0, 2, RoundingStrategy::ToZero, None),
+        (i128::MAX, 1, 0, RoundingStrategy::ToZero, None),
+        // Values that can't be held at the requested scale use the largest scale that fits
+        (
+            79_228_162_514_264_337_593_543_950_335,
+            1,
+            1,
+            RoundingStrategy::ToZero,
+            Some("79228162514264337593543950335"),
+        ),
+        (
+            12_345_678,
+            1,
+            28,
+            RoundingStrategy::ToZero,
+            Some("12345678.000000000000000000000"),
+        ),
+        (
+            12_345_678,
+            7,
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("1763668.2857142857142857142857"),
+        ),
+        (
+            -79_228_162_514_264_337_593_543_950_335,
+            1,
+            0,
+            RoundingStrategy::ToZero,
+            Some("-79228162514264337593543950335"),
+        ),
+    ];
+    for &(num, den, dp, strategy, expected) in &tests {
+        let result = Decimal::from_ratio(num, den, dp, strategy);
+        assert_eq!(
+            result.map(|d| d.to_string()).as_deref(),
+            expected,
+            "{num}/{den} to {dp} places with {strategy:?}"
+        );
+    }
+
+    assert_eq!(DecimalRatio::from(Decimal::new(-15, 1)), DecimalRatio::new(-3, 2));
+}
+
+#[test]
+fn it_can_approximate_a_decimal_as_a_ratio() {
+    let tests = [
+        ("3.14159265358979", 1000, "355/113"),
+        ("3.14159265358979", 100, "311/99"),
+        ("0.333", 10, "1/3"),
+        ("-2.7182818284590452353602874714", 1000, "-1457/536"),
+        ("7.9228162514264337593543950335", 1_000_000, "4813919/607602"),
+        ("0.4999", 1, "0"),
+        ("0.125", 100, "1/8"),
+        ("0.125", 0, "0"),
+        ("-5", 7, "-5"),
+    ];
+    for &(input, max_denominator, expected) in &tests {
+        let ratio = Decimal::from_str(input).unwrap().approximate_ratio(max_denominator);
+        assert_eq!(ratio.to_string(), expected, "{input} limited to {max_denominator}");
+    }
+}
+
+#[test]
+fn it_can_perform_decimal_ratio_arithmetic() {
+    use rust_decimal::DecimalRatio;
+
+    let third = DecimalRatio::new(1, 3);
+    let tests = [
+        (third, '+', DecimalRatio::new(1, 6), "1/2"),
+        (third, '-', DecimalRatio::new(1, 2), "-1/6"),
+        (DecimalRatio::new(-4, 9), '*', DecimalRatio::new(3, -8), "1/6"),
+        (DecimalRatio::new(2, 3), '/', DecimalRatio::new(-4, 3), "-1/2"),
+        (DecimalRatio::new(6, 4), '*', DecimalRatio::from(2), "3"),
+        (DecimalRatio::ZERO, '/', third, "0"),
+    ];
+    for &(a, op, b, expected) in &tests {
+        let result = match op {
+            '+' => a + b,
+            '-' => a - b,
+            '*' => a * b,
+            '/' => a / b,
+            _ => unreachable!(),
+        };
+        assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+    }
+
+    // Common factors are cancelled before multiplying, so this doesn't overflow
+    let big = DecimalRatio::new(i128::MAX, 3);
+    assert_eq!(
+        big.checked_mul(DecimalRatio::new(3, i128::MAX)),
+        Some(DecimalRatio::ONE)
+    );
+    assert_eq!(big.checked_add(big), None);
+    assert_eq!(third.checked_div(DecimalRatio::ZERO), None);
+    assert_eq!(DecimalRatio::try_new(1, 0), Err(Error::DivisionByZero));
+    assert!(DecimalRatio::try_new(i128::MIN, 1).is_err());
+    assert_eq!(DecimalRatio::new(i128::MIN, 2), DecimalRatio::new(i128::MIN / 2, 1));
+
+    assert!(DecimalRatio::new(-1, 2) < DecimalRatio::new(-1, 3));
+    assert!(DecimalRatio::new(i128::MAX - 1, i128::MAX) < DecimalRatio::ONE);
+    assert!(DecimalRatio::new(i128::MAX, i128::MAX - 1) > DecimalRatio::ONE);
+    assert_eq!(DecimalRatio::new(7, -12).recip(), Some(DecimalRatio::new(-12, 7)));
+}
+
+// DecimalInterval
+
+#[test]
+fn it_can_perform_decimal_interval_arithmetic() {
+    use rust_decimal::DecimalInterval;
+
+    let interval =
+        |lo: &str, hi: &str| DecimalInterval::new(Decimal::from_str(lo).unwrap(), Decimal::from_str(hi).unwrap());
+    let tests = [
+        (interval("1", "2"), '+', interval("0.5", "0.75"), "[1.5, 2.75]"),
+        (interval("1", "2"), '-', interval("0.5", "0.75"), "[0.25, 1.5]"),
+        (interval("-2", "3"), '*', interval("-5", "4"), "[-15, 12]"),
+        (interval("-3", "-2"), '*', interval("4", "5"), "[-15, -8]"),
+        (
+            interval("1", "1"),
+            '/',
+            interval("3", "3"),
+            "[0.3333333333333333333333333333, 0.3333333333333333333333333334]",
+        ),
+        (
+            interval("-1", "-1"),
+            '/',
+            interval("3", "3"),
+            "[-0.3333333333333333333333333334, -0.3333333333333333333333333333]",
+        ),
+        (interval("1", "2"), '/', interval("-4", "-2"), "[-1, -0.25]"),
+        (
+            interval("0.1", "0.1"),
+            '*',
+            interval("0.0000000000000000000000000001", "0.0000000000000000000000000001"),
+            "[0.0000000000000000000000000000, 0.0000000000000000000000000001]",
+        ),
+        (
+            interval("79228162514264337593543950335", "79228162514264337593543950335"),
+            '/',
+            interval("7", "7"),
+            "[11318308930609191084791992905, 11318308930609191084791992905]",
+        ),
+        (
+            interval("79228162514264337593543950334", "79228162514264337593543950334"),
+            '/',
+            interval("10", "10"),
+            "[7922816251426433759354395033.4, 7922816251426433759354395033.4]",
+        ),
+    ];
+    for &(a, op, b, expected) in &tests {
+        let result = match op {
+            '+' => a + b,
+            '-' => a - b,
+            '*' => a * b,
+            '/' => a / b,
+            _ => unreachable!(),
+        };
+        assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+    }
+
+    let max = DecimalInterval::from(Decimal::MAX);
+    assert_eq!(max.checked_add(max), None);
+    assert_eq!(max.checked_div(interval("-1", "1")), None);
+    assert_eq!(-interval("-1", "2"), interval("-2", "1"));
+    assert_eq!(DecimalInterval::checked_new(Decimal::ONE, Decimal::ZERO), None);
+}
+
+#[test]
+fn it_can_inspect_a_decimal_interval() {
+    use rust_decimal::DecimalInterval;
+
+    let tests = [
+        ("1", "2", Some("1"), "1.5"),
+        ("-1.25", "1.25", Some("2.5"), "0"),
+        (
+            "0.3333333333333333333333333333",
+            "0.3333333333333333333333333334",
+            Some("0.0000000000000000000000000001"),
+            "0.3333333333333333333333333333",
+        ),
+        (
+            "-79228162514264337593543950335",
+            "79228162514264337593543950335",
+            None,
+            "0",
+        ),
+    ];
+    for &(lo, hi, width, midpoint) in &tests {
+        let interval = DecimalInterval::new(Decimal::from_str(lo).unwrap(), Decimal::from_str(hi).unwrap());
+        let width = width.map(|w| Decimal::from_str(w).unwrap());
+        assert_eq!(interval.width(), width, "width of {interval}");
+        assert_eq!(
+            interval.midpoint(),
+            Decimal::from_str(midpoint).unwrap(),
+            "midpoint of {interval}"
+        );
+        assert!(interval.contains(&interval.midpoint()), "{interval}");
+    }
+
+    let interval = DecimalInterval::new(Decimal::new(-5, 1), Decimal::new(25, 1));
+    assert!(interval.contains(&Decimal::ZERO) && interval.contains_zero());
+    assert!(interval.contains(&Decimal::new(-5, 1)) && interval.contains(&Decimal::new(25, 1)));
+    assert!(!interval.contains(&Decimal::new(26, 1)));
+    assert_eq!(format!("{interval:.2}"), "[-0.50, 2.50]");
+
+    // The printed bounds are rounded outward so they still enclose the interval
+    let interval = DecimalInterval::new(Decimal::new(1234, 3), Decimal::new(1236, 3));
+    assert_eq!(format!("{interval:.2}"), "[1.23, 1.24]");
+    let interval = DecimalInterval::new(Decimal::new(-1236, 3), Decimal::new(-1234, 3));
+    assert_eq!(format!("{interval:.2}"), "[-1.24, -1.23]");
+    assert_eq!(format!("{interval:.0}"), "[-2, -1]");
+}
+
+#[test]
+#[cfg(feature = "maths")]
+fn it_can_bound_decimal_interval_functions() {
+    use rust_decimal::DecimalInterval;
+
+    // Each bound was checked against a high precision reference value
+    let tests = [
+        (
+            "2",
+            "sqrt",
+            "1.4142135623730950488016887242",
+            "1.4142135623730950488016887243",
+        ),
+        (
+            "0.0000000000000000000000000002",
+            "sqrt",
+            "0.0000000000000141421356237309",
+            "0.0000000000000141421356237310",
+        ),
+        (
+            "1",
+            "exp",
+            "2.7182818284590452353602874713",
+            "2.7182818284590452353602874714",
+        ),
+        ("0", "exp", "1", "1"),
+        ("-70", "exp", "0", "0.0000000000000000000000000001"),
+        (
+            "10",
+            "ln",
+            "2.3025850929940456840179914546",
+            "2.3025850929940456840179914547",
+        ),
+        ("1", "ln", "0", "0"),
+    ];
+    for &(input, function, lo, hi) in &tests {
+        let value = DecimalInterval::from(Decimal::from_str(input).unwrap());
+        let result = match function {
+            "sqrt" => value.sqrt(),
+            "exp" => value.exp(),
+            "ln" => value.ln(),
+            _ => unreachable!(),
+        }
+        .unwrap();
+        let (lo, hi) = (Decimal::from_str(lo).unwrap(), Decimal::from_str(hi).unwrap());
+        assert!(result.lo() <= lo && hi <= result.hi(), "{function}({input}) = {result}");
+        assert!(
+            result.width().unwrap() <= Decimal::new(1, 26),
+            "{function}({input}) = {result}"
+        );
+    }
+
+    // The square of the root's upper bound, 2^48, is too large to represent
+    let root = DecimalInterval::from(Decimal::MAX).sqrt().unwrap();
+    let exact = Decimal::from_str("281474976710655.99999999999999822364").unwrap();
+    assert!(root.lo() <= exact && exact <= root.hi(), "sqrt(MAX) = {root}");
+    assert!(root.width().unwrap() <= Decimal::new(1, 12), "sqrt(MAX) = {root}");
+
+    assert_eq!(DecimalInterval::new(Decimal::NEGATIVE_ONE, Decimal::ONE).sqrt(), None);
+    assert_eq!(DecimalInterval::new(Decimal::ZERO, Decimal::ONE).ln(), None);
+    assert_eq!(DecimalInterval::from(Decimal::ONE_HUNDRED).exp(), None);
+}
+
+// BigDecimal
+#[cfg(feature = "big-decimal")]
+mod big_decimal {
+    use super::*;
+    use rust_decimal::BigDecimal;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    fn it_can_parse_and_format_big_decimal() {
+        let tests = [
+            ("0", "0"),
+            ("-0.00", "0.00"),
+            ("-1.500", "-1.500"),
+            ("+1_000.25", "1000.25"),
+            ("1000000000", "1000000000"),
+            ("0.000000001", "0.000000001"),
+            (
+                "123456789012345678901234567890123456789012345678901234567890.123456789012345678",
+                "123456789012345678901234567890123456789012345678901234567890.123456789012345678",
+            ),
+            (
+                "-0.000000000000000000000000000000000000000000000000000000000000000000000000000001",
+                "-0.000000000000000000000000000000000000000000000000000000000000000000000000000001",
+            ),
+        ];
+        for &(input, expected) in &tests {
+            let value = BigDecimal::from_str(input).unwrap();
+            assert_eq!(value.to_string(), expected, "{input}");
+        }
+
+        let errors = [
+            ("", Error::EmptyData),
+            ("-", Error::NoDigits),
+            ("1.2.3", Error::DuplicatedDecimalPoint),
+            ("abc", Error::InvalidCharacter),
+            ("_1", Error::InvalidLeadingChar),
+            ("-_1", Error::InvalidLeadingChar),
+            ("._1", Error::InvalidLeadingChar),
+        ];
+        for (input, expected) in errors {
+            assert_eq!(BigDecimal::from_str(input), Err(expected), "{input}");
+        }
+
+        let value = BigDecimal::new(-31415, 4);
+        assert_eq!(format!("{value:.2}"), "-3.14");
+        assert_eq!(format!("{value:.6}"), "-3.141500");
+        assert_eq!(format!("{value:>10}"), "   -3.1415");
+        assert_eq!(value.scale(), 4);
+        assert_eq!(
+            BigDecimal::from(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn it_can_perform_big_decimal_arithmetic() {
+        let tests = [
+            ("0.1", '+', "0.2", "0.3"),
+            ("1.5", '-', "2.25", "-0.75"),
+            ("-1.5", '-', "-1.5", "0.0"),
+            (
+                "340282366920938463463374607431768211455",
+                '+',
+                "1",
+                "340282366920938463463374607431768211456",
+            ),
+            (
+                "340282366920938463463374607431768211456",
+                '-',
+                "0.000000000000000000000000000000000000001",
+                "340282366920938463463374607431768211455.999999999999999999999999999999999999999",
+            ),
+            (
+                "123456789012345678901234567890",
+                '*',
+                "-987654321098765432109876543210.5",
+                "-121932631137021795226185032733684651726743636640561880810845.0",
+            ),
+            ("0.001", '*', "0.001", "0.000001"),
+            ("123.45", '*', "0", "0.00"),
+            ("1", '/', "3", "0.3333333333333333333333333333"),
+            ("-2", '/', "3", "-0.6666666666666666666666666667"),
+            ("10.00", '/', "4", "2.5"),
+            ("0", '/', "-7", "0"),
+            (
+                "340282366920938463463374607431768211456",
+                '/',
+                "0.000000000000000000000000000000000000001",
+                "340282366920938463463374607431768211456000000000000000000000000000000000000000",
+            ),
+            ("1", '/', "7000000000000000000000000000000000000000", "0"),
+            (
+                "1.0000000000000000000000000000000000000000",
+                '/',
+                "7",
+                "0.1428571428571428571428571428571428571429",
+            ),
+        ];
+        for &(a, op, b, expected) in &tests {
+            let a = BigDecimal::from_str(a).unwrap();
+            let b = BigDecimal::from_str(b).unwrap();
+            let result = match op {
+                '+' => &a + &b,
+                '-' => &a - &b,
+                '*' => &a * &b,
+                '/' => &a / &b,
+                _ => unreachable!(),
+            };
+            assert_eq!(result.to_string(), expected, "{a} {op} {b}");
+        }
+
+        let mut value = BigDecimal::from(2);
+        value *= BigDecimal::new(5, 1);
+        value -= BigDecimal::from(3);
+        assert_eq!(value.to_string(), "-2.0");
+        assert_eq!((-value).to_string(), "2.0");
+
+        let values = [BigDecimal::new(15, 1), BigDecimal::new(-2, 0), BigDecimal::new(4, 2)];
+        assert_eq!(values.iter().sum::<BigDecimal>().to_string(), "-0.46");
+        assert_eq!(values.iter().product::<BigDecimal>().to_string(), "-0.120");
+
+        let tiny = BigDecimal::new(1, u32::MAX);
+        assert_eq!(tiny.checked_mul(&tiny), None);
+        assert_eq!(BigDecimal::from(1).checked_div(&BigDecimal::ZERO), None);
+        let strategy = RoundingStrategy::ToNegativeInfinity;
+        assert_eq!(
+            BigDecimal::from(-2)
+                .div_with_scale(&BigDecimal::from(3), 40, strategy)
+                .unwrap()
+                .to_string(),
+            "-0.6666666666666666666666666666666666666667"
+        );
+        assert_eq!(
+            BigDecimal::new(15, 1)
+                .div_with_scale(&BigDecimal::new(5, 1), 3, strategy)
+                .unwrap()
+                .to_string(),
+            "3.000"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn it_panics_when_big_decimal_division_by_zero() {
+        let _ = BigDecimal::from(1) / BigDecimal::ZERO;
+    }
+
+    #[test]
+    fn it_can_round_and_compare_big_decimal() {
+        let tests = [
+            ("1.2345", 2, RoundingStrategy::MidpointNearestEven, "1.23"),
+            ("1.235", 2, RoundingStrategy::MidpointNearestEven, "1.24"),
+            ("1.245", 2, RoundingStrategy::MidpointNearestEven, "1.24"),
+            ("-1.245", 2, RoundingStrategy::MidpointAwayFromZero, "-1.25"),
+            ("1.2401", 2, RoundingStrategy::ToZero, "1.24"),
+            ("-1.2401", 2, RoundingStrategy::ToNegativeInfinity, "-1.25"),
+            (
+                "9999999999.9999999999",
+                0,
+                RoundingStrategy::MidpointNearestEven,
+                "10000000000",
+            ),
+            ("-0.4", 0, RoundingStrategy::MidpointNearestEven, "0"),
+            ("1.5", 3, RoundingStrategy::MidpointNearestEven, "1.5"),
+            (
+                "0.1234567890123456789012345678901234567895",
+                39,
+                RoundingStrategy::MidpointNearestEven,
+                "0.123456789012345678901234567890123456790",
+            ),
+        ];
+        for &(input, dp, strategy, expected) in &tests {
+            let value = BigDecimal::from_str(input).unwrap();
+            assert_eq!(
+                value.round_dp_with_strategy(dp, strategy).to_string(),
+                expected,
+                "{input} {dp} {strategy:?}"
+            );
+        }
+
+        let value = BigDecimal::from_str("-120.3400").unwrap();
+        assert_eq!(value.normalize().to_string(), "-120.34");
+        assert_eq!(BigDecimal::from_str("-0.000").unwrap().normalize().to_string(), "0");
+        assert_eq!(BigDecimal::from_str("1000").unwrap().normalize().to_string(), "1000");
+
+        let a = BigDecimal::from_str("1.50").unwrap();
+        let b = BigDecimal::from_str("1.5").unwrap();
+        assert_eq!(a, b);
+        let hash = |d: &BigDecimal| {
+            let mut hasher = DefaultHasher::new();
+            d.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&a), hash(&b));
+        assert!(BigDecimal::from_str("-2").unwrap() < BigDecimal::from_str("-1.99999999999999999999999").unwrap());
+        assert!(BigDecimal::from_str("100000000000000000000000000000000000000000").unwrap() > b);
+        assert_eq!(BigDecimal::from_str("-0").unwrap(), BigDecimal::ZERO);
+    }
+
+    #[test]
+    fn it_can_convert_big_decimal_to_and_from_decimal() {
+        let tests = [
+            ("0", Some("0")),
+            ("-1.5", Some("-1.5")),
+            ("79228162514264337593543950335", Some("79228162514264337593543950335")),
+            ("79228162514264337593543950335.4", Some("79228162514264337593543950335")),
+            ("79228162514264337593543950335.5", None),
+            ("-79228162514264337593543950336", None),
+            ("1000000000000000000000000000000000000000000", None),
+            (
+                "0.12345678901234567890123456789012345678901234567890",
+                Some("0.1234567890123456789012345679"),
+            ),
+            (
+                "1.000000000000000000000000000050000000000000000000000000001",
+                Some("1.0000000000000000000000000001"),
+            ),
+            (
+                "1.00000000000000000000000000005",
+                Some("1.0000000000000000000000000000"),
+            ),
+            (
+                "0.00000000000000000000000000000000000000001",
+                Some("0.0000000000000000000000000000"),
+            ),
+        ];
+        for &(input, expected) in &tests {
+            let value = BigDecimal::from_str(input).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(value.to_decimal(), expected, "{input}");
+            assert_eq!(Decimal::try_from(value).ok(), expected, "{input}");
+        }
+
+        let value = BigDecimal::from_str("2.5000000000000000000000000000000000000001").unwrap();
+        assert_eq!(
+            value.to_decimal_with_strategy(RoundingStrategy::ToZero),
+            Some(Decimal::from_str("2.5").unwrap())
+        );
+        assert_eq!(
+            Decimal::try_from(-BigDecimal::from(u128::MAX)),
+            Err(Error::LessThanMinimumPossibleValue)
+        );
+
+        for input in [
+            "0",
+            "-1.5",
+            "79228162514264337593543950335",
+            "-0.0000000000000000000000000001",
+        ] {
+            let decimal = Decimal::from_str(input).unwrap();
+            let big = BigDecimal::from(decimal);
+            assert_eq!(big.to_string(), decimal.to_string());
+            assert_eq!(big.to_decimal(), Some(decimal));
+        }
+    }
+}
+
+// Money
+#[cfg(feature = "money")]
+mod money {
+    use super::*;
+    use rust_decimal::{Currency, Money};
+
+    #[test]
+    fn it_can_parse_and_format_money() {
+        let tests = [
+            ("USD 12.34", "USD 12.34"),
+            ("12.34 USD", "USD 12.34"),
+            ("  -0.5   EUR ", "EUR -0.5"),
+            ("JPY 1_000", "JPY 1000"),
+            ("KWD 1.2345", "KWD 1.2345"),
+        ];
+        for &(input, expected) in &tests {
+            let value = Money::from_str(input).unwrap();
+            assert_eq!(value.to_string(), expected, "{input}");
+        }
+
+        let errors = [
+            ("", Error::UnknownCurrency),
+            ("12.34", Error::UnknownCurrency),
+            ("XYZ 12.34", Error::UnknownCurrency),
+            ("usd 12.34", Error::UnknownCurrency),
+            ("USD 12..34", Error::DuplicatedDecimalPoint),
+            ("USD abc", Error::InvalidCharacter),
+        ];
+        for (input, expected) in errors {
+            assert_eq!(Money::from_str(input), Err(expected), "{input}");
+        }
+
+        let value = Money::new(Decimal::new(31415, 4), Currency::GBP);
+        assert_eq!(format!("{value:.2}"), "GBP 3.14");
+        assert_eq!(value.amount(), Decimal::new(31415, 4));
+        assert_eq!(value.currency().code(), "GBP");
+        assert_eq!(Money::zero(Currency::USD).to_string(), "USD 0.00");
+        assert_eq!(Money::zero(Currency::JPY).to_string(), "JPY 0");
+    }
+
+    #[test]
+    fn it_can_look_up_currencies() {
+        let tests = [
+            ("USD", 2),
+            ("EUR", 2),
+            ("JPY", 0),
+            ("KRW", 0),
+            ("BHD", 3),
+            ("CLF", 4),
+            ("AED", 2),
+            ("ZWG", 2),
+        ];
+        for &(code, minor_units) in &tests {
+            let currency = Currency::from_code(code).unwrap();
+            assert_eq!(currency.code(), code);
+            assert_eq!(currency.minor_units(), minor_units, "{code}");
+        }
+        for code in ["", "US", "USDD", "XAU", "ZZZ"] {
+            assert_eq!(Currency::from_str(code), Err(Error::UnknownCurrency), "{code}");
+        }
+        assert_eq!(Currency::from_code("USD"), Some(Currency::USD));
+    }
+
+    #[test]
+    fn it_rounds_money_to_minor_units() {
+        let tests = [
+            ("USD 1.005", RoundingStrategy::MidpointNearestEven, "USD 1.00"),
+            ("USD 1.015", RoundingStrategy::MidpointNearestEven, "USD 1.02"),
+            ("USD 1.001", RoundingStrategy::AwayFromZero, "USD 1.01"),
+            ("USD -1.009", RoundingStrategy::ToZero, "USD -1.00"),
+            ("USD 7", RoundingStrategy::MidpointNearestEven, "USD 7.00"),
+            ("JPY 1234.5", RoundingStrategy::MidpointAwayFromZero, "JPY 1235"),
+            ("KWD 0.12345", RoundingStrategy::MidpointNearestEven, "KWD 0.123"),
+            ("CLF 2.5", RoundingStrategy::MidpointNearestEven, "CLF 2.5000"),
+        ];
+        for &(input, strategy, expected) in &tests {
+            let value = Money::from_str(input).unwrap();
+            assert_eq!(
+                value.round_with_strategy(strategy).to_string(),
+                expected,
+                "{input} {strategy:?}"
+            );
+        }
+        assert_eq!(Money::from_str("EUR 2.345").unwrap().round().to_string(), "EUR 2.34");
+    }
+
+    #[test]
+    fn it_refuses_to_mix_currencies() {
+        let dollars = Money::from_str("USD 10.00").unwrap();
+        let cents = Money::from_str("USD 0.25").unwrap();
+        let euros = Money::from_str("EUR 10.00").unwrap();
+
+        assert_eq!((dollars + cents).to_string(), "USD 10.25");
+        assert_eq!((dollars - cents).to_string(), "USD 9.75");
+        assert_eq!((-cents).to_string(), "USD -0.25");
+        assert_eq!((dollars * Decimal::new(3, 0)).to_string(), "USD 30.00");
+        assert_eq!((dollars / Decimal::new(4, 0)).to_string(), "USD 2.50");
+        assert!(cents < dollars);
+
+        assert_eq!(dollars.try_add(euros), Err(Error::CurrencyMismatch("USD", "EUR")));
+        assert_eq!(euros.try_sub(dollars), Err(Error::CurrencyMismatch("EUR", "USD")));
+        assert_eq!(dollars.partial_cmp(&euros), None);
+        assert_ne!(dollars, euros);
+
+        let max = Money::new(Decimal::MAX, Currency::USD);
+        assert_eq!(max.try_add(max), Err(Error::ExceedsMaximumPossibleValue));
+        assert_eq!(max.checked_mul(Decimal::TWO), None);
+        assert_eq!(dollars.checked_div(Decimal::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot combine amounts in USD and EUR.")]
+    fn it_panics_when_adding_mismatched_currencies() {
+        let _ = Money::from_str("USD 1").unwrap() + Money::from_str("EUR 1").unwrap();
+    }
+}
+
 // Mathematical features
-// legacy-ops produces incorrect values for maths functions; not recommended.
-#[cfg(all(feature = "maths", not(feature = "legacy-ops")))]
+#[cfg(feature = "maths")]
 mod maths {
     use super::*;
     use rust_decimal::MathematicalOps;